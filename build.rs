@@ -1,19 +1,38 @@
 use brotli::{enc::backward_references::BrotliEncoderParams, CompressorWriter};
 use std::{
-    ffi::OsStr,
-    fs::{self, File},
+    fs::File,
     io::{self, BufRead, BufReader, BufWriter, Write},
     path::PathBuf,
 };
 use unicase::UniCase;
 
+// Each language's list lives in its own data crate under data/, whose
+// build script builds it; only the shared helpers are used here.
+#[path = "data/wordlist.rs"]
+#[allow(dead_code)]
+mod wordlist;
+
+use wordlist::{read_dir_filter_ext, recompress_zstd};
+
 fn main() -> io::Result<()> {
-    let txt_folderpath = PathBuf::from("src/txt/");
-    let br_folderpath = PathBuf::from("src/br/");
+    // Frequency lists are ordered by rank, which must be preserved.
+    compress_folder(&PathBuf::from("src/rank/"), &PathBuf::from("src/rank/"), false)?;
+    compress_folder(&PathBuf::from("src/pos/"), &PathBuf::from("src/pos/"), true)?;
+    // BIP-39 wordlists encode bits by position, so their order is fixed.
+    compress_folder(&PathBuf::from("src/bip39/"), &PathBuf::from("src/bip39/"), false)?;
+    compress_folder(&PathBuf::from("src/romanization/"), &PathBuf::from("src/romanization/"), false)?;
+    compress_folder(&PathBuf::from("src/hsk/"), &PathBuf::from("src/hsk/"), false)?;
+    for folder in ["src/rank/", "src/pos/", "src/bip39/", "src/romanization/", "src/hsk/"] {
+        recompress_folder_zstd(&PathBuf::from(folder))?;
+    }
 
-    let txt_file_paths = read_dir_filter_ext(&txt_folderpath, "txt")?;
+    Ok(())
+}
 
-    let br_file_names = read_dir_filter_ext(&br_folderpath, "br")?
+fn compress_folder(txt_folderpath: &PathBuf, br_folderpath: &PathBuf, sort: bool) -> io::Result<()> {
+    let txt_file_paths = read_dir_filter_ext(txt_folderpath, "txt")?;
+
+    let br_file_names = read_dir_filter_ext(br_folderpath, "br")?
         .into_iter()
         .map(|path| path.file_stem().unwrap().to_str().unwrap().to_owned())
         .collect::<Vec<_>>();
@@ -22,14 +41,14 @@ fn main() -> io::Result<()> {
         let stem = txt_path.file_stem().unwrap().to_str().unwrap();
         if !br_file_names.contains(&stem.to_string()) {
             let br_output_path = br_folderpath.join(format!("{}.br", stem));
-            compress_file_brotli(&txt_path, &br_output_path)?;
+            compress_file_brotli(txt_path, &br_output_path, sort)?;
         }
     }
 
     Ok(())
 }
 
-fn compress_file_brotli(path: &PathBuf, output_path: &PathBuf) -> io::Result<()> {
+fn compress_file_brotli(path: &PathBuf, output_path: &PathBuf, sort: bool) -> io::Result<()> {
     let file = File::open(path)?;
     let mut reader = BufReader::new(file);
 
@@ -40,7 +59,9 @@ fn compress_file_brotli(path: &PathBuf, output_path: &PathBuf) -> io::Result<()>
         line.clear();
     }
 
-    lines.sort_by_key(|l| UniCase::new(l.clone()));
+    if sort {
+        lines.sort_by_key(|l| UniCase::new(l.clone()));
+    }
 
     let output_file = File::create(output_path)?;
     let writer = BufWriter::new(output_file);
@@ -56,14 +77,11 @@ fn compress_file_brotli(path: &PathBuf, output_path: &PathBuf) -> io::Result<()>
     Ok(())
 }
 
-fn read_dir_filter_ext(dir: &PathBuf, ext: &str) -> io::Result<Vec<PathBuf>> {
-    let mut paths = Vec::new();
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_file() && path.extension() == Some(OsStr::new(ext)) {
-            paths.push(path);
-        }
+/// Writes a zstd copy of every brotli file in `folder`, for the `zstd`
+/// feature.
+fn recompress_folder_zstd(folder: &PathBuf) -> io::Result<()> {
+    for br_path in read_dir_filter_ext(folder, "br")? {
+        recompress_zstd(&br_path, &br_path.with_extension("zst"))?;
     }
-    Ok(paths)
+    Ok(())
 }
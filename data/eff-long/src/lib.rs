@@ -0,0 +1,32 @@
+//! The EFF long word list of the [`random_word`](https://crates.io/crates/random_word)
+//! crate, enabled by its `eff-long` feature.
+#![no_std]
+
+/// The words in byte-wise order, front-coded (each is a byte counting the
+/// leading bytes it shares with the previous word, then the rest of the word
+/// and a `\n`) and compressed with brotli, or with zstd under the `zstd`
+/// feature.
+#[cfg(not(feature = "zstd"))]
+pub static RAW: &[u8] = include_bytes!("../eff_long.br");
+#[cfg(feature = "zstd")]
+pub static RAW: &[u8] = include_bytes!("../eff_long.zst");
+
+/// Indexes of the words computed when the list was compressed, so that
+/// looking words up by length needs no scan. Compressed like [`RAW`]; see
+/// the `random_word` build script for the layout.
+#[cfg(not(feature = "zstd"))]
+pub static INDEX: &[u8] = include_bytes!("../eff_long.idx");
+#[cfg(feature = "zstd")]
+pub static INDEX: &[u8] = include_bytes!("../eff_long.idx.zst");
+
+/// The SHA-256 of the words, each followed by a `\n`, as lowercase hex,
+/// written with [`RAW`] from the same list, so that `random_word::verify`
+/// detects a payload that is corrupt or doesn't match it.
+pub const SHA256: &str = include_str!("../eff_long.sha256");
+
+/// The version of this crate. The words change only with it, so it names
+/// the dataset along with its hash.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Where the words come from.
+pub const SOURCE: &str = "EFF long word list, https://www.eff.org/deeplinks/2016/07/new-wordlists-random-passphrases, CC BY 3.0 US";
@@ -0,0 +1,9 @@
+// Builds the files this crate embeds from eff_short1.txt.
+#[path = "../wordlist.rs"]
+#[allow(dead_code)]
+mod wordlist;
+
+fn main() -> std::io::Result<()> {
+    println!("cargo:rerun-if-changed=../wordlist.rs");
+    wordlist::build("eff_short1")
+}
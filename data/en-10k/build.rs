@@ -0,0 +1,9 @@
+// Builds the files this crate embeds from en_10k.txt.
+#[path = "../wordlist.rs"]
+#[allow(dead_code)]
+mod wordlist;
+
+fn main() -> std::io::Result<()> {
+    println!("cargo:rerun-if-changed=../wordlist.rs");
+    wordlist::build("en_10k")
+}
@@ -0,0 +1,32 @@
+//! The 10,000 most common English words of the [`random_word`](https://crates.io/crates/random_word)
+//! crate, enabled by its `en-10k` feature.
+#![no_std]
+
+/// The words in byte-wise order, front-coded (each is a byte counting the
+/// leading bytes it shares with the previous word, then the rest of the word
+/// and a `\n`) and compressed with brotli, or with zstd under the `zstd`
+/// feature.
+#[cfg(not(feature = "zstd"))]
+pub static RAW: &[u8] = include_bytes!("../en_10k.br");
+#[cfg(feature = "zstd")]
+pub static RAW: &[u8] = include_bytes!("../en_10k.zst");
+
+/// Indexes of the words computed when the list was compressed, so that
+/// looking words up by length needs no scan. Compressed like [`RAW`]; see
+/// the `random_word` build script for the layout.
+#[cfg(not(feature = "zstd"))]
+pub static INDEX: &[u8] = include_bytes!("../en_10k.idx");
+#[cfg(feature = "zstd")]
+pub static INDEX: &[u8] = include_bytes!("../en_10k.idx.zst");
+
+/// The SHA-256 of the words, each followed by a `\n`, as lowercase hex,
+/// written with [`RAW`] from the same list, so that `random_word::verify`
+/// detects a payload that is corrupt or doesn't match it.
+pub const SHA256: &str = include_str!("../en_10k.sha256");
+
+/// The version of this crate. The words change only with it, so it names
+/// the dataset along with its hash.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Where the words come from.
+pub const SOURCE: &str = "The 10,000 most frequent ENABLE words by the zxcvbn English Wikipedia frequency list";
@@ -0,0 +1,183 @@
+// Builds the files each `random_word-data-*` crate embeds. Compiled into
+// every data crate's build.rs, and into the root build.rs for the helpers
+// at the end, so this file only uses std, brotli, sha2, unicode-normalization
+// and zstd.
+use brotli::{enc::backward_references::BrotliEncoderParams, CompressorWriter, Decompressor};
+use sha2::{Digest, Sha256};
+use std::{
+    ffi::OsStr,
+    fs::{self, File},
+    io::{self, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+// The checks of `random_word::dictionary::validate`.
+#[path = "../src/dictionary/validate.rs"]
+#[allow(dead_code)]
+mod validate;
+
+/// Builds the files of the data crate whose list is `<stem>.txt`, in the
+/// crate's directory, where its build script runs:
+///
+/// * `<stem>.br`, the front-coded words, and `<stem>.sha256`, their hash;
+/// * `<stem>.idx`, the precomputed indexes;
+/// * `<stem>.zst` and `<stem>.idx.zst`, zstd copies for the `zstd` feature.
+///
+/// Each is written again when older than the file it comes from. Published
+/// crates hold the built files without the list, and build nothing.
+pub fn build(stem: &str) -> io::Result<()> {
+    let txt_path = PathBuf::from(format!("{}.txt", stem));
+    if !txt_path.exists() {
+        println!("cargo:rerun-if-changed=build.rs");
+        return Ok(());
+    }
+    println!("cargo:rerun-if-changed={}", txt_path.display());
+    let br_path = txt_path.with_extension("br");
+    let sha256_path = txt_path.with_extension("sha256");
+    if is_stale(&br_path, &txt_path)? || is_stale(&sha256_path, &txt_path)? {
+        compress_word_list(&txt_path, &br_path, &sha256_path)?;
+    }
+    let idx_path = txt_path.with_extension("idx");
+    if is_stale(&idx_path, &br_path)? {
+        write_index(&br_path, &idx_path)?;
+    }
+    recompress_zstd(&br_path, &txt_path.with_extension("zst"))?;
+    recompress_zstd(&idx_path, &txt_path.with_extension("idx.zst"))
+}
+
+/// Compresses a word list front-coded: the words in byte-wise order, each
+/// written as a byte counting the leading bytes it shares with the previous
+/// word (at most 255), then the rest of the word and a `\n`. Sorted lists
+/// share long prefixes, so this roughly halves the compressed size of most
+/// lists.
+///
+/// Also writes the SHA-256 of the words, each followed by a `\n`, as
+/// lowercase hex, which `random_word::verify` checks the embedded words
+/// against.
+fn compress_word_list(txt_path: &Path, br_path: &PathBuf, sha256_path: &Path) -> io::Result<()> {
+    let text = fs::read_to_string(txt_path)?;
+    let mut words: Vec<&str> = text.lines().filter(|line| !line.is_empty()).collect();
+    warn_questionable(txt_path, &words);
+    words.sort_unstable();
+
+    let mut coded = Vec::new();
+    let mut hasher = Sha256::new();
+    let mut previous = "";
+    for word in words {
+        let shared = previous.bytes().zip(word.bytes()).take_while(|(a, b)| a == b).count().min(255);
+        coded.push(shared as u8);
+        coded.extend_from_slice(&word.as_bytes()[shared..]);
+        coded.push(b'\n');
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+        previous = word;
+    }
+    write_brotli(br_path, &coded)?;
+    let hex: String = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect();
+    fs::write(sha256_path, hex)
+}
+
+/// Warns about entries of a word list that lookups and hashes treat as
+/// different words from what a reader sees: duplicates, surrounding
+/// whitespace and strings not in Unicode Normalization Form C.
+fn warn_questionable(path: &Path, words: &[&str]) {
+    use validate::IssueKind;
+    let issues = validate::validate(words);
+    let count = |kind| issues.iter().filter(|issue| issue.kind == kind).count();
+    let (duplicates, whitespace, not_nfc) =
+        (count(IssueKind::Duplicate), count(IssueKind::Whitespace), count(IssueKind::NotNfc));
+    if duplicates + whitespace + not_nfc > 0 {
+        println!(
+            "cargo:warning={}: {} duplicate, {} whitespace-padded and {} non-NFC entries",
+            path.display(),
+            duplicates,
+            whitespace,
+            not_nfc
+        );
+    }
+}
+
+/// Reads the words of a front-coded list written by `compress_word_list`.
+fn expand(coded: &[u8]) -> io::Result<Vec<String>> {
+    let mut words: Vec<String> = Vec::new();
+    let mut rest = coded;
+    while let Some((&shared, tail)) = rest.split_first() {
+        let len = tail.iter().position(|&b| b == b'\n').unwrap_or(tail.len());
+        let mut word = words.last().map_or(Vec::new(), |previous| previous.as_bytes()[..shared as usize].to_vec());
+        word.extend_from_slice(&tail[..len]);
+        words.push(String::from_utf8(word).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?);
+        rest = tail.get(len + 1..).unwrap_or_default();
+    }
+    Ok(words)
+}
+
+fn write_brotli(path: &PathBuf, data: &[u8]) -> io::Result<()> {
+    let writer = BufWriter::new(File::create(path)?);
+    let params = BrotliEncoderParams::default();
+    let mut compressor =
+        CompressorWriter::new(writer, 4096, params.quality as u32, params.lgwin as u32);
+    compressor.write_all(data)?;
+    compressor.flush()
+}
+
+/// Writes the indexes of a compressed word list, so that the crate only
+/// decompresses them at run time. The file is a brotli stream of:
+///
+/// * the length in bytes of the expanded words, each followed by a `\n`, as
+///   a little-endian `u32`, so the crate allocates their text once;
+/// * a little-endian `u32` count of distinct first characters, then each
+///   as a `u32`, in ascending order;
+/// * the length in `char`s of each word, in the list's byte-wise order, as
+///   a `u8`.
+fn write_index(br_path: &PathBuf, idx_path: &PathBuf) -> io::Result<()> {
+    let mut coded = Vec::new();
+    Decompressor::new(File::open(br_path)?, 4096).read_to_end(&mut coded)?;
+    let words = expand(&coded)?;
+
+    let mut initials: Vec<u32> = words.iter().filter_map(|word| word.chars().next()).map(u32::from).collect();
+    initials.dedup();
+    let text_len: usize = words.iter().map(|word| word.len() + 1).sum();
+    let mut out = Vec::new();
+    out.extend(u32::try_from(text_len).expect("word lists are smaller than 4 GiB").to_le_bytes());
+    out.extend((initials.len() as u32).to_le_bytes());
+    for initial in initials {
+        out.extend(initial.to_le_bytes());
+    }
+    for word in &words {
+        let len = word.chars().count();
+        out.push(u8::try_from(len).expect("words are shorter than 256 chars"));
+    }
+    write_brotli(idx_path, &out)
+}
+
+/// Writes the brotli file `br_path` again as the zstd file `zst_path`,
+/// unless that is newer.
+pub fn recompress_zstd(br_path: &PathBuf, zst_path: &PathBuf) -> io::Result<()> {
+    if !is_stale(zst_path, br_path)? {
+        return Ok(());
+    }
+    let mut data = Vec::new();
+    Decompressor::new(File::open(br_path)?, 4096).read_to_end(&mut data)?;
+    fs::write(zst_path, zstd::encode_all(data.as_slice(), 19)?)
+}
+
+/// Returns whether `output` is missing or older than `input`.
+pub fn is_stale(output: &Path, input: &Path) -> io::Result<bool> {
+    match fs::metadata(output) {
+        Ok(meta) => Ok(meta.modified()? < fs::metadata(input)?.modified()?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(true),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn read_dir_filter_ext(dir: &PathBuf, ext: &str) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_file() && path.extension() == Some(OsStr::new(ext)) {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
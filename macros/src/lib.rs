@@ -0,0 +1,281 @@
+//! Procedural macros of the [`random_word`](https://crates.io/crates/random_word)
+//! crate, enabled by its `macros` feature: `embed_wordlist!` and
+//! `static_word!`. Use them through `random_word` rather than depending on
+//! this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::quote;
+use rand::SeedableRng;
+use rand::seq::IndexedRandom;
+use rand_chacha::ChaCha12Rng;
+#[cfg(feature = "clean")]
+use std::collections::HashSet;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitInt, LitStr, Token, parse_macro_input};
+
+/// Compresses a word list file at compile time and embeds it as a
+/// `random_word::EmbeddedList`.
+///
+/// The path is relative to the directory of the invoking crate's
+/// `Cargo.toml`. The file holds one word per line; surrounding whitespace is
+/// trimmed, empty lines and lines starting with `#` are skipped, and
+/// repeated words are kept once. A missing file, a word with control
+/// characters or a list without words is a compile error.
+///
+/// ```ignore
+/// use random_word::EmbeddedList;
+///
+/// static WORDS: EmbeddedList = random_word::embed_wordlist!("approved-words.txt");
+///
+/// let word = WORDS.get();
+/// ```
+#[proc_macro]
+pub fn embed_wordlist(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    match embed(&path) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn embed(lit: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from).unwrap_or_default();
+    let path = dir.join(lit.value());
+    let error = |message: String| syn::Error::new(lit.span(), format!("{}: {}", path.display(), message));
+    let text = std::fs::read_to_string(&path).map_err(|err| error(err.to_string()))?;
+
+    let mut words = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+        if word.contains(char::is_control) {
+            return Err(error(format!("line {} has a control character", i + 1)));
+        }
+        words.push(word);
+    }
+    words.sort_unstable();
+    words.dedup();
+    if words.is_empty() {
+        return Err(error("the word list has no words".into()));
+    }
+
+    let compressed = compress(&front_code(&words)).map_err(|err| error(err.to_string()))?;
+    let raw = Literal::byte_string(&compressed);
+    let path = path.to_string_lossy();
+    Ok(quote! {
+        {
+            // Makes cargo rebuild the invoking crate when the list changes.
+            const _: &[u8] = include_bytes!(#path);
+            ::random_word::EmbeddedList::__from_compressed(#raw)
+        }
+    })
+}
+
+/// Picks a random word at compile time and expands to it as a string
+/// literal, for generated identifiers and build tags where no RNG is
+/// available at run time.
+///
+/// The first argument is a language's feature name, such as `"en"`, which
+/// must be enabled. The word is the one `random_word::seeded(seed).get(lang)`
+/// returns at run time, and under random_word's `clean` feature is never one
+/// it drops. The seed is the `seed` argument if given, else the
+/// `SOURCE_DATE_EPOCH` environment variable, so that reproducible builds
+/// pick the same word, else the current time. Invocations sharing a seed
+/// pick the same word.
+///
+/// ```ignore
+/// const BUILD_TAG: &str = random_word::static_word!("en");
+/// const CODENAME: &str = random_word::static_word!("en", seed = 42);
+/// ```
+#[proc_macro]
+pub fn static_word(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as StaticWordArgs);
+    match pick(&args) {
+        Ok(word) => quote!(#word).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// The arguments of `static_word!`: a language and an optional
+/// `seed = <u64>`.
+struct StaticWordArgs {
+    lang: LitStr,
+    seed: Option<u64>,
+}
+
+impl Parse for StaticWordArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lang = input.parse()?;
+        let mut seed = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "seed" {
+                return Err(syn::Error::new(key.span(), "expected `seed`"));
+            }
+            input.parse::<Token![=]>()?;
+            seed = Some(input.parse::<LitInt>()?.base10_parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Self { lang, seed })
+    }
+}
+
+fn pick(args: &StaticWordArgs) -> syn::Result<String> {
+    let code = args.lang.value();
+    let raw = raw_words(&code).ok_or_else(|| {
+        syn::Error::new(
+            args.lang.span(),
+            format!("language \"{}\" is unknown or its random_word feature is not enabled", code),
+        )
+    })?;
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => match std::env::var("SOURCE_DATE_EPOCH") {
+            Ok(epoch) => epoch.trim().parse().map_err(|_| {
+                syn::Error::new(args.lang.span(), "SOURCE_DATE_EPOCH is not a number of seconds")
+            })?,
+            Err(_) => SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_nanos() as u64),
+        },
+    };
+
+    #[allow(unused_mut)]
+    let mut words = decompress(raw)
+        .and_then(|coded| expand(&coded))
+        .map_err(|err| syn::Error::new(args.lang.span(), err.to_string()))?;
+    #[cfg(feature = "clean")]
+    {
+        let banned = banned(&code);
+        words.retain(|word| !is_banned(word, &banned));
+    }
+    let word = words.choose(&mut ChaCha12Rng::seed_from_u64(seed)).expect("array is empty");
+    Ok(word.clone())
+}
+
+/// Returns the banned words of the language with the given feature name,
+/// from the same lists as random_word's `clean` feature.
+#[cfg(feature = "clean")]
+fn banned(code: &str) -> HashSet<&'static str> {
+    let list = match code.to_ascii_lowercase().as_str() {
+        "de" => include_str!("profanity/de.txt"),
+        "es" => include_str!("profanity/es.txt"),
+        "fr" => include_str!("profanity/fr.txt"),
+        "ja" => include_str!("profanity/ja.txt"),
+        "ru" => include_str!("profanity/ru.txt"),
+        "zh" => include_str!("profanity/zh.txt"),
+        _ => include_str!("profanity/en.txt"),
+    };
+    list.lines().filter(|line| !line.is_empty() && !line.starts_with('#')).collect()
+}
+
+/// Returns whether `entry` has a banned word, matching random_word's
+/// `clean` feature: each space-separated part is checked, without brackets
+/// and in lowercase.
+#[cfg(feature = "clean")]
+fn is_banned(entry: &str, banned: &HashSet<&str>) -> bool {
+    entry.split(' ').any(|part| {
+        let part = part.trim_matches(['[', ']']);
+        if part.chars().any(char::is_uppercase) {
+            banned.contains(part.to_lowercase().as_str())
+        } else {
+            banned.contains(part)
+        }
+    })
+}
+
+/// Front-codes sorted words the way random_word's embedded lists are: each
+/// word is a byte counting the leading bytes it shares with the previous
+/// word, then the rest of the word and a `\n`.
+fn front_code(words: &[&str]) -> Vec<u8> {
+    let mut coded = Vec::new();
+    let mut previous = "";
+    for &word in words {
+        let shared = previous.bytes().zip(word.bytes()).take_while(|(a, b)| a == b).count().min(255);
+        coded.push(shared as u8);
+        coded.extend_from_slice(&word.as_bytes()[shared..]);
+        coded.push(b'\n');
+        previous = word;
+    }
+    coded
+}
+
+/// Reads the words of a front-coded list, in byte-wise order.
+fn expand(coded: &[u8]) -> io::Result<Vec<String>> {
+    let mut words: Vec<String> = Vec::new();
+    let mut rest = coded;
+    while let Some((&shared, tail)) = rest.split_first() {
+        let len = tail.iter().position(|&b| b == b'\n').unwrap_or(tail.len());
+        let mut word = words.last().map_or(Vec::new(), |previous| previous.as_bytes()[..usize::from(shared)].to_vec());
+        word.extend_from_slice(&tail[..len]);
+        words.push(String::from_utf8(word).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?);
+        rest = tail.get(len + 1..).unwrap_or_default();
+    }
+    Ok(words)
+}
+
+/// Compresses `data` the way random_word's embedded lists are.
+fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+    #[cfg(feature = "zstd")]
+    return zstd::stream::encode_all(data, 19);
+    #[cfg(not(feature = "zstd"))]
+    {
+        use std::io::Write;
+        let mut compressed = Vec::new();
+        let mut compressor = brotli::CompressorWriter::new(&mut compressed, 4096, 11, 22);
+        compressor.write_all(data)?;
+        drop(compressor);
+        Ok(compressed)
+    }
+}
+
+/// Decompresses one of random_word's embedded lists.
+fn decompress(raw: &[u8]) -> io::Result<Vec<u8>> {
+    #[cfg(feature = "zstd")]
+    return zstd::stream::decode_all(raw);
+    #[cfg(not(feature = "zstd"))]
+    {
+        use std::io::Read;
+        let mut bytes = Vec::new();
+        brotli::Decompressor::new(raw, 4096).read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Returns the compressed words of the language with the given feature
+/// name, if enabled.
+fn raw_words(code: &str) -> Option<&'static [u8]> {
+    match code.to_ascii_lowercase().as_str() {
+        #[cfg(feature = "de")]
+        "de" => Some(random_word_data_de::RAW),
+        #[cfg(feature = "en")]
+        "en" => Some(random_word_data_en::RAW),
+        #[cfg(feature = "es")]
+        "es" => Some(random_word_data_es::RAW),
+        #[cfg(feature = "fr")]
+        "fr" => Some(random_word_data_fr::RAW),
+        #[cfg(feature = "ja")]
+        "ja" => Some(random_word_data_ja::RAW),
+        #[cfg(feature = "ru")]
+        "ru" => Some(random_word_data_ru::RAW),
+        #[cfg(feature = "zh")]
+        "zh" => Some(random_word_data_zh::RAW),
+        #[cfg(feature = "eff-long")]
+        "eff-long" => Some(random_word_data_eff_long::RAW),
+        #[cfg(feature = "eff-short1")]
+        "eff-short1" => Some(random_word_data_eff_short1::RAW),
+        #[cfg(feature = "eff-short2")]
+        "eff-short2" => Some(random_word_data_eff_short2::RAW),
+        #[cfg(feature = "en-10k")]
+        "en-10k" => Some(random_word_data_en_10k::RAW),
+        #[cfg(feature = "en-gb")]
+        "en-gb" => Some(random_word_data_en_gb::RAW),
+        #[cfg(feature = "en-us")]
+        "en-us" => Some(random_word_data_en_us::RAW),
+        _ => None,
+    }
+}
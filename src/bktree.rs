@@ -0,0 +1,96 @@
+/// A BK-tree over the words of one language, for finding every word within
+/// an edit distance of a query without comparing against all of them.
+///
+/// Each child edge is labelled with the distance between the child and its
+/// parent. By the triangle inequality, only children whose label is within
+/// `max` of the query's distance to the parent can hold matches.
+pub(crate) struct BkTree {
+    nodes: Vec<Node>,
+}
+
+struct Node {
+    word: &'static str,
+    children: Vec<(u32, u32)>,
+}
+
+impl BkTree {
+    pub(crate) fn new(words: &[&'static str]) -> Self {
+        let mut nodes: Vec<Node> = Vec::with_capacity(words.len());
+        let mut chars = Vec::new();
+        let mut row = Vec::new();
+        for &word in words {
+            if nodes.is_empty() {
+                nodes.push(Node { word, children: Vec::new() });
+                continue;
+            }
+            chars.clear();
+            chars.extend(word.chars());
+            let mut current = 0;
+            loop {
+                let dist = distance(&chars, nodes[current].word, &mut row) as u32;
+                if dist == 0 {
+                    break;
+                }
+                match nodes[current].children.iter().find(|&&(d, _)| d == dist) {
+                    Some(&(_, child)) => current = child as usize,
+                    None => {
+                        let child = nodes.len() as u32;
+                        nodes[current].children.push((dist, child));
+                        nodes.push(Node { word, children: Vec::new() });
+                        break;
+                    }
+                }
+            }
+        }
+        Self { nodes }
+    }
+
+    /// Returns every word within `max` edits of `query` with its distance.
+    pub(crate) fn find(&self, query: &str, max: usize) -> Vec<(usize, &'static str)> {
+        let mut found = Vec::new();
+        if self.nodes.is_empty() {
+            return found;
+        }
+        let chars: Vec<char> = query.chars().collect();
+        let mut row = Vec::new();
+        let mut stack = vec![0];
+        while let Some(current) = stack.pop() {
+            let node: &Node = &self.nodes[current];
+            let dist = distance(&chars, node.word, &mut row);
+            if dist <= max {
+                found.push((dist, node.word));
+            }
+            let range = dist.saturating_sub(max)..=dist + max;
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|&&(d, _)| range.contains(&(d as usize)))
+                    .map(|&(_, child)| child as usize),
+            );
+        }
+        found
+    }
+}
+
+/// Returns the Levenshtein distance between `a` and `b` in characters.
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let chars: Vec<char> = a.chars().collect();
+    distance(&chars, b, &mut Vec::new())
+}
+
+/// Returns the Levenshtein distance between `a` and `b`, reusing `row` as
+/// scratch space.
+fn distance(a: &[char], b: &str, row: &mut Vec<usize>) -> usize {
+    row.clear();
+    row.extend(0..=a.len());
+    for (j, cb) in b.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = j + 1;
+        for (i, &ca) in a.iter().enumerate() {
+            let substitution = diagonal + (ca != cb) as usize;
+            diagonal = row[i + 1];
+            row[i + 1] = substitution.min(row[i] + 1).min(diagonal + 1);
+        }
+    }
+    row[a.len()]
+}
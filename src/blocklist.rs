@@ -0,0 +1,123 @@
+use crate::{Dictionary, WordSource};
+use ahash::AHashSet;
+use std::{borrow::Cow, fs, io, path::Path, sync::Arc};
+
+/// A set of banned words, matched case-insensitively.
+///
+/// Passed to [`WordQuery::blocklist`](crate::WordQuery::blocklist) and
+/// [`PassphraseOptions::blocklist`](crate::PassphraseOptions::blocklist),
+/// and to `passgen` with `--blocklist`. For the selectors of a single list,
+/// such as [`get`](crate::get), [`get_len`](crate::get_len) and
+/// [`get_starts_with`](crate::get_starts_with), [`apply`](Self::apply) it
+/// to the language and draw from the resulting [`Dictionary`], whose
+/// selectors of the same names only return words the blocklist allows.
+///
+/// Cloning is cheap: clones share the same underlying set.
+///
+/// # Example
+/// ```
+/// use random_word::{Blocklist, Lang, WordQuery};
+/// let blocklist = Blocklist::new(["password", "Admin"]);
+/// assert!(blocklist.contains("ADMIN"));
+///
+/// let word = WordQuery::new(Lang::En)
+///     .blocklist(&blocklist)
+///     .one(&mut rand::rng());
+/// assert!(!blocklist.contains(word.unwrap()));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Blocklist {
+    words: Arc<AHashSet<Box<str>>>,
+}
+
+impl Blocklist {
+    /// Creates a blocklist from the given words.
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            words: Arc::new(words.into_iter().map(|word| fold(word.as_ref()).into()).collect()),
+        }
+    }
+
+    /// Returns a blocklist of the profanity, slurs and sexual terms the
+    /// `clean` feature drops from the given language, for filtering
+    /// dictionaries loaded at runtime the same way (feature = "clean").
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Blocklist, Lang};
+    /// let profanity = Blocklist::profanity(Lang::En);
+    /// assert!(profanity.contains("Shit"));
+    /// assert!(!profanity.contains("shiitake"));
+    /// ```
+    #[cfg(feature = "clean")]
+    pub fn profanity(lang: crate::Lang) -> Self {
+        Self::new(crate::profanity::banned(lang))
+    }
+
+    /// Returns a dictionary of the words of `source`, such as a
+    /// [`Lang`](crate::Lang), that aren't banned.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Blocklist, Lang};
+    /// let blocklist = Blocklist::new(["zebra"]);
+    /// let words = blocklist.apply(&Lang::En);
+    /// assert!(!words.contains("zebra") && words.contains("zebras"));
+    /// assert!(!blocklist.contains(words.get_len(5).unwrap()));
+    /// ```
+    pub fn apply(&self, source: &impl WordSource) -> Dictionary {
+        Dictionary::from_words(source.words().iter().copied().filter(|word| !self.contains(word)))
+    }
+
+    /// Reads a blocklist from a file with one word per line. Surrounding
+    /// whitespace is trimmed, and empty lines and lines starting with `#`
+    /// are skipped.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self::new(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#')),
+        ))
+    }
+
+    /// Returns whether `word` is banned.
+    #[inline(always)]
+    pub fn contains(&self, word: &str) -> bool {
+        !self.words.is_empty() && self.words.contains(&*fold(word))
+    }
+
+    /// Returns the number of banned words.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns whether no words are banned.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Blocklist {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Lowercases `word`, borrowing it when it already is, as most words
+/// checked against the blocklist are.
+#[inline(always)]
+fn fold(word: &str) -> Cow<'_, str> {
+    if word.chars().all(|ch| ch.to_lowercase().eq([ch])) {
+        Cow::Borrowed(word)
+    } else {
+        Cow::Owned(word.to_lowercase())
+    }
+}
@@ -0,0 +1,146 @@
+//! Offline checks against known breached passwords.
+//!
+//! A bloom filter of the 30,000 most common leaked passwords is bundled, and
+//! filters built from other lists, such as the SHA-1 hashes published by
+//! Have I Been Pwned, can be loaded with [`BloomFilter::from_bytes`].
+//! Enabled by the `breach` feature.
+//!
+//! A bloom filter never misses a listed password but may report an unlisted
+//! one as breached, with the false positive rate it was built for.
+//!
+//! # Example
+//! ```
+//! use random_word::breach;
+//! assert!(breach::is_breached("password1"));
+//! assert!(!breach::is_breached("correct-horse-battery-staple-87"));
+//! ```
+
+use crate::Error;
+use sha1::{Digest, Sha1};
+use std::sync::OnceLock;
+
+/// Identifies serialized filters and their format version.
+const MAGIC: &[u8; 8] = b"RWBLOOM1";
+
+/// The bundled filter of common leaked passwords, built with
+/// `BloomFilter::new(30_000, 1e-4)` from src/breach/passwords.txt.
+static BUNDLED_RAW: &[u8] = include_bytes!("breach/passwords.bloom");
+static BUNDLED: OnceLock<BloomFilter> = OnceLock::new();
+
+/// A bloom filter of passwords, keyed by their SHA-1 hashes.
+///
+/// # Example
+/// ```
+/// use random_word::breach::BloomFilter;
+/// let mut filter = BloomFilter::new(1000, 1e-6);
+/// filter.insert("hunter2");
+/// let filter = BloomFilter::from_bytes(&filter.to_bytes()).unwrap();
+/// assert!(filter.contains("hunter2"));
+/// assert!(!filter.contains("hunter3"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BloomFilter {
+    bits: Box<[u64]>,
+    hashes: u32,
+}
+
+impl BloomFilter {
+    /// Creates an empty filter sized for `items` passwords with the given
+    /// false positive rate.
+    ///
+    /// # Panics
+    /// Panics unless `false_positive_rate` is between 0 and 1, exclusive.
+    pub fn new(items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "the false positive rate must be between 0 and 1"
+        );
+        let ln2 = std::f64::consts::LN_2;
+        let bits = (-(items.max(1) as f64) * false_positive_rate.ln() / (ln2 * ln2)).ceil() as usize;
+        let words = bits.div_ceil(64).max(1);
+        let hashes = ((words * 64) as f64 / items.max(1) as f64 * ln2).round().clamp(1.0, 64.0) as u32;
+        Self {
+            bits: vec![0; words].into_boxed_slice(),
+            hashes,
+        }
+    }
+
+    /// Adds a password.
+    #[inline(always)]
+    pub fn insert(&mut self, password: &str) {
+        self.insert_sha1(Sha1::digest(password).into());
+    }
+
+    /// Adds a password by its SHA-1 hash, as listed by Have I Been Pwned.
+    pub fn insert_sha1(&mut self, sha1: [u8; 20]) {
+        for bit in self.positions(sha1) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns whether the password may have been inserted. `false` is
+    /// always right.
+    #[inline(always)]
+    pub fn contains(&self, password: &str) -> bool {
+        self.contains_sha1(Sha1::digest(password).into())
+    }
+
+    /// Returns whether the password with the given SHA-1 hash may have been
+    /// inserted.
+    pub fn contains_sha1(&self, sha1: [u8; 20]) -> bool {
+        self.positions(sha1).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Returns the bits set for a hash, by double hashing its first 16
+    /// bytes.
+    fn positions(&self, sha1: [u8; 20]) -> impl Iterator<Item = usize> + use<> {
+        let h1 = u64::from_le_bytes(sha1[..8].try_into().expect("8 bytes"));
+        let h2 = u64::from_le_bytes(sha1[8..16].try_into().expect("8 bytes")) | 1;
+        let len = self.bits.len() as u64 * 64;
+        (0..u64::from(self.hashes)).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % len) as usize)
+    }
+
+    /// Serializes the filter, to be loaded with [`from_bytes`](Self::from_bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + self.bits.len() * 8);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&self.hashes.to_le_bytes());
+        for word in &self.bits {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Loads a filter serialized by [`to_bytes`](Self::to_bytes), such as
+    /// one read with `std::fs::read`.
+    ///
+    /// Returns [`Error::InvalidFilter`] if the data isn't a serialized
+    /// filter.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let rest = bytes.strip_prefix(MAGIC).ok_or(Error::InvalidFilter)?;
+        let (hashes, bits) = rest.split_first_chunk::<4>().ok_or(Error::InvalidFilter)?;
+        let hashes = u32::from_le_bytes(*hashes);
+        if !(1..=64).contains(&hashes) || bits.is_empty() || !bits.len().is_multiple_of(8) {
+            return Err(Error::InvalidFilter);
+        }
+        Ok(Self {
+            bits: bits
+                .chunks_exact(8)
+                .map(|word| u64::from_le_bytes(word.try_into().expect("8 bytes")))
+                .collect(),
+            hashes,
+        })
+    }
+}
+
+/// Returns the bundled filter of the 30,000 most common leaked passwords.
+pub fn bundled() -> &'static BloomFilter {
+    BUNDLED.get_or_init(|| BloomFilter::from_bytes(BUNDLED_RAW).expect("the bundled filter is valid"))
+}
+
+/// Returns whether `password` is one of the most common leaked passwords,
+/// according to the [`bundled`] filter.
+#[inline(always)]
+pub fn is_breached(password: &str) -> bool {
+    bundled().contains(password)
+}
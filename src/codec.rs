@@ -0,0 +1,74 @@
+use crate::{Error, Lang, words};
+
+/// Returns the number of bits each word carries: the largest `k` with
+/// `2^k` distinct words in the list.
+pub(crate) fn bits_per_word(lang: Lang) -> u32 {
+    words::distinct(lang).len().ilog2()
+}
+
+/// Returns the word for `index` among the `2^bits` words spread evenly
+/// over the list, using the same stride as the dice mapping.
+fn word(index: u64, bits: u32, words: &[&'static str]) -> &'static str {
+    words[((index * words.len() as u64) >> bits) as usize]
+}
+
+/// Returns the index of `word` among the `2^bits` words spread evenly over
+/// the list, if it is one of them.
+fn index(word: &str, bits: u32, words: &[&'static str]) -> Option<u64> {
+    let position = words.binary_search(&word).ok()? as u64;
+    let len = words.len() as u64;
+    let index = (position << bits).div_ceil(len);
+    (index >> bits == 0 && (index * len) >> bits == position).then_some(index)
+}
+
+pub(crate) fn encode(bytes: &[u8], lang: Lang) -> Vec<&'static str> {
+    let words = words::distinct(lang);
+    let bits = bits_per_word(lang);
+    let mut encoded = Vec::with_capacity((bytes.len() * 8).div_ceil(bits as usize) + 1);
+    let (mut buffer, mut buffered) = (0u64, 0);
+    for &byte in bytes {
+        buffer = buffer << 8 | u64::from(byte);
+        buffered += 8;
+        while buffered >= bits {
+            buffered -= bits;
+            encoded.push(word(buffer >> buffered, bits, &words));
+            buffer &= (1 << buffered) - 1;
+        }
+    }
+    // The last word holds the leftover bits behind a leading one, which
+    // marks how many there are.
+    encoded.push(word(1 << buffered | buffer, bits, &words));
+    encoded
+}
+
+pub(crate) fn decode<S: AsRef<str>>(encoded: &[S], lang: Lang) -> Result<Vec<u8>, Error> {
+    let Some((last, full)) = encoded.split_last() else {
+        return Err(Error::InvalidWordCount(0));
+    };
+    let words = words::distinct(lang);
+    let bits = bits_per_word(lang);
+    let mut decoded = Vec::with_capacity(full.len() * bits as usize / 8);
+    let (mut buffer, mut buffered) = (0u64, 0);
+    for (position, word) in full.iter().enumerate() {
+        let index = index(word.as_ref(), bits, &words).ok_or(Error::UnknownWord(position))?;
+        buffer = buffer << bits | index;
+        buffered += bits;
+        while buffered >= 8 {
+            buffered -= 8;
+            decoded.push((buffer >> buffered) as u8);
+            buffer &= (1 << buffered) - 1;
+        }
+    }
+    let last = index(last.as_ref(), bits, &words).ok_or(Error::UnknownWord(full.len()))?;
+    let leftover = last.checked_ilog2().ok_or(Error::InvalidPadding)?;
+    buffer = buffer << leftover | last & ((1 << leftover) - 1);
+    buffered += leftover;
+    if !buffered.is_multiple_of(8) {
+        return Err(Error::InvalidPadding);
+    }
+    while buffered > 0 {
+        buffered -= 8;
+        decoded.push((buffer >> buffered) as u8);
+    }
+    Ok(decoded)
+}
@@ -0,0 +1,149 @@
+use crate::{Lang, words};
+use ahash::AHashMap;
+use rand::{Rng, prelude::IndexedRandom};
+
+/// Letter and position constraints for fixed-length words, as used by
+/// Wordle-style games.
+///
+/// Positions are zero-based character indexes.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, LetterConstraints};
+/// // Guessed "crane": 'r' is in place, 'a' is elsewhere, the rest are absent.
+/// let constraints = LetterConstraints::new(5).feedback("crane", "bgybb");
+/// let words = constraints.all(Lang::En);
+/// assert!(words.contains(&"array"));
+/// assert!(words.iter().all(|w| w.chars().nth(1) == Some('r') && w.contains('a')));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LetterConstraints {
+    fixed: Vec<Option<char>>,
+    not_at: Vec<Vec<char>>,
+    min_counts: AHashMap<char, usize>,
+    excluded: Vec<char>,
+}
+
+impl LetterConstraints {
+    /// Creates constraints matching every word of `len` characters.
+    pub fn new(len: usize) -> Self {
+        Self {
+            fixed: vec![None; len],
+            not_at: vec![Vec::new(); len],
+            min_counts: AHashMap::new(),
+            excluded: Vec::new(),
+        }
+    }
+
+    /// Requires `char` at `position`.
+    ///
+    /// # Panics
+    /// Panics if `position` is out of range.
+    pub fn at(mut self, position: usize, char: char) -> Self {
+        self.fixed[position] = Some(char);
+        self
+    }
+
+    /// Requires `char` somewhere in the word, but not at `position`.
+    ///
+    /// # Panics
+    /// Panics if `position` is out of range.
+    pub fn not_at(mut self, position: usize, char: char) -> Self {
+        self.not_at[position].push(char);
+        self.require(char, 1)
+    }
+
+    /// Requires `char` to occur at least `count` times.
+    pub fn require(mut self, char: char, count: usize) -> Self {
+        let min = self.min_counts.entry(char).or_insert(0);
+        *min = (*min).max(count);
+        self
+    }
+
+    /// Excludes every character of `chars`. A character that is also
+    /// required may still occur, but no more often than required.
+    pub fn excludes(mut self, chars: &str) -> Self {
+        self.excluded.extend(chars.chars());
+        self
+    }
+
+    /// Applies the result of a guess. Each mark in `marks` describes the
+    /// character at the same position of `guess`: `g` is in the right place,
+    /// `y` is in the word elsewhere, and anything else is absent.
+    ///
+    /// # Panics
+    /// Panics if `guess` is longer than the constraint length.
+    pub fn feedback(mut self, guess: &str, marks: &str) -> Self {
+        let mut found: AHashMap<char, usize> = AHashMap::new();
+        let mut absent = String::new();
+        for (position, (char, mark)) in guess.chars().zip(marks.chars()).enumerate() {
+            match mark {
+                'g' | 'G' => {
+                    self = self.at(position, char);
+                    *found.entry(char).or_insert(0) += 1;
+                }
+                'y' | 'Y' => {
+                    self.not_at[position].push(char);
+                    *found.entry(char).or_insert(0) += 1;
+                }
+                _ => {
+                    self.not_at[position].push(char);
+                    absent.push(char);
+                }
+            }
+        }
+        for (char, count) in found {
+            self = self.require(char, count);
+        }
+        self.excludes(&absent)
+    }
+
+    /// Returns whether `word` satisfies every constraint.
+    pub fn matches(&self, word: &str) -> bool {
+        let mut counts: AHashMap<char, usize> = AHashMap::new();
+        let mut len = 0;
+        for (position, char) in word.chars().enumerate() {
+            if position >= self.fixed.len()
+                || self.fixed[position].is_some_and(|fixed| fixed != char)
+                || self.not_at[position].contains(&char)
+            {
+                return false;
+            }
+            *counts.entry(char).or_insert(0) += 1;
+            len += 1;
+        }
+        if len != self.fixed.len() {
+            return false;
+        }
+
+        let required = |char: &char| self.min_counts.get(char).copied().unwrap_or(0);
+        self.min_counts
+            .iter()
+            .all(|(char, min)| counts.get(char).copied().unwrap_or(0) >= *min)
+            && self
+                .excluded
+                .iter()
+                .all(|char| counts.get(char).copied().unwrap_or(0) <= required(char))
+    }
+
+    /// Returns every word of the given language satisfying the constraints.
+    pub fn all(&self, lang: Lang) -> Vec<&'static str> {
+        words::get_len(self.fixed.len(), lang)
+            .map(|bucket| bucket.iter().copied().filter(|word| self.matches(word)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns a random word of the given language satisfying the
+    /// constraints, using the supplied RNG.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, LetterConstraints};
+    /// let constraints = LetterConstraints::new(5).at(0, 's').not_at(4, 'e');
+    /// let word = constraints.one(Lang::En, &mut rand::rng()).unwrap();
+    /// assert!(word.starts_with('s') && word.contains('e') && !word.ends_with('e'));
+    /// ```
+    pub fn one(&self, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+        self.all(lang).choose(rng).copied()
+    }
+}
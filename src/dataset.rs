@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// Identifies the word list a language embeds, returned by
+/// [`Lang::dataset_version`](crate::Lang::dataset_version).
+///
+/// Anything derived from positions in a list, such as diceware rolls,
+/// [`encode_bytes`](crate::encode_bytes) output or a seeded word, changes
+/// when the list does. Record the version with such data, or compare it
+/// against a pinned one at startup, to notice a list that changed between
+/// crate versions instead of silently deriving different words.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let version = Lang::En.dataset_version();
+/// assert_eq!(version.sha256, random_word::sha256(Lang::En));
+/// assert!(version.to_string().starts_with(version.version));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct DatasetVersion {
+    /// The version of the `random_word-data-*` crate holding the list.
+    pub version: &'static str,
+    /// The SHA-256 of the full list, as checked by [`verify`](crate::verify).
+    /// Equal hashes mean equal words, whatever the versions.
+    pub sha256: &'static str,
+    /// Where the words come from: the upstream dictionary and its license.
+    pub source: &'static str,
+    /// Whether the `clean` feature drops profanity from the list, so that
+    /// [`all`](crate::all) holds fewer words than the hash covers.
+    pub clean: bool,
+}
+
+impl fmt::Display for DatasetVersion {
+    /// Formats the version and hash, such as `0.5.2+sha256:30590540…`, and
+    /// `+clean` under the `clean` feature.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}+sha256:{}", self.version, self.sha256)?;
+        if self.clean {
+            write!(f, "+clean")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+//! Word lists loaded at runtime, as [`Dictionary`], and with the `validate`
+//! feature, checks and fixes for the lists themselves: `validate` reports
+//! duplicates, uppercase letters, surrounding whitespace, strings not in
+//! Unicode Normalization Form C, and words with digits or punctuation, and
+//! `normalize` fixes them.
+//!
+//! Run them over a custom list before loading it, or over an embedded one
+//! to audit it. The build checks the embedded lists for duplicates,
+//! whitespace and non-NFC strings when it compresses them.
+
+use crate::{WordSource, default_rng, pool_entropy, words};
+use ahash::AHashMap;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::sync::{Arc, OnceLock};
+use std::{fmt, fs, io, path::Path};
+
+#[cfg(feature = "validate")]
+mod validate;
+#[cfg(feature = "validate")]
+pub use validate::{Fixes, Issue, IssueKind, normalize, validate};
+
+/// A word list loaded at runtime, such as a company-approved or
+/// domain-specific list, with the same lookups as the embedded languages.
+///
+/// Words are deduplicated and kept in byte-wise order, like [`all`](crate::all).
+/// Their text lives as long as the program, like the embedded lists, so that
+/// lookups return `&'static str` too; load a dictionary once and clone it
+/// rather than loading it again. Cloning is cheap: clones share the same
+/// words and indexes.
+///
+/// # Example
+/// ```
+/// use random_word::Dictionary;
+/// let dictionary = Dictionary::new(["apple", "banana", "cherry", "avocado"]);
+/// assert_eq!(dictionary.len(), 4);
+/// assert_eq!(dictionary.all_starts_with('a'), Some(&["apple", "avocado"][..]));
+/// assert_eq!(dictionary.all_len(6), Some(&["banana", "cherry"][..]));
+/// assert!(dictionary.contains(dictionary.get().unwrap()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Dictionary {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    words: Box<[&'static str]>,
+    len_index: OnceLock<AHashMap<usize, Box<[&'static str]>>>,
+}
+
+impl Dictionary {
+    /// Creates a dictionary from the given words. Empty strings are
+    /// skipped.
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        // One allocation holds every word, rather than one per word.
+        let mut text = String::new();
+        let mut ends = Vec::new();
+        for word in words {
+            text.push_str(word.as_ref());
+            ends.push(text.len());
+        }
+        let text: &'static str = Box::leak(text.into_boxed_str());
+        Self::from_words(ends.iter().scan(0, |start, &end| Some(&text[std::mem::replace(start, end)..end])))
+    }
+
+    /// Reads a dictionary from a UTF-8 file with one word per line.
+    /// Surrounding whitespace is trimmed, and empty lines and lines starting
+    /// with `#` are skipped.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::from_text(fs::read_to_string(path)?))
+    }
+
+    /// Reads a dictionary from UTF-8 text with one word per line, such as a
+    /// list embedded with `include_bytes!`, like
+    /// [`from_file`](Self::from_file).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, std::str::Utf8Error> {
+        Ok(Self::from_text(std::str::from_utf8(bytes)?.to_owned()))
+    }
+
+    fn from_text(text: String) -> Self {
+        Self::from_words(
+            Box::leak(text.into_boxed_str())
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.starts_with('#')),
+        )
+    }
+
+    /// Sorts, deduplicates and stores words whose text is already static.
+    pub(crate) fn from_words(words: impl IntoIterator<Item = &'static str>) -> Self {
+        let mut words: Vec<&'static str> = words.into_iter().filter(|word| !word.is_empty()).collect();
+        words.sort_unstable();
+        words.dedup();
+        Self {
+            inner: Arc::new(Inner {
+                words: words.into_boxed_slice(),
+                len_index: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// Returns the number of words.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.inner.words.len()
+    }
+
+    /// Returns whether the dictionary has no words.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.inner.words.is_empty()
+    }
+
+    /// Returns whether `word` is in the dictionary.
+    #[inline(always)]
+    pub fn contains(&self, word: &str) -> bool {
+        self.inner.words.binary_search(&word).is_ok()
+    }
+
+    /// Returns all words, in byte-wise order.
+    #[inline(always)]
+    pub fn all(&self) -> &[&'static str] {
+        &self.inner.words
+    }
+
+    /// Returns a random word, or `None` if the dictionary is empty.
+    #[inline(always)]
+    pub fn get(&self) -> Option<&'static str> {
+        self.get_with_rng(&mut default_rng())
+    }
+
+    /// Returns a random word using the supplied RNG, or `None` if the
+    /// dictionary is empty.
+    #[inline(always)]
+    pub fn get_with_rng(&self, rng: &mut impl Rng) -> Option<&'static str> {
+        self.inner.words.choose(rng).copied()
+    }
+
+    /// Returns all words with the given length in `char`s.
+    pub fn all_len(&self, len: usize) -> Option<&[&'static str]> {
+        self.inner
+            .len_index
+            .get_or_init(|| {
+                let mut map = AHashMap::new();
+                for &word in self.inner.words.iter() {
+                    map.entry(word.chars().count()).or_insert_with(Vec::new).push(word);
+                }
+                map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
+            })
+            .get(&len)
+            .map(|words| &**words)
+    }
+
+    /// Returns a random word with the given length in `char`s.
+    #[inline(always)]
+    pub fn get_len(&self, len: usize) -> Option<&'static str> {
+        self.get_len_with_rng(len, &mut default_rng())
+    }
+
+    /// Returns a random word with the given length in `char`s, using the
+    /// supplied RNG.
+    #[inline(always)]
+    pub fn get_len_with_rng(&self, len: usize, rng: &mut impl Rng) -> Option<&'static str> {
+        self.all_len(len)?.choose(rng).copied()
+    }
+
+    /// Returns all words with the given starting character.
+    pub fn all_starts_with(&self, char: char) -> Option<&[&'static str]> {
+        let words = words::prefix_range(&self.inner.words, char.encode_utf8(&mut [0; 4]));
+        (!words.is_empty()).then_some(words)
+    }
+
+    /// Returns a random word with the given starting character.
+    #[inline(always)]
+    pub fn get_starts_with(&self, char: char) -> Option<&'static str> {
+        self.get_starts_with_rng(char, &mut default_rng())
+    }
+
+    /// Returns a random word with the given starting character, using the
+    /// supplied RNG.
+    #[inline(always)]
+    pub fn get_starts_with_rng(&self, char: char, rng: &mut impl Rng) -> Option<&'static str> {
+        self.all_starts_with(char)?.choose(rng).copied()
+    }
+
+    /// Returns a dictionary of the words for which `predicate` returns
+    /// `true`, such as those matching a [`WordQuery`](crate::WordQuery).
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Dictionary, Lang, WordQuery};
+    /// let dictionary = Dictionary::new(["ant", "bee", "beetle", "moth"]);
+    /// let query = WordQuery::new(Lang::En).len(3..=4).excluding("a");
+    /// let filtered = dictionary.filter(|word| query.matches(word));
+    /// assert_eq!(filtered.all(), ["bee", "moth"]);
+    /// ```
+    pub fn filter(&self, mut predicate: impl FnMut(&str) -> bool) -> Self {
+        Self::from_words(self.inner.words.iter().copied().filter(|word| predicate(word)))
+    }
+
+    /// Returns a dictionary of the words in either this dictionary or
+    /// `other`, which may be a [`Lang`](crate::Lang) or another dictionary.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Dictionary;
+    /// let fruit = Dictionary::new(["apple", "fig"]);
+    /// let trees = Dictionary::new(["fig", "oak"]);
+    /// assert_eq!(fruit.union(&trees).all(), ["apple", "fig", "oak"]);
+    /// ```
+    pub fn union(&self, other: &impl WordSource) -> Self {
+        Self::from_words(self.inner.words.iter().chain(other.words()).copied())
+    }
+
+    /// Returns a dictionary of the words in both this dictionary and
+    /// `other`, such as the English words that are also on an approved
+    /// list.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Dictionary, Lang};
+    /// let approved = Dictionary::new(["zebra", "battery", "xyzzy"]);
+    /// assert_eq!(approved.intersection(&Lang::En).all(), ["battery", "zebra"]);
+    /// ```
+    pub fn intersection(&self, other: &impl WordSource) -> Self {
+        self.filter(|word| other.contains(word))
+    }
+
+    /// Returns a dictionary of the words in this dictionary but not in
+    /// `other`, such as a language minus a blocklist.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Dictionary;
+    /// let words = Dictionary::new(["apple", "fig", "oak"]);
+    /// let trees = Dictionary::new(["oak", "elm"]);
+    /// assert_eq!(words.difference(&trees).all(), ["apple", "fig"]);
+    /// ```
+    pub fn difference(&self, other: &impl WordSource) -> Self {
+        self.filter(|word| !other.contains(word))
+    }
+
+    /// Returns the entropy in bits of one word drawn uniformly from the
+    /// dictionary.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Dictionary;
+    /// let dictionary = Dictionary::new(["north", "south", "east", "west"]);
+    /// assert_eq!(dictionary.entropy_bits(), 2.0);
+    /// ```
+    #[inline(always)]
+    pub fn entropy_bits(&self) -> f64 {
+        pool_entropy(self.len())
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for Dictionary {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl fmt::Debug for Dictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dictionary").field("len", &self.len()).finish()
+    }
+}
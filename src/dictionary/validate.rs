@@ -0,0 +1,183 @@
+// Also compiled into build.rs to check the embedded lists, so this file only
+// uses std and unicode-normalization.
+use std::collections::HashSet;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// A kind of questionable entry reported by [`validate`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum IssueKind {
+    /// The word repeats an earlier one.
+    Duplicate,
+    /// The word has an uppercase letter, as in a proper noun or acronym.
+    /// Lookups are case-sensitive, so it only matches with the same
+    /// capitals.
+    MixedCase,
+    /// The word starts or ends with whitespace.
+    Whitespace,
+    /// The word isn't in Unicode Normalization Form C, so it doesn't equal
+    /// the same word as most keyboards type it.
+    NotNfc,
+    /// The word has a digit.
+    Digit,
+    /// The word has punctuation or a symbol: a character other than a
+    /// letter, digit, space or combining mark.
+    Punctuation,
+}
+
+/// A questionable entry found by [`validate`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Issue<'a> {
+    /// The position of the word in the validated words, from zero.
+    pub index: usize,
+    /// The word as given.
+    pub word: &'a str,
+    /// What is questionable about it.
+    pub kind: IssueKind,
+}
+
+/// The fixes [`normalize`] applies. Duplicates, including those the other
+/// fixes create, and words left empty are always dropped.
+///
+/// The default only makes fixes that don't change how a word reads:
+/// `trim` and `nfc`.
+///
+/// # Example
+/// ```
+/// use random_word::dictionary::Fixes;
+/// let fixes = Fixes { lowercase: true, ..Fixes::default() };
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Fixes {
+    /// Trims surrounding whitespace.
+    pub trim: bool,
+    /// Converts words to Unicode Normalization Form C.
+    pub nfc: bool,
+    /// Converts words to lowercase.
+    pub lowercase: bool,
+    /// Drops words with a digit.
+    pub drop_digits: bool,
+    /// Drops words with punctuation or a symbol.
+    pub drop_punctuation: bool,
+}
+
+impl Fixes {
+    /// Only drops duplicates and empty words.
+    pub const NONE: Self = Self {
+        trim: false,
+        nfc: false,
+        lowercase: false,
+        drop_digits: false,
+        drop_punctuation: false,
+    };
+
+    /// Every fix, leaving no issue [`validate`] reports.
+    pub const ALL: Self = Self {
+        trim: true,
+        nfc: true,
+        lowercase: true,
+        drop_digits: true,
+        drop_punctuation: true,
+    };
+}
+
+impl Default for Fixes {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            trim: true,
+            nfc: true,
+            ..Self::NONE
+        }
+    }
+}
+
+/// Reports the questionable entries of a word list: duplicates, words with
+/// uppercase letters, surrounding whitespace, words not in Unicode
+/// Normalization Form C, and words with digits or punctuation. A word with
+/// several issues is reported once per kind, in the order of [`IssueKind`].
+///
+/// # Example
+/// ```
+/// use random_word::dictionary::{self, IssueKind};
+/// let issues = dictionary::validate(&["apple", "Paris", "apple", "e-mail"]);
+/// let kinds: Vec<IssueKind> = issues.iter().map(|issue| issue.kind).collect();
+/// assert_eq!(kinds, [IssueKind::MixedCase, IssueKind::Duplicate, IssueKind::Punctuation]);
+/// assert_eq!(issues[1].index, 2);
+/// ```
+pub fn validate<'a, S>(words: impl IntoIterator<Item = &'a S>) -> Vec<Issue<'a>>
+where
+    S: AsRef<str> + ?Sized + 'a,
+{
+    let mut seen = HashSet::new();
+    let mut issues = Vec::new();
+    for (index, word) in words.into_iter().enumerate() {
+        let word = word.as_ref();
+        let mut report = |kind| issues.push(Issue { index, word, kind });
+        if !seen.insert(word) {
+            report(IssueKind::Duplicate);
+        }
+        if word.chars().any(char::is_uppercase) {
+            report(IssueKind::MixedCase);
+        }
+        if word.trim() != word {
+            report(IssueKind::Whitespace);
+        }
+        if !unicode_normalization::is_nfc(word) {
+            report(IssueKind::NotNfc);
+        }
+        if has_digit(word) {
+            report(IssueKind::Digit);
+        }
+        if has_punctuation(word) {
+            report(IssueKind::Punctuation);
+        }
+    }
+    issues
+}
+
+/// Applies `fixes` to a word list, keeping the first of any duplicates, in
+/// the given order.
+///
+/// # Example
+/// ```
+/// use random_word::dictionary::{self, Fixes};
+/// let words = dictionary::normalize(&[" apple", "Apple", "caf\u{65}\u{301}", "2fa"], Fixes::ALL);
+/// assert_eq!(words, ["apple", "caf\u{e9}"]);
+/// assert!(dictionary::validate(&words).is_empty());
+/// ```
+pub fn normalize<'a, S>(words: impl IntoIterator<Item = &'a S>, fixes: Fixes) -> Vec<String>
+where
+    S: AsRef<str> + ?Sized + 'a,
+{
+    let mut seen = HashSet::new();
+    let mut fixed = Vec::new();
+    for word in words {
+        let word = word.as_ref();
+        let mut word = if fixes.trim { word.trim() } else { word }.to_owned();
+        // Lowercasing can decompose characters, so it comes first.
+        if fixes.lowercase {
+            word = word.to_lowercase();
+        }
+        if fixes.nfc {
+            word = word.nfc().collect();
+        }
+        let dropped = word.is_empty()
+            || fixes.drop_digits && has_digit(&word)
+            || fixes.drop_punctuation && has_punctuation(&word);
+        if !dropped && seen.insert(word.clone()) {
+            fixed.push(word);
+        }
+    }
+    fixed
+}
+
+fn has_digit(word: &str) -> bool {
+    word.chars().any(char::is_numeric)
+}
+
+fn has_punctuation(word: &str) -> bool {
+    word.chars()
+        .any(|c| !(c.is_alphanumeric() || c.is_whitespace() || is_combining_mark(c)))
+}
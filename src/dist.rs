@@ -0,0 +1,99 @@
+use crate::{Lang, WordQuery, all, all_ends_with, all_len, all_starts_with};
+use rand::Rng;
+use rand::distr::Distribution;
+use std::borrow::Cow;
+
+/// A uniform distribution over the words of one language, optionally
+/// filtered, for use with [`Rng::sample`] and [`Distribution::sample_iter`].
+///
+/// A `WordDist` is never empty, so sampling always returns a word.
+///
+/// # Example
+/// ```
+/// use rand::Rng;
+/// use random_word::{Lang, WordDist};
+/// let dist = WordDist::len(5, Lang::En).unwrap();
+/// let word = rand::rng().sample(&dist);
+/// assert_eq!(word.len(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordDist {
+    words: Cow<'static, [&'static str]>,
+}
+
+impl WordDist {
+    /// Creates a distribution over all words with the given language.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::distr::Distribution;
+    /// use random_word::{Lang, WordDist};
+    /// let words: Vec<&str> = WordDist::new(Lang::En).sample_iter(rand::rng()).take(4).collect();
+    /// assert_eq!(words.len(), 4);
+    /// ```
+    #[inline(always)]
+    pub fn new(lang: Lang) -> Self {
+        Self {
+            words: Cow::Borrowed(all(lang)),
+        }
+    }
+
+    /// Creates a distribution over the words with the given length and
+    /// language, or `None` if there are none.
+    #[inline(always)]
+    pub fn len(len: usize, lang: Lang) -> Option<Self> {
+        all_len(len, lang).map(Self::borrowed)
+    }
+
+    /// Creates a distribution over the words with the given starting
+    /// character and language, or `None` if there are none.
+    #[inline(always)]
+    pub fn starts_with(char: char, lang: Lang) -> Option<Self> {
+        all_starts_with(char, lang).map(Self::borrowed)
+    }
+
+    /// Creates a distribution over the words with the given ending
+    /// character and language, or `None` if there are none.
+    #[inline(always)]
+    pub fn ends_with(char: char, lang: Lang) -> Option<Self> {
+        all_ends_with(char, lang).map(Self::borrowed)
+    }
+
+    /// Creates a distribution over the words matching `query`, or `None` if
+    /// there are none.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::Rng;
+    /// use random_word::{Lang, WordDist, WordQuery};
+    /// let query = WordQuery::new(Lang::En).starts_with('c').ends_with('t');
+    /// let dist = WordDist::from_query(&query).unwrap();
+    /// let word = rand::rng().sample(&dist);
+    /// assert!(word.starts_with('c') && word.ends_with('t'));
+    /// ```
+    pub fn from_query(query: &WordQuery) -> Option<Self> {
+        let words = query.all();
+        (!words.is_empty()).then_some(Self {
+            words: Cow::Owned(words),
+        })
+    }
+
+    /// Returns the words this distribution samples from.
+    #[inline(always)]
+    pub fn words(&self) -> &[&'static str] {
+        &self.words
+    }
+
+    fn borrowed(words: &'static [&'static str]) -> Self {
+        Self {
+            words: Cow::Borrowed(words),
+        }
+    }
+}
+
+impl Distribution<&'static str> for WordDist {
+    #[inline(always)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &'static str {
+        self.words[rng.random_range(0..self.words.len())]
+    }
+}
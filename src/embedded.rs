@@ -0,0 +1,81 @@
+use crate::{Dictionary, WordSource, words};
+use std::ops::Deref;
+use std::{fmt, sync::OnceLock};
+
+/// A word list compressed and embedded in the binary at compile time by
+/// [`embed_wordlist!`](crate::embed_wordlist), like the built-in languages
+/// (feature = "macros").
+///
+/// It dereferences to a [`Dictionary`], decompressed on first use, so it has
+/// the same lookups and needs no file at run time.
+///
+/// # Example
+/// ```
+/// use random_word::EmbeddedList;
+///
+/// static WORDS: EmbeddedList = random_word::embed_wordlist!("data/eff-short1/eff_short1.txt");
+///
+/// assert_eq!(WORDS.len(), 1296);
+/// assert!(WORDS.contains(WORDS.get().unwrap()));
+/// assert!(WORDS.all_len(3).is_some());
+/// ```
+pub struct EmbeddedList {
+    raw: &'static [u8],
+    dictionary: OnceLock<Dictionary>,
+}
+
+impl EmbeddedList {
+    /// Wraps the compressed, front-coded words emitted by `embed_wordlist!`.
+    #[doc(hidden)]
+    pub const fn __from_compressed(raw: &'static [u8]) -> Self {
+        Self {
+            raw,
+            dictionary: OnceLock::new(),
+        }
+    }
+
+    /// Returns the words as a [`Dictionary`], decompressing them on the
+    /// first call. Clone it to pass the words to a
+    /// [`WordQuery`](crate::WordQuery).
+    pub fn dictionary(&self) -> &Dictionary {
+        self.dictionary
+            .get_or_init(|| Dictionary::from_words(words::expand(words::decoder(self.raw), 0, 0).1))
+    }
+}
+
+impl Deref for EmbeddedList {
+    type Target = Dictionary;
+
+    #[inline(always)]
+    fn deref(&self) -> &Dictionary {
+        self.dictionary()
+    }
+}
+
+impl fmt::Debug for EmbeddedList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmbeddedList").field("len", &self.len()).finish()
+    }
+}
+
+impl WordSource for EmbeddedList {
+    #[inline(always)]
+    fn words(&self) -> &[&'static str] {
+        self.all()
+    }
+
+    #[inline(always)]
+    fn all_len(&self, len: usize) -> Option<&[&'static str]> {
+        Dictionary::all_len(self, len)
+    }
+
+    #[inline(always)]
+    fn all_starts_with(&self, char: char) -> Option<&[&'static str]> {
+        Dictionary::all_starts_with(self, char)
+    }
+
+    #[inline(always)]
+    fn contains(&self, word: &str) -> bool {
+        Dictionary::contains(self, word)
+    }
+}
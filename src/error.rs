@@ -0,0 +1,85 @@
+use crate::Lang;
+use std::fmt;
+
+/// The error type of the fallible `try_*` functions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The language with the given code exists but its crate feature is
+    /// not enabled.
+    LangNotEnabled(&'static str),
+    /// The string is neither a supported language code nor a language name.
+    UnknownLang,
+    /// No word satisfies the requested filter.
+    NoWordsForFilter,
+    /// The embedded word data for the language does not match its
+    /// expected SHA-256 hash.
+    CorruptDictionary(Lang),
+    /// A die roll was outside 1 to 6.
+    DiceRollOutOfRange(u8),
+    /// The number of die rolls is not a multiple of the rolls needed per
+    /// word.
+    DiceRollCount {
+        /// The rolls read per word.
+        per_word: usize,
+        /// The rolls supplied.
+        rolls: usize,
+    },
+    /// Entropy for a mnemonic must be 16, 20, 24, 28 or 32 bytes long.
+    InvalidEntropyLength(usize),
+    /// A mnemonic must have 12, 15, 18, 21 or 24 words, and an S/KEY
+    /// password six.
+    InvalidWordCount(usize),
+    /// The word at the given position is not in the wordlist.
+    UnknownWord(usize),
+    /// The word at the given position belongs to a different position, as
+    /// when a word was skipped or swapped.
+    WordOutOfOrder(usize),
+    /// The template has a malformed placeholder at the given byte offset.
+    InvalidTemplate(usize),
+    /// The last word of encoded bytes doesn't end them on a byte boundary.
+    InvalidPadding,
+    /// The data is not a serialized bloom filter.
+    InvalidFilter,
+    /// The checksum of an encoded value doesn't match its data.
+    InvalidChecksum,
+    /// No passphrase satisfying the password policy could be generated.
+    PolicyUnsatisfiable,
+    /// The passphrase options can't produce the requested number of
+    /// distinct passphrases.
+    TooFewPassphrases,
+    /// The requested entropy is not a finite number of bits.
+    InvalidEntropyTarget,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::LangNotEnabled(code) => {
+                write!(f, "language {:?} is not enabled; enable the {:?} crate feature", code, code)
+            }
+            Error::UnknownLang => write!(f, "unknown language"),
+            Error::NoWordsForFilter => write!(f, "no words match the filter"),
+            Error::CorruptDictionary(lang) => write!(f, "the word data for {:?} is corrupt", lang),
+            Error::DiceRollOutOfRange(roll) => write!(f, "die roll {} is not between 1 and 6", roll),
+            Error::DiceRollCount { per_word, rolls } => {
+                write!(f, "{} die rolls is not a multiple of the {} rolls per word", rolls, per_word)
+            }
+            Error::InvalidEntropyLength(len) => {
+                write!(f, "{} bytes of entropy is not 16, 20, 24, 28 or 32", len)
+            }
+            Error::InvalidWordCount(count) => write!(f, "{} words is not a valid length", count),
+            Error::UnknownWord(position) => write!(f, "word {} is not in the wordlist", position + 1),
+            Error::WordOutOfOrder(position) => write!(f, "word {} is out of order", position + 1),
+            Error::InvalidTemplate(offset) => write!(f, "invalid placeholder at byte {} of the template", offset),
+            Error::InvalidPadding => write!(f, "the words do not end on a byte boundary"),
+            Error::InvalidFilter => write!(f, "the data is not a serialized bloom filter"),
+            Error::InvalidChecksum => write!(f, "the checksum does not match"),
+            Error::PolicyUnsatisfiable => write!(f, "no passphrase satisfies the password policy"),
+            Error::TooFewPassphrases => write!(f, "the options cannot produce that many distinct passphrases"),
+            Error::InvalidEntropyTarget => write!(f, "the requested entropy is not a finite number of bits"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
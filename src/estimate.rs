@@ -0,0 +1,329 @@
+use crate::{Lang, contains, contains_ignore_case, frequency_rank};
+
+/// Characters of input beyond this are ignored, as the matching below is
+/// quadratic in the input length.
+const MAX_INPUT_CHARS: usize = 128;
+
+/// Dictionary tokens longer than this are not looked up.
+const MAX_WORD_CHARS: usize = 32;
+
+/// Printable ASCII characters that are neither letters nor digits.
+const SYMBOL_CARDINALITY: f64 = 33.0;
+
+/// Guesses for a 4-digit year, roughly the span of years people pick.
+const YEAR_GUESSES: f64 = 200.0;
+
+/// What a [`Match`] recognised in the input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchKind {
+    /// A word from the list of the given language.
+    Dictionary(Lang),
+    /// A single character repeated, such as `"aaaa"`.
+    Repeat,
+    /// Characters with a constant step, such as `"abcd"` or `"9876"`.
+    Sequence,
+    /// A year between 1900 and 2099.
+    Year,
+    /// A separator character seen earlier in the input.
+    Separator,
+    /// Characters matching no pattern, guessed one by one.
+    Bruteforce,
+}
+
+/// A part of the input and the number of guesses it costs an attacker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match {
+    /// The matched text.
+    pub token: String,
+    /// What the text was recognised as.
+    pub kind: MatchKind,
+    /// The base-2 logarithm of the guesses needed for this part.
+    pub bits: f64,
+}
+
+/// The estimated strength of a password or passphrase, returned by
+/// [`estimate`](crate::estimate).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate {
+    /// The base-2 logarithm of the guesses an attacker needs.
+    pub bits: f64,
+    /// The parts the input was split into, in order.
+    pub matches: Vec<Match>,
+}
+
+impl Estimate {
+    /// Returns a score from 0 (guessable in under a thousand tries) to 4
+    /// (more than ten billion tries), on the scale used by zxcvbn.
+    ///
+    /// # Example
+    /// ```
+    /// assert_eq!(random_word::estimate("abc123").score(), 0);
+    /// ```
+    pub fn score(&self) -> u8 {
+        match self.bits {
+            b if b < 3.0 * 10f64.log2() => 0,
+            b if b < 6.0 * 10f64.log2() => 1,
+            b if b < 8.0 * 10f64.log2() => 2,
+            b if b < 10.0 * 10f64.log2() => 3,
+            _ => 4,
+        }
+    }
+
+    /// Returns the expected seconds to find the input at the given guessing
+    /// rate, assuming it is found halfway through the search.
+    ///
+    /// # Example
+    /// ```
+    /// let estimate = random_word::estimate("correct-horse-battery-staple");
+    /// assert!(estimate.crack_seconds(1e10) > 1.0);
+    /// ```
+    pub fn crack_seconds(&self, guesses_per_second: f64) -> f64 {
+        self.bits.exp2() / 2.0 / guesses_per_second
+    }
+}
+
+/// Splits `input` into the cheapest sequence of matches to guess.
+pub(crate) fn estimate(input: &str) -> Estimate {
+    let chars: Vec<char> = input.chars().take(MAX_INPUT_CHARS).collect();
+    let candidates = candidates(&chars);
+
+    // best[j] holds the fewest bits to guess chars[..j] and the match
+    // ending there.
+    let mut best: Vec<(f64, Option<usize>)> = vec![(f64::INFINITY, None); chars.len() + 1];
+    best[0].0 = 0.0;
+    for end in 1..=chars.len() {
+        for (index, candidate) in candidates.iter().enumerate().filter(|(_, c)| c.end == end) {
+            let bits = best[candidate.start].0 + candidate.bits;
+            if bits < best[end].0 {
+                best[end] = (bits, Some(index));
+            }
+        }
+    }
+
+    let mut matches: Vec<Match> = Vec::new();
+    let mut end = chars.len();
+    while end > 0 {
+        let candidate = &candidates[best[end].1.expect("every character has a bruteforce match")];
+        let token: String = chars[candidate.start..candidate.end].iter().collect();
+        match matches.last_mut() {
+            // Runs of guessed characters read better as one match.
+            Some(last) if last.kind == MatchKind::Bruteforce && candidate.kind == MatchKind::Bruteforce => {
+                last.token.insert_str(0, &token);
+                last.bits += candidate.bits;
+            }
+            _ => matches.push(Match {
+                token,
+                kind: candidate.kind,
+                bits: candidate.bits,
+            }),
+        }
+        end = candidate.start;
+    }
+    matches.reverse();
+    Estimate {
+        bits: best[chars.len()].0,
+        matches,
+    }
+}
+
+struct Candidate {
+    start: usize,
+    end: usize,
+    kind: MatchKind,
+    bits: f64,
+}
+
+fn candidates(chars: &[char]) -> Vec<Candidate> {
+    let mut found = Vec::new();
+    let mut seen_separators = Vec::new();
+    for (i, &char) in chars.iter().enumerate() {
+        found.push(Candidate {
+            start: i,
+            end: i + 1,
+            kind: MatchKind::Bruteforce,
+            bits: cardinality(char).log2(),
+        });
+        if !char.is_alphanumeric() {
+            if seen_separators.contains(&char) {
+                // Reusing a separator costs one guess of whether it repeats.
+                found.push(Candidate {
+                    start: i,
+                    end: i + 1,
+                    kind: MatchKind::Separator,
+                    bits: 1.0,
+                });
+            } else {
+                seen_separators.push(char);
+            }
+        }
+    }
+    dictionary(chars, &mut found);
+    repeats(chars, &mut found);
+    sequences(chars, &mut found);
+    years(chars, &mut found);
+    found
+}
+
+fn cardinality(char: char) -> f64 {
+    match char {
+        '0'..='9' => 10.0,
+        'a'..='z' | 'A'..='Z' => 26.0,
+        _ if char.is_ascii() => SYMBOL_CARDINALITY,
+        // Letters of other scripts come from far larger alphabets.
+        _ => 100.0,
+    }
+}
+
+fn dictionary(chars: &[char], found: &mut Vec<Candidate>) {
+    for start in 0..chars.len() {
+        for end in start + 1..=chars.len().min(start + MAX_WORD_CHARS) {
+            let token: String = chars[start..end].iter().collect();
+            let mut best = lookup(&token);
+            if best.is_none() && token.chars().any(char::is_alphabetic) {
+                // Undo leet spellings, charging a bit per replaced letter.
+                best = ['i', 'l']
+                    .into_iter()
+                    .filter_map(|one| unleet(&token, one))
+                    .filter_map(|(word, replaced)| lookup(&word).map(|(lang, bits)| (lang, bits + replaced as f64)))
+                    .min_by(|a, b| a.1.total_cmp(&b.1));
+            }
+            if let Some((lang, bits)) = best {
+                found.push(Candidate {
+                    start,
+                    end,
+                    kind: MatchKind::Dictionary(lang),
+                    bits,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the language in which `token` is cheapest to guess as a word,
+/// and the bits to guess it.
+fn lookup(token: &str) -> Option<(Lang, f64)> {
+    let lower = token.to_lowercase();
+    Lang::ALL
+        .iter()
+        .filter(|&&lang| contains_ignore_case(token, lang))
+        .map(|&lang| {
+            // Words listed capitalized, such as German nouns, cost
+            // nothing extra when written that way.
+            let case = if contains(token, lang) { 0.0 } else { case_bits(token) };
+            (lang, word_guesses(&lower, lang).log2() + case)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Returns `token` with the replacements of [`leet`](crate::leet) undone,
+/// reading `1` as `one`, and the number of characters replaced, or `None`
+/// if nothing was replaced.
+fn unleet(token: &str, one: char) -> Option<(String, usize)> {
+    let mut replaced = 0;
+    let word = token
+        .chars()
+        .map(|c| {
+            let letter = match c {
+                '4' | '@' => 'a',
+                '8' => 'b',
+                '3' => 'e',
+                '9' => 'g',
+                '1' => one,
+                '!' => 'i',
+                '0' => 'o',
+                '5' | '$' => 's',
+                '7' => 't',
+                '2' => 'z',
+                _ => return c,
+            };
+            replaced += 1;
+            letter
+        })
+        .collect();
+    (replaced > 0).then_some((word, replaced))
+}
+
+/// Returns the guesses to find `word` in the given language, trying words
+/// by frequency when it is known and the whole list otherwise.
+fn word_guesses(word: &str, lang: Lang) -> f64 {
+    match frequency_rank(word, lang) {
+        Some(rank) => (rank + 1) as f64,
+        None => lang.word_count() as f64,
+    }
+}
+
+/// Returns the extra bits for the capitalization of a dictionary word.
+fn case_bits(token: &str) -> f64 {
+    let upper = token.chars().filter(|c| c.is_uppercase()).count();
+    if upper == 0 {
+        return 0.0;
+    }
+    let first_only = upper == 1 && token.chars().next().is_some_and(char::is_uppercase);
+    let all = !token.chars().any(char::is_lowercase);
+    if first_only || all {
+        1.0
+    } else {
+        // Any mix is possible; charge a bit for each cased letter.
+        token.chars().filter(|c| c.is_lowercase() || c.is_uppercase()).count() as f64
+    }
+}
+
+fn repeats(chars: &[char], found: &mut Vec<Candidate>) {
+    let mut start = 0;
+    while start < chars.len() {
+        let end = start + chars[start..].iter().take_while(|&&c| c == chars[start]).count();
+        if end - start >= 3 {
+            found.push(Candidate {
+                start,
+                end,
+                kind: MatchKind::Repeat,
+                bits: (cardinality(chars[start]) * (end - start) as f64).log2(),
+            });
+        }
+        start = end;
+    }
+}
+
+fn sequences(chars: &[char], found: &mut Vec<Candidate>) {
+    let mut start = 0;
+    while start + 2 < chars.len() {
+        let step = chars[start + 1] as i64 - chars[start] as i64;
+        let mut end = start + 2;
+        if step.abs() == 1 {
+            while end < chars.len() && chars[end] as i64 - chars[end - 1] as i64 == step {
+                end += 1;
+            }
+        }
+        if end - start >= 3 {
+            // Sequences starting at an end of the alphabet are tried first.
+            let base = match chars[start] {
+                'a' | 'A' | 'z' | 'Z' | '0' | '1' | '9' => 4.0,
+                _ => cardinality(chars[start]),
+            };
+            let direction = if step < 0 { 2.0 } else { 1.0 };
+            found.push(Candidate {
+                start,
+                end,
+                kind: MatchKind::Sequence,
+                bits: (base * direction * (end - start) as f64).log2(),
+            });
+            start = end - 1;
+        } else {
+            start += 1;
+        }
+    }
+}
+
+fn years(chars: &[char], found: &mut Vec<Candidate>) {
+    for start in 0..chars.len().saturating_sub(3) {
+        let token: String = chars[start..start + 4].iter().collect();
+        if token.parse::<u32>().is_ok_and(|year| (1900..=2099).contains(&year)) {
+            found.push(Candidate {
+                start,
+                end: start + 4,
+                kind: MatchKind::Year,
+                bits: YEAR_GUESSES.log2(),
+            });
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Dictionary, LoadError, load};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+
+/// Limits and checks applied by [`Dictionary::from_url`] (feature = "http").
+///
+/// # Example
+/// ```
+/// use random_word::FetchOptions;
+/// let options = FetchOptions {
+///     sha256: Some("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".into()),
+///     ..FetchOptions::default()
+/// };
+/// assert_eq!(options.max_bytes, 16 << 20);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FetchOptions {
+    /// The largest response body accepted, in bytes. 16 MiB by default,
+    /// several times the largest embedded list.
+    pub max_bytes: u64,
+    /// The expected SHA-256 of the response body as a hex string, checked
+    /// before the list is parsed. Unchecked by default.
+    pub sha256: Option<String>,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            max_bytes: 16 << 20,
+            sha256: None,
+        }
+    }
+}
+
+impl Dictionary {
+    /// Downloads a word list and reads it like [`from_path`](Self::from_path),
+    /// detecting its format from the data and the last segment of the URL
+    /// (feature = "http").
+    ///
+    /// This blocks the calling thread; see
+    /// [`from_url_async`](Self::from_url_async) for async code.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use random_word::{Dictionary, FetchOptions};
+    /// let dictionary = Dictionary::from_url(
+    ///     "https://www.eff.org/files/2016/07/18/eff_large_wordlist.txt",
+    ///     &FetchOptions::default(),
+    /// )?;
+    /// assert_eq!(dictionary.len(), 7776);
+    /// # Ok::<(), random_word::LoadError>(())
+    /// ```
+    pub fn from_url(url: &str, options: &FetchOptions) -> Result<Self, LoadError> {
+        let mut response = ureq::get(url).call().map_err(|err| LoadError::Http(err.into()))?;
+        let mut bytes = Vec::new();
+        response
+            .body_mut()
+            .as_reader()
+            .take(options.max_bytes.saturating_add(1))
+            .read_to_end(&mut bytes)?;
+        finish(bytes, url, options)
+    }
+
+    /// Downloads a word list like [`from_url`](Self::from_url) without
+    /// blocking. Must be awaited within a Tokio runtime
+    /// (feature = "http-async").
+    #[cfg(feature = "http-async")]
+    pub async fn from_url_async(url: &str, options: &FetchOptions) -> Result<Self, LoadError> {
+        let http = |err: reqwest::Error| LoadError::Http(err.into());
+        let mut response = reqwest::get(url).await.and_then(|response| response.error_for_status()).map_err(http)?;
+        if response.content_length().is_some_and(|len| len > options.max_bytes) {
+            return Err(LoadError::TooLarge(options.max_bytes));
+        }
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(http)? {
+            bytes.extend_from_slice(&chunk);
+            if bytes.len() as u64 > options.max_bytes {
+                break;
+            }
+        }
+        finish(bytes, url, options)
+    }
+}
+
+/// Checks a downloaded body against `options` and parses it.
+fn finish(bytes: Vec<u8>, url: &str, options: &FetchOptions) -> Result<Dictionary, LoadError> {
+    if bytes.len() as u64 > options.max_bytes {
+        return Err(LoadError::TooLarge(options.max_bytes));
+    }
+    if let Some(expected) = &options.sha256 {
+        let digest = Sha256::digest(&bytes);
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        if !hex.eq_ignore_ascii_case(expected.trim()) {
+            return Err(LoadError::ChecksumMismatch);
+        }
+    }
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    load::parse(bytes, path.rsplit('/').next().unwrap_or_default())
+}
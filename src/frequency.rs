@@ -0,0 +1,103 @@
+use crate::words::Words;
+use crate::{Lang, WeightModel};
+use ahash::AHashMap;
+use rand::distr::weighted::WeightedIndex;
+use std::sync::OnceLock;
+
+#[cfg(any(feature = "en", feature = "en-10k"))]
+static EN_RANK_RAW: &[u8] = include_compressed!("rank/en");
+
+/// The frequency data of one list, built on first use.
+#[cfg_attr(not(any(feature = "en", feature = "en-10k")), allow(dead_code))]
+struct Ranking {
+    /// How many of the ranked words of the language the list holds.
+    limit: usize,
+    ranked: OnceLock<Words>,
+    ranks: OnceLock<AHashMap<&'static str, u32>>,
+    weighted: [OnceLock<WeightedIndex<f64>>; 2],
+}
+
+#[cfg_attr(not(any(feature = "en", feature = "en-10k")), allow(dead_code))]
+impl Ranking {
+    const fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            ranked: OnceLock::new(),
+            ranks: OnceLock::new(),
+            weighted: [const { OnceLock::new() }; 2],
+        }
+    }
+
+    /// Reads the ranked words of `lang`, most frequent first.
+    #[cfg_attr(not(feature = "clean"), allow(unused_variables))]
+    fn read(&self, lang: Lang) -> Words {
+        #[cfg(any(feature = "en", feature = "en-10k"))]
+        {
+            static TEXT: OnceLock<String> = OnceLock::new();
+            let words = TEXT.get_or_init(|| crate::words::decompress(EN_RANK_RAW)).lines().take(self.limit);
+            #[cfg(feature = "clean")]
+            let words = {
+                let profanity = crate::profanity::Profanity::of(lang);
+                words.filter(move |word| !profanity.matches(word))
+            };
+            words.collect()
+        }
+        #[cfg(not(any(feature = "en", feature = "en-10k")))]
+        unreachable!("no language has frequency data")
+    }
+}
+
+#[cfg(feature = "en")]
+static EN: Ranking = Ranking::new(usize::MAX);
+// `Lang::En10k` holds exactly the 10,000 most frequent English words.
+#[cfg(feature = "en-10k")]
+static EN_10K: Ranking = Ranking::new(10_000);
+
+/// Returns the frequency data of the given language, if it has any.
+fn ranking(lang: Lang) -> Option<&'static Ranking> {
+    match lang {
+        #[cfg(feature = "en")]
+        Lang::En => Some(&EN),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => Some(&EN_10K),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Builds a map from each word to its position in `ranked`.
+fn rank_index(ranked: &[&'static str]) -> AHashMap<&'static str, u32> {
+    ranked.iter().enumerate().map(|(rank, &word)| (word, rank as u32)).collect()
+}
+
+/// Returns the words of the given language that have frequency data, most
+/// frequent first, or `None` if the language has no frequency data.
+pub(crate) fn ranked(lang: Lang) -> Option<&'static [&'static str]> {
+    let ranking = ranking(lang)?;
+    Some(ranking.ranked.get_or_init(|| ranking.read(lang)))
+}
+
+/// Returns the zero-based frequency rank of `word`, where `0` is the most
+/// frequent word of the language.
+pub(crate) fn rank(word: &str, lang: Lang) -> Option<usize> {
+    ranking(lang)?
+        .ranks
+        .get_or_init(|| rank_index(ranked(lang).unwrap_or_default()))
+        .get(word)
+        .map(|&rank| rank as usize)
+}
+
+/// Returns the distribution over the [`ranked`] words of the given language
+/// under `model`, or `None` for [`WeightModel::Uniform`] and languages
+/// without frequency data.
+pub(crate) fn weighted(model: WeightModel, lang: Lang) -> Option<&'static WeightedIndex<f64>> {
+    let slot = match model {
+        WeightModel::Uniform => return None,
+        WeightModel::Zipf => 0,
+        WeightModel::Corpus => 1,
+    };
+    Some(ranking(lang)?.weighted[slot].get_or_init(|| {
+        let ranked = ranked(lang).unwrap_or_default();
+        WeightedIndex::new((0..ranked.len()).map(|rank| model.weight(rank))).expect("weights are positive")
+    }))
+}
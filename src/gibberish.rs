@@ -0,0 +1,236 @@
+//! Pronounceable made-up words assembled from syllables, such as `brokatemi`
+//! or `tsuhomira`.
+//!
+//! Each language has a small syllable inventory: onset consonants paired
+//! with vowels, or for Japanese and Chinese a list of open Hepburn and
+//! pinyin syllables. Words are drawn uniformly from every sequence of
+//! syllables with the target length in `char`s. Every syllable ends in its
+//! only vowel, so a word splits into syllables in exactly one way and
+//! [`Generator::entropy_bits`] is exact rather than an estimate.
+//!
+//! # Example
+//! ```
+//! use random_word::Lang;
+//! use random_word::gibberish::{self, Generator};
+//!
+//! let word = gibberish::generate(10, Lang::En);
+//! assert_eq!(word.chars().count(), 10);
+//!
+//! let generator = Generator::new(Lang::En).length(12);
+//! assert!(generator.entropy_bits() > 40.0);
+//! ```
+
+use crate::{Lang, default_rng};
+use rand::Rng;
+
+/// Onsets paired with every English vowel.
+#[cfg(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us"))]
+const EN_ONSETS: &[&str] = &[
+    "", "b", "c", "d", "f", "g", "h", "j", "k", "l", "m", "n", "p", "r", "s", "t", "v", "w", "z", "bl", "br", "ch", "cl",
+    "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "sh", "sk", "sl", "sm", "sn", "sp", "st", "str", "th", "tr",
+];
+#[cfg(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us"))]
+const EN_VOWELS: &[&str] = &["a", "e", "i", "o", "u"];
+
+#[cfg(feature = "de")]
+const DE_ONSETS: &[&str] = &[
+    "", "b", "d", "f", "g", "h", "k", "l", "m", "n", "p", "r", "s", "t", "w", "z", "br", "kl", "kr", "pf", "sch", "schl",
+    "schw", "sp", "st", "tr",
+];
+#[cfg(feature = "de")]
+const DE_VOWELS: &[&str] = &["a", "e", "i", "o", "u", "ä", "ö", "ü"];
+
+#[cfg(feature = "es")]
+const ES_ONSETS: &[&str] = &[
+    "", "b", "c", "d", "f", "g", "j", "l", "m", "n", "p", "r", "s", "t", "v", "z", "ch", "ll", "ñ", "bl", "br", "cl",
+    "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "tr",
+];
+#[cfg(feature = "es")]
+const ES_VOWELS: &[&str] = &["a", "e", "i", "o", "u"];
+
+#[cfg(feature = "fr")]
+const FR_ONSETS: &[&str] = &[
+    "", "b", "d", "f", "g", "j", "l", "m", "n", "p", "r", "s", "t", "v", "ch", "bl", "br", "cl", "cr", "dr", "fl", "fr",
+    "gl", "gr", "pl", "pr", "tr",
+];
+#[cfg(feature = "fr")]
+const FR_VOWELS: &[&str] = &["a", "e", "i", "o", "u", "é"];
+
+#[cfg(feature = "ru")]
+const RU_ONSETS: &[&str] = &[
+    "", "б", "в", "г", "д", "ж", "з", "к", "л", "м", "н", "п", "р", "с", "т", "ф", "х", "ц", "ч", "ш", "щ", "бр", "вр",
+    "гр", "др", "кр", "пр", "тр", "ск", "сп", "ст",
+];
+#[cfg(feature = "ru")]
+const RU_VOWELS: &[&str] = &["а", "е", "и", "о", "у", "ю", "я"];
+
+/// Open Hepburn syllables. The moraic `n` is left out, since it would make
+/// words like `nanu` split ambiguously.
+#[cfg(feature = "ja")]
+const JA_SYLLABLES: &[&str] = &[
+    "a", "i", "u", "e", "o", "ka", "ki", "ku", "ke", "ko", "sa", "shi", "su", "se", "so", "ta", "chi", "tsu", "te", "to",
+    "na", "ni", "nu", "ne", "no", "ha", "hi", "fu", "he", "ho", "ma", "mi", "mu", "me", "mo", "ya", "yu", "yo", "ra",
+    "ri", "ru", "re", "ro", "wa", "ga", "gi", "gu", "ge", "go", "za", "ji", "zu", "ze", "zo", "da", "de", "do", "ba",
+    "bi", "bu", "be", "bo", "pa", "pi", "pu", "pe", "po", "kya", "kyu", "kyo", "sha", "shu", "sho", "cha", "chu", "cho",
+    "nya", "nyu", "nyo", "hya", "hyu", "hyo", "mya", "myu", "myo", "rya", "ryu", "ryo", "gya", "gyu", "gyo", "ja",
+    "ju", "jo", "bya", "byu", "byo", "pya", "pyu", "pyo",
+];
+
+/// Toneless pinyin syllables with a single-vowel final.
+#[cfg(feature = "zh")]
+const ZH_SYLLABLES: &[&str] = &[
+    "a", "o", "e", "ba", "bo", "bi", "bu", "pa", "po", "pi", "pu", "ma", "mo", "me", "mi", "mu", "fa", "fo", "fu", "da",
+    "de", "di", "du", "ta", "te", "ti", "tu", "na", "ne", "ni", "nu", "la", "le", "li", "lu", "ga", "ge", "gu", "ka",
+    "ke", "ku", "ha", "he", "hu", "ji", "ju", "qi", "qu", "xi", "xu", "zha", "zhe", "zhi", "zhu", "cha", "che", "chi",
+    "chu", "sha", "she", "shi", "shu", "re", "ri", "ru", "za", "ze", "zi", "zu", "ca", "ce", "ci", "cu", "sa", "se",
+    "si", "su", "ya", "ye", "yi", "yo", "yu", "wa", "wo", "wu",
+];
+
+/// Returns every onset paired with every vowel.
+#[cfg_attr(
+    not(any(
+        feature = "de",
+        feature = "en",
+        feature = "es",
+        feature = "fr",
+        feature = "ru",
+        feature = "eff-long",
+        feature = "eff-short1",
+        feature = "eff-short2",
+        feature = "en-10k",
+        feature = "en-gb",
+        feature = "en-us"
+    )),
+    allow(dead_code)
+)]
+fn product(onsets: &[&str], vowels: &[&str]) -> Vec<String> {
+    onsets
+        .iter()
+        .flat_map(|onset| vowels.iter().map(move |vowel| format!("{onset}{vowel}")))
+        .collect()
+}
+
+/// Returns the syllable inventory of the given language.
+fn inventory(lang: Lang) -> Vec<String> {
+    match lang {
+        #[cfg(feature = "de")]
+        Lang::De => product(DE_ONSETS, DE_VOWELS),
+        #[cfg(feature = "en")]
+        Lang::En => product(EN_ONSETS, EN_VOWELS),
+        #[cfg(feature = "es")]
+        Lang::Es => product(ES_ONSETS, ES_VOWELS),
+        #[cfg(feature = "fr")]
+        Lang::Fr => product(FR_ONSETS, FR_VOWELS),
+        #[cfg(feature = "ja")]
+        Lang::Ja => JA_SYLLABLES.iter().map(|&syllable| syllable.to_owned()).collect(),
+        #[cfg(feature = "ru")]
+        Lang::Ru => product(RU_ONSETS, RU_VOWELS),
+        #[cfg(feature = "zh")]
+        Lang::Zh => ZH_SYLLABLES.iter().map(|&syllable| syllable.to_owned()).collect(),
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => product(EN_ONSETS, EN_VOWELS),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => product(EN_ONSETS, EN_VOWELS),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => product(EN_ONSETS, EN_VOWELS),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => product(EN_ONSETS, EN_VOWELS),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => product(EN_ONSETS, EN_VOWELS),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => product(EN_ONSETS, EN_VOWELS),
+    }
+}
+
+/// Generates made-up words of one language and length.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    syllables: Vec<String>,
+    length: usize,
+    /// `ways[n]` is the number of syllable sequences `n` chars long.
+    ways: Vec<f64>,
+}
+
+impl Generator {
+    /// Creates a generator of 8-character words from the language's
+    /// syllable inventory.
+    pub fn new(lang: Lang) -> Self {
+        Self {
+            syllables: inventory(lang),
+            length: 0,
+            ways: vec![1.0],
+        }
+        .length(8)
+    }
+
+    /// Sets the length of generated words in `char`s.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length;
+        self.ways = vec![1.0];
+        for n in 1..=length {
+            let ways = self
+                .syllables
+                .iter()
+                .filter_map(|syllable| n.checked_sub(syllable.chars().count()))
+                .map(|rest| self.ways[rest])
+                .sum();
+            self.ways.push(ways);
+        }
+        self
+    }
+
+    /// Returns the syllables words are assembled from, for auditing.
+    pub fn syllables(&self) -> impl Iterator<Item = &str> {
+        self.syllables.iter().map(String::as_str)
+    }
+
+    /// Generates a word.
+    #[inline(always)]
+    pub fn generate(&self) -> String {
+        self.generate_rng(&mut default_rng())
+    }
+
+    /// Generates a word using the supplied RNG.
+    pub fn generate_rng(&self, rng: &mut impl Rng) -> String {
+        let mut word = String::new();
+        let mut remaining = self.length;
+        while remaining > 0 {
+            // Pick each syllable in proportion to the number of words that
+            // can follow it, so every word is equally likely.
+            let mut target = rng.random::<f64>() * self.ways[remaining];
+            let mut candidates = self.syllables.iter().filter_map(|syllable| {
+                let rest = remaining.checked_sub(syllable.chars().count())?;
+                Some((syllable, rest)).filter(|_| self.ways[rest] > 0.0)
+            });
+            let mut choice = candidates.next().expect("every length has a word");
+            for candidate in candidates {
+                target -= self.ways[choice.1];
+                if target < 0.0 {
+                    break;
+                }
+                choice = candidate;
+            }
+            word.push_str(choice.0);
+            remaining = choice.1;
+        }
+        word
+    }
+
+    /// Returns the number of distinct words the generator can produce.
+    pub fn combinations(&self) -> f64 {
+        self.ways[self.length]
+    }
+
+    /// Returns the entropy of a generated word in bits.
+    #[inline(always)]
+    pub fn entropy_bits(&self) -> f64 {
+        self.combinations().log2()
+    }
+}
+
+/// Generates a made-up word of `length` `char`s from the syllables of the
+/// given language.
+#[inline(always)]
+pub fn generate(length: usize, lang: Lang) -> String {
+    Generator::new(lang).length(length).generate()
+}
@@ -0,0 +1,62 @@
+use crate::Lang;
+#[cfg(feature = "zh")]
+use crate::words::{self, Words};
+#[cfg(feature = "zh")]
+use ahash::AHashMap;
+use std::ops::RangeInclusive;
+#[cfg(feature = "zh")]
+use std::sync::OnceLock;
+
+/// The HSK levels, from 1 for beginners to 6.
+pub(crate) const LEVELS: RangeInclusive<u8> = 1..=6;
+
+/// The simplified form of each word of the HSK vocabulary, with its level.
+#[cfg(feature = "zh")]
+static ZH_RAW: &[u8] = include_compressed!("hsk/zh");
+#[cfg(feature = "zh")]
+static ZH_TEXT: OnceLock<String> = OnceLock::new();
+#[cfg(feature = "zh")]
+static ZH_LEVELS: OnceLock<AHashMap<&'static str, u8>> = OnceLock::new();
+#[cfg(feature = "zh")]
+static ZH: [OnceLock<Words>; 6] = [const { OnceLock::new() }; 6];
+
+/// Returns the HSK level of `word`, an entry of the Chinese list or its
+/// simplified form, or `None` if the language has no HSK data or the word
+/// isn't in the HSK vocabulary.
+#[cfg_attr(not(feature = "zh"), allow(unused_variables))]
+pub(crate) fn level(word: &str, lang: Lang) -> Option<u8> {
+    match lang {
+        #[cfg(feature = "zh")]
+        Lang::Zh => {
+            let levels = ZH_LEVELS.get_or_init(|| {
+                let text = ZH_TEXT.get_or_init(|| words::decompress(ZH_RAW));
+                text.lines()
+                    .filter_map(|line| line.split_once(' '))
+                    .filter_map(|(word, level)| Some((word, level.parse().ok()?)))
+                    .collect()
+            });
+            // Entries list the traditional form, then the simplified one.
+            levels.get(word.rsplit(' ').next()?).copied()
+        }
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Returns the words of the given language at HSK `level`, in the order of
+/// [`all`](crate::all), or `None` if the language has no HSK data or
+/// `level` isn't one of [`LEVELS`].
+#[cfg_attr(not(feature = "zh"), allow(unused_variables))]
+pub(crate) fn get(hsk: u8, lang: Lang) -> Option<&'static [&'static str]> {
+    if !LEVELS.contains(&hsk) {
+        return None;
+    }
+    match lang {
+        #[cfg(feature = "zh")]
+        Lang::Zh => Some(&**ZH[hsk as usize - 1].get_or_init(|| {
+            words::get(lang).iter().copied().filter(|word| level(word, lang) == Some(hsk)).collect()
+        })),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
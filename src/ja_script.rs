@@ -0,0 +1,51 @@
+use crate::{JaScript, Lang};
+#[cfg(feature = "ja")]
+use crate::words::{self, Words};
+#[cfg(feature = "ja")]
+use std::sync::OnceLock;
+
+/// Japanese words indexed by `JaScript as usize`.
+#[cfg(feature = "ja")]
+static JA: OnceLock<[Words; 4]> = OnceLock::new();
+
+/// Returns the words of the given language written in `script`, in the
+/// order of [`all`](crate::all), or `None` if the language isn't Japanese.
+#[cfg_attr(not(feature = "ja"), allow(unused_variables))]
+pub(crate) fn get(script: JaScript, lang: Lang) -> Option<&'static [&'static str]> {
+    match lang {
+        #[cfg(feature = "ja")]
+        Lang::Ja => Some(&JA.get_or_init(|| classify(words::get(lang)))[script as usize]),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Sorts `words` into the lists of each script in one pass.
+#[cfg(feature = "ja")]
+fn classify(words: &[&'static str]) -> [Words; 4] {
+    let mut lists: [Vec<&'static str>; 4] = Default::default();
+    for &word in words {
+        for script in [JaScript::Hiragana, JaScript::Katakana, JaScript::Kana, JaScript::Kanji] {
+            if script.matches(word) {
+                lists[script as usize].push(word);
+            }
+        }
+    }
+    lists.map(Vec::into_boxed_slice)
+}
+
+/// Returns whether `char` is a hiragana, or a mark written with either
+/// kana such as "ー".
+pub(crate) fn is_hiragana(char: char) -> bool {
+    matches!(char, '\u{3041}'..='\u{309F}' | 'ー' | '・')
+}
+
+/// Returns whether `char` is a katakana, or a mark written with it.
+pub(crate) fn is_katakana(char: char) -> bool {
+    matches!(char, '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}')
+}
+
+/// Returns whether `char` is a kanji, or the kanji repetition mark "々".
+pub(crate) fn is_kanji(char: char) -> bool {
+    matches!(char, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' | '々')
+}
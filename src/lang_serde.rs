@@ -0,0 +1,33 @@
+use crate::Lang;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// Serializes as the ISO 639-1 code, e.g. `"en"`.
+impl Serialize for Lang {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// Deserializes from anything `Lang::from_str` accepts, e.g. `"en"` or
+/// `"English"`.
+impl<'de> Deserialize<'de> for Lang {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(LangVisitor)
+    }
+}
+
+struct LangVisitor;
+
+impl Visitor<'_> for LangVisitor {
+    type Value = Lang;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an ISO 639-1 language code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Lang, E> {
+        v.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Lang;
+use crate::words::{self, Words};
+use std::sync::OnceLock;
+
+/// Common words that end like a regular inflection of another listed word
+/// without being one, as "news" and "new" or "during" and "dure".
+const EN_NOT_INFLECTED: &[&str] = &["always", "ceiling", "during", "evening", "morning", "news", "species"];
+
+#[cfg(feature = "en")]
+static EN: OnceLock<Words> = OnceLock::new();
+#[cfg(feature = "eff-long")]
+static EFF_LONG: OnceLock<Words> = OnceLock::new();
+#[cfg(feature = "eff-short1")]
+static EFF_SHORT1: OnceLock<Words> = OnceLock::new();
+#[cfg(feature = "eff-short2")]
+static EFF_SHORT2: OnceLock<Words> = OnceLock::new();
+#[cfg(feature = "en-10k")]
+static EN_10K: OnceLock<Words> = OnceLock::new();
+#[cfg(feature = "en-gb")]
+static EN_GB: OnceLock<Words> = OnceLock::new();
+#[cfg(feature = "en-us")]
+static EN_US: OnceLock<Words> = OnceLock::new();
+
+/// Returns the base forms of the given language's words, in the order of
+/// [`all`](crate::all), or `None` if the language has no lemma data.
+///
+/// Japanese and Chinese entries are dictionary forms already. English lists
+/// drop their regular inflections, as found by [`english`].
+#[cfg_attr(
+    not(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us")),
+    allow(unused_variables)
+)]
+pub(crate) fn lemmas(lang: Lang) -> Option<&'static [&'static str]> {
+    let english = |cache: &'static OnceLock<Words>| Some(&**cache.get_or_init(|| english(words::get(lang))));
+    match lang {
+        #[cfg(feature = "ja")]
+        Lang::Ja => Some(words::get(lang)),
+        #[cfg(feature = "zh")]
+        Lang::Zh => Some(words::get(lang)),
+        #[cfg(feature = "en")]
+        Lang::En => english(&EN),
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => english(&EFF_LONG),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => english(&EFF_SHORT1),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => english(&EFF_SHORT2),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => english(&EN_10K),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => english(&EN_GB),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => english(&EN_US),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Returns the words of the sorted English list `words` that aren't a
+/// regular inflection of another word of the list: a plural or third
+/// person in -s, -es or -ies, or a form in -ed or -ing, allowing for a
+/// dropped final "e" or a doubled consonant. So "run" stays and "runs" and
+/// "running" go, while "cats" stays in a list without "cat".
+///
+/// Only the spelling is compared, so nouns that share a form with a verb,
+/// such as "building", go too.
+pub(crate) fn english(words: &[&'static str]) -> Words {
+    let listed = |stem: &String| words.binary_search(&stem.as_str()).is_ok();
+    words
+        .iter()
+        .copied()
+        .filter(|word| EN_NOT_INFLECTED.contains(word) || !english_stems(word).iter().any(listed))
+        .collect()
+}
+
+/// Returns the words `word` would be a regular inflection of. Stems shorter
+/// than three letters are skipped, and so is restoring the "e" of a stem
+/// shorter than that, since short words such as "ne" and "the" would make
+/// "need" and "thing" look inflected.
+fn english_stems(word: &str) -> Vec<String> {
+    let mut stems = Vec::new();
+    let mut add = |bare: &str, ending: &str| {
+        if bare.len() + ending.len() >= 3 {
+            stems.push(format!("{}{}", bare, ending));
+        }
+    };
+    if let Some(bare) = word.strip_suffix("ies") {
+        add(bare, "y");
+    }
+    if let Some(bare) = word.strip_suffix("es")
+        && ["s", "x", "z", "ch", "sh", "o"].iter().any(|end| bare.ends_with(end))
+    {
+        add(bare, "");
+    }
+    if let Some(bare) = word.strip_suffix('s')
+        && !bare.ends_with(['s', 'u', 'i'])
+    {
+        add(bare, "");
+    }
+    if let Some(bare) = word.strip_suffix("ied") {
+        add(bare, "y");
+    }
+    if let Some(bare) = word.strip_suffix("ying") {
+        add(bare, "ie");
+    }
+    for ending in ["ed", "ing"] {
+        let Some(bare) = word.strip_suffix(ending) else {
+            continue;
+        };
+        add(bare, "");
+        let mut last = bare.chars().rev();
+        if last.next().is_some_and(is_consonant) {
+            if bare.len() >= 3 {
+                add(bare, "e");
+            }
+            if bare.chars().next_back() == last.next() {
+                add(&bare[..bare.len() - 1], "");
+            }
+        }
+    }
+    stems
+}
+
+fn is_consonant(c: char) -> bool {
+    c.is_ascii_lowercase() && !"aeiou".contains(c)
+}
@@ -1,8 +1,8 @@
 //! # random_word
 //!
 //! The `random_word` crate provides an efficient way to generate
-//! random words. Included words can be filtered by length or
-//! first character.
+//! random words. Included words can be filtered by length, first
+//! character or last character.
 //!
 //! ## Usage
 //! You **MUST** enable a crate language feature.
@@ -12,6 +12,7 @@
 //! [dependencies]
 //! random_word = { version = "0.5.2", features = ["en"] }
 //! ```
+//! The `all-langs` feature enables every language.
 //!
 //! **Supported Languages**
 //! - English
@@ -22,22 +23,307 @@
 //! - Russian
 //! - Chinese
 //!
+//! **Curated lists**
+//!
+//! The EFF diceware lists are available as `Lang::EffLong` (7,776 words,
+//! feature `eff-long`), `Lang::EffShort1` and `Lang::EffShort2` (1,296
+//! words each, features `eff-short1` and `eff-short2`). They hold short,
+//! memorable English words and map exactly onto dice rolls with
+//! [`from_dice_rolls`].
+//!
+//! `Lang::En10k` (feature `en-10k`) holds only the 10,000 most common
+//! English words, for passphrase generators that don't need the full
+//! English list in their binary. Only English ships frequency data, so
+//! other languages have no such list.
+//!
+//! `Lang::EnGb` and `Lang::EnUs` (features `en-gb` and `en-us`) hold the
+//! English list with one spelling per word: `Lang::EnGb` drops American
+//! forms such as "color" and "center", and `Lang::EnUs` drops British forms
+//! such as "colour" and "centre". `Lang::En` keeps both.
+//!
+//! The standard BIP-39 wordlists are available through the `mnemonic`
+//! module, each behind a feature such as `bip39-en`, for encoding entropy
+//! as words with `mnemonic::encode` and `mnemonic::decode`.
+//!
+//! The PGP word list is available through the `pgp` module with the `pgp`
+//! feature, for spelling fingerprints aloud.
+//!
+//! The RFC 2289 S/KEY dictionary is available through the `skey` module
+//! with the `skey` feature, for six-word one-time passwords.
+//!
+//! ## Length units
+//! Length filters count `char`s by default. [`LengthUnit`] selects bytes
+//! instead, or grapheme clusters with the `graphemes` feature.
+//!
+//! ## Common words
+//! [`all_common`], [`get_common`] and `WordQuery::commonness` restrict
+//! words to a frequency [`Tier`], and [`top_n`] returns any number of the
+//! most common words. Only English, including `Lang::En10k`, ships
+//! frequency data; other languages have no common words.
+//!
+//! ## Parts of speech
+//! [`all_pos`] and [`get_pos`] select nouns, verbs, adjectives or adverbs.
+//! Only English ships part-of-speech data. The [`name`] module builds
+//! Heroku- and Docker-style names such as `brave-otter-7421` from them.
+//!
+//! ## Base forms
+//! [`all_lemmas`], [`is_lemma`] and `WordQuery::lemmas_only` drop plurals
+//! and conjugations. English lists drop regular inflections of their own
+//! words, Japanese and Chinese keep every entry, and the other languages
+//! have no lemma data.
+//!
+//! ## Japanese scripts
+//! [`all_ja_script`], [`get_ja_script`] and `WordQuery::ja_script` select
+//! Japanese words written only in hiragana, only in katakana, only in kana,
+//! or with kanji, by [`JaScript`], for learners and for fonts without kanji.
+//!
+//! ## HSK levels
+//! [`all_hsk`], [`get_hsk`], [`hsk_level`] and `WordQuery::hsk` select
+//! Chinese words by their level in the six-level HSK vocabulary, for
+//! language-learning apps. [`LengthUnit::Headword`] counts the characters
+//! of one written form of a Chinese entry, which lists both.
+//!
+//! ## Romanization
+//! Enable the `romanization` feature for `romanize`, `all_script` and
+//! `get_script`, which write Japanese words in romaji, Chinese in Pinyin
+//! and Russian in the Latin alphabet, with or without diacritics, for
+//! passphrases typed on ASCII keyboards.
+//!
+//! ## Custom word lists
+//! [`Dictionary`] loads a word list at runtime, from a file, bytes or any
+//! iterator of strings, with the same lookups as the embedded languages.
+//! [`Dictionary::from_path`] also reads diceware, CSV and
+//! brotli-compressed lists, and with the `http` feature
+//! `Dictionary::from_url` downloads them. With the `macros` feature,
+//! `embed_wordlist!` instead compresses a list into the binary at compile
+//! time, as an `EmbeddedList` that dereferences to a dictionary, and
+//! `static_word!` picks a word of a language at compile time. With the
+//! `mmap` feature, `Pack` reads lists from one memory-mapped file, written
+//! by `passgen pack`, that many processes share. Dictionaries combine through
+//! [`Dictionary::union`], [`Dictionary::intersection`] and
+//! [`Dictionary::difference`]. Both they and [`Lang`] implement
+//! [`WordSource`], so a [`WordQuery`] and the passphrases built from it can
+//! draw from either. With the `validate` feature,
+//! `dictionary::validate` reports duplicates, uppercase letters,
+//! surrounding whitespace, non-NFC strings and words with digits or
+//! punctuation in any list, and `dictionary::normalize` fixes them.
+//!
+//! ## Errors
+//! Functions returning `Option` have `try_*` counterparts, such as
+//! [`try_get_len`], that return a [`Result`] with an [`Error`] explaining
+//! the failure.
+//!
+//! ## Regular expressions
+//! Enable the `regex` feature for `all_matching`, `get_matching` and
+//! `WordQuery::matching`.
+//!
+//! ## Serde
+//! Enable the `serde` feature to serialize and deserialize [`Lang`] as its
+//! ISO 639-1 code.
+//!
+//! ## Zeroize
+//! Enable the `zeroize` feature to wipe the text of a [`Passphrase`] from
+//! memory when it is dropped. `Passphrase::to_zeroizing` returns the
+//! assembled passphrase in a buffer that is wiped too, and transforms wipe
+//! the intermediate strings they replace.
+//!
+//! ## Breached passwords
+//! Enable the `breach` feature to check passphrases against a bundled bloom
+//! filter of the most common leaked passwords, or against your own filter
+//! built from Have I Been Pwned hashes, with the `breach` module.
+//!
+//! ## Family-friendly lists
+//! Enable the `clean` feature to drop profanity, slurs and sexual terms
+//! from every embedded list as it is loaded, so no function returns them.
+//! An entry is dropped when any of its space-separated parts, such as a
+//! Japanese reading or either form of a Chinese entry, is banned.
+//! `Blocklist::profanity` filters dictionaries loaded at runtime the same
+//! way. Since the lists shrink, words are numbered differently, and
+//! [`encode_bytes`] output isn't interchangeable with builds without the
+//! feature. [`verify`] still checks the full embedded data. Add the `lean`
+//! feature to read [`Lang::word_count`], [`Lang::lengths`] and
+//! [`Lang::initials`] without keeping the decompressed list.
+//!
+//! ## Dataset versions
+//! Anything derived from word positions, such as diceware rolls or
+//! [`encode_bytes`] output, depends on the exact list.
+//! [`Lang::dataset_version`] returns the version, hash and source of a
+//! language's list, to record with such data or pin against.
+//!
+//! ## Compression
+//! Word lists are embedded compressed with brotli, from the default
+//! `brotli` feature. Enable the `zstd` feature to embed zstd copies
+//! instead; without default features, brotli is then not built at all.
+//!
+//! ## Secure randomness
+//! Enable the `secure` feature to draw every word from the operating
+//! system's CSPRNG instead of the thread-local RNG. This also provides
+//! [`get_secure`].
+//!
+
+#[cfg(not(any(
+    feature = "de",
+    feature = "en",
+    feature = "es",
+    feature = "fr",
+    feature = "ja",
+    feature = "ru",
+    feature = "zh",
+    feature = "eff-long",
+    feature = "eff-short1",
+    feature = "eff-short2",
+    feature = "en-10k",
+    feature = "en-gb",
+    feature = "en-us"
+)))]
+compile_error!(
+    "random_word has no languages enabled. Enable at least one language feature: \"de\", \"en\", \"es\", \"fr\", \"ja\", \"ru\", \"zh\", \"eff-long\", \"eff-short1\", \"eff-short2\", \"en-10k\", \"en-gb\" or \"en-us\", or \"all-langs\" for every language."
+);
+
+#[cfg(not(any(feature = "brotli", feature = "zstd")))]
+compile_error!(
+    "random_word has no decompressor enabled. Enable the \"brotli\" feature, which is on by default, or \"zstd\"."
+);
+
+/// Embeds the compressed file at `$path`, without its extension, in the
+/// format selected by the `zstd` feature.
+#[cfg(not(feature = "zstd"))]
+#[cfg_attr(
+    not(any(feature = "en", feature = "en-10k", feature = "_bip39", feature = "zh")),
+    allow(unused_macros)
+)]
+macro_rules! include_compressed {
+    ($path:expr) => {
+        include_bytes!(concat!($path, ".br"))
+    };
+}
+#[cfg(feature = "zstd")]
+#[cfg_attr(
+    not(any(feature = "en", feature = "en-10k", feature = "_bip39", feature = "zh")),
+    allow(unused_macros)
+)]
+macro_rules! include_compressed {
+    ($path:expr) => {
+        include_bytes!(concat!($path, ".zst"))
+    };
+}
+
+// Lets `embed_wordlist!`, which names `::random_word`, expand in the unit tests.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as random_word;
 
 #[allow(unused_imports)]
 #[allow(unused_macros)]
 #[allow(unused_variables)]
 mod words;
 
-#[allow(unused)]
+mod bktree;
+#[cfg(feature = "breach")]
+pub mod breach;
+mod blocklist;
+mod codec;
+mod constraints;
+mod dataset;
+pub mod dictionary;
+mod dist;
+#[cfg(feature = "macros")]
+mod embedded;
+mod error;
+mod estimate;
+#[cfg(feature = "http")]
+mod fetch;
+mod frequency;
+pub mod gibberish;
+mod hsk;
+mod ja_script;
+pub mod name;
+#[cfg(feature = "_bip39")]
+pub mod mnemonic;
+#[cfg(feature = "serde")]
+mod lang_serde;
+mod lemma;
+mod load;
+#[cfg(feature = "mmap")]
+mod pack;
+mod passphrase;
+#[cfg(feature = "pgp")]
+pub mod pgp;
+mod phonetics;
+mod policy;
+pub mod poem;
+mod pos;
+mod preload;
+#[cfg(feature = "clean")]
+mod profanity;
+mod query;
+#[cfg(feature = "romanization")]
+mod romanization;
+mod seeded;
+pub mod sentence;
+#[cfg(feature = "skey")]
+pub mod skey;
+mod source;
+mod template;
+mod transcription;
+mod transform;
+
+#[allow(unused, clippy::module_inception)]
 mod tests;
 
+pub use blocklist::Blocklist;
+pub use constraints::LetterConstraints;
+pub use dataset::DatasetVersion;
+pub use dictionary::Dictionary;
+pub use dist::WordDist;
+#[cfg(feature = "macros")]
+pub use embedded::EmbeddedList;
+pub use error::Error;
+pub use estimate::{Estimate, Match, MatchKind};
+#[cfg(feature = "http")]
+pub use fetch::FetchOptions;
+pub use load::LoadError;
+#[cfg(feature = "mmap")]
+pub use pack::Pack;
+pub use passphrase::{Passphrase, PassphraseOptions};
+pub use policy::{Policy, RequiredClass};
+pub use preload::Indexes;
+pub use query::WordQuery;
+#[cfg(feature = "romanization")]
+pub use romanization::Script;
+pub use seeded::Seeded;
+pub use source::WordSource;
+pub use template::Template;
+pub use transform::{Capitalize, LeetLevel, Transform};
 pub use words::Lang;
+#[cfg(feature = "macros")]
+pub use random_word_macros::{embed_wordlist, static_word};
+
+use rand::{Rng, distr::Distribution, seq::IndexedRandom};
+#[cfg(feature = "secure")]
+use rand::{TryRngCore, rngs::OsRng};
+use std::ops::{Bound, RangeBounds, RangeInclusive};
 
-use rand::{prelude::IndexedRandom, rng};
+/// The RNG used by functions that don't take one explicitly.
+#[cfg(not(feature = "secure"))]
+#[inline(always)]
+fn default_rng() -> rand::rngs::ThreadRng {
+    rand::rng()
+}
 
+/// The RNG used by functions that don't take one explicitly. With the
+/// `secure` feature this reads directly from the operating system.
+#[cfg(feature = "secure")]
+#[inline(always)]
+fn default_rng() -> rand::rand_core::UnwrapErr<OsRng> {
+    OsRng.unwrap_err()
+}
 
 /// Returns all words with the given language.
 ///
+/// The slice is sorted in byte-wise lexicographic order, so it can be
+/// searched with [`slice::binary_search`] or [`slice::partition_point`].
+/// The filtered slices returned by other functions keep this order.
+///
 /// # Example
 /// ```
 /// use random_word::Lang;
@@ -59,11 +345,93 @@ pub fn all(lang: Lang) -> &'static [&'static str] {
 /// ```
 #[inline(always)]
 pub fn get(lang: Lang) -> &'static str {
+    get_with_rng(lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_with_rng(Lang::En, &mut rng);
+/// assert!(!word.is_empty());
+/// ```
+#[inline(always)]
+pub fn get_with_rng(lang: Lang, rng: &mut impl Rng) -> &'static str {
     words::get(lang)
-        .choose(&mut rng())
+        .choose(rng)
         .expect("array is empty")
 }
 
+/// Returns a random word with the given language, drawn from the operating
+/// system's CSPRNG.
+///
+/// # Panics
+/// Panics if the operating system fails to provide randomness.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_secure(Lang::En);
+/// assert!(!word.is_empty());
+/// ```
+#[cfg(feature = "secure")]
+#[inline(always)]
+pub fn get_secure(lang: Lang) -> &'static str {
+    get_with_rng(lang, &mut OsRng.unwrap_err())
+}
+
+/// Returns an endless iterator of random words with the given language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words: Vec<&str> = random_word::iter(Lang::En).take(5).collect();
+/// assert_eq!(words.len(), 5);
+/// ```
+#[inline(always)]
+pub fn iter(lang: Lang) -> impl Iterator<Item = &'static str> {
+    iter_with_rng(lang, default_rng())
+}
+
+/// Returns an endless iterator of random words with the given language,
+/// drawn from the supplied RNG.
+///
+/// Pass `&mut rng` to keep using the RNG after the iterator is dropped.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let words: Vec<&str> = random_word::iter_with_rng(Lang::En, &mut rng)
+///     .filter(|w| w.len() == 4)
+///     .take(3)
+///     .collect();
+/// assert_eq!(words.len(), 3);
+/// ```
+#[inline(always)]
+pub fn iter_with_rng<R: Rng>(lang: Lang, mut rng: R) -> impl Iterator<Item = &'static str> {
+    std::iter::repeat_with(move || get_with_rng(lang, &mut rng))
+}
+
+/// Returns a generator whose words follow a reproducible sequence
+/// determined by `seed`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let mut generator = random_word::seeded(42);
+/// let word = generator.get(Lang::En);
+/// assert_eq!(word, random_word::seeded(42).get(Lang::En));
+/// ```
+#[inline(always)]
+pub fn seeded(seed: u64) -> Seeded {
+    Seeded::new(seed)
+}
+
 /// Returns all words with the given length and language.
 ///
 /// # Example
@@ -87,13 +455,187 @@ pub fn all_len(len: usize, lang: Lang) -> Option<&'static [&'static str]> {
 /// ```
 #[inline(always)]
 pub fn get_len(len: usize, lang: Lang) -> Option<&'static str> {
+    get_len_with_rng(len, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given length and language, using the
+/// supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_len_with_rng(4, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_len_with_rng(len: usize, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
     words::get_len(len, lang)?
-        .choose(&mut rng())
+        .choose(rng)
         .copied()
 }
 
+/// The unit in which word length is measured.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum LengthUnit {
+    /// UTF-8 bytes, as limited by database columns and wire protocols.
+    Bytes,
+    /// Unicode scalar values, as counted by [`str::chars`].
+    #[default]
+    Chars,
+    /// Extended grapheme clusters, i.e. user-perceived characters
+    /// (feature = "graphemes").
+    #[cfg(feature = "graphemes")]
+    Graphemes,
+    /// Characters of the written form alone, before the first space: one
+    /// form of a Chinese entry, which lists the traditional and simplified
+    /// forms, or a Japanese entry without its kana reading. Other words
+    /// count as [`Chars`](Self::Chars).
+    Headword,
+}
+
+impl LengthUnit {
+    /// Returns the length of `word` in this unit.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::LengthUnit;
+    /// assert_eq!(LengthUnit::Bytes.measure("café"), 5);
+    /// assert_eq!(LengthUnit::Chars.measure("café"), 4);
+    /// assert_eq!(LengthUnit::Headword.measure("遠征軍 远征军"), 3);
+    /// ```
+    pub fn measure(self, word: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => word.len(),
+            LengthUnit::Chars => word.chars().count(),
+            #[cfg(feature = "graphemes")]
+            LengthUnit::Graphemes => {
+                use unicode_segmentation::UnicodeSegmentation;
+                word.graphemes(true).count()
+            }
+            LengthUnit::Headword => word.split(' ').next().unwrap_or(word).chars().count(),
+        }
+    }
+
+    /// Returns the words whose length in this unit is `len`.
+    #[inline(always)]
+    fn bucket(self, len: usize, lang: Lang) -> Option<&'static [&'static str]> {
+        match self {
+            LengthUnit::Bytes => words::byte_len_index(lang).get(&len),
+            LengthUnit::Chars => words::get_len(len, lang),
+            #[cfg(feature = "graphemes")]
+            LengthUnit::Graphemes => words::grapheme_len_index(lang).get(&len),
+            LengthUnit::Headword => words::headword_len_index(lang).get(&len),
+        }
+        .map(|boxed| &**boxed)
+    }
+
+    /// Returns every length in this unit that has at least one word, in
+    /// ascending order.
+    fn lengths(self, lang: Lang) -> Vec<usize> {
+        let index = match self {
+            LengthUnit::Bytes => words::byte_len_index(lang),
+            LengthUnit::Chars => return lang.lengths().to_vec(),
+            #[cfg(feature = "graphemes")]
+            LengthUnit::Graphemes => words::grapheme_len_index(lang),
+            LengthUnit::Headword => words::headword_len_index(lang),
+        };
+        let mut lens: Vec<usize> = index.keys().copied().collect();
+        lens.sort_unstable();
+        lens
+    }
+}
+
+/// Returns the length buckets within `range`, ordered by length.
+fn len_buckets(range: &RangeInclusive<usize>, lang: Lang) -> Vec<&'static [&'static str]> {
+    len_buckets_in(range, LengthUnit::Chars, lang)
+}
+
+/// Returns the length buckets within `range` measured in `unit`, ordered
+/// by length.
+fn len_buckets_in(
+    range: &RangeInclusive<usize>,
+    unit: LengthUnit,
+    lang: Lang,
+) -> Vec<&'static [&'static str]> {
+    unit.lengths(lang)
+        .into_iter()
+        .filter(|len| range.contains(len))
+        .filter_map(|len| unit.bucket(len, lang))
+        .collect()
+}
+
+/// Returns all words with a length in the given range and language,
+/// ordered by length.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_len_between(4..=7, Lang::En);
+/// assert!(words.is_some());
+/// ```
+pub fn all_len_between(range: RangeInclusive<usize>, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = len_buckets(&range, lang).concat();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with a length in the given range and language.
+///
+/// Every word in the range is equally likely, regardless of how many
+/// words share its length.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_len_between(4..=7, Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_len_between(range: RangeInclusive<usize>, lang: Lang) -> Option<&'static str> {
+    get_len_between_with_rng(range, lang, &mut default_rng())
+}
+
+/// Returns a random word with a length in the given range and language,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_len_between_with_rng(4..=7, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+pub fn get_len_between_with_rng(
+    range: RangeInclusive<usize>,
+    lang: Lang,
+    rng: &mut impl Rng,
+) -> Option<&'static str> {
+    choose_across(&len_buckets(&range, lang), rng)
+}
+
+/// Picks a uniformly random word from the union of `buckets` without
+/// concatenating them.
+fn choose_across(buckets: &[&[&'static str]], rng: &mut impl Rng) -> Option<&'static str> {
+    let total: usize = buckets.iter().map(|bucket| bucket.len()).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut index = rng.random_range(0..total);
+    for bucket in buckets {
+        if index < bucket.len() {
+            return Some(bucket[index]);
+        }
+        index -= bucket.len();
+    }
+    unreachable!("index is within the total bucket length")
+}
+
 /// Returns all words with the given starting character and language.
 ///
+/// Matching is case-sensitive; see [`all_starts_with_case`] to ignore case.
+///
 /// # Example
 /// ```
 /// use random_word::Lang;
@@ -102,11 +644,13 @@ pub fn get_len(len: usize, lang: Lang) -> Option<&'static str> {
 /// ```
 #[inline(always)]
 pub fn all_starts_with(char: char, lang: Lang) -> Option<&'static [&'static str]> {
-    words::get_starts_with(char, lang).map(|boxed| &**boxed)
+    words::get_starts_with(char, lang)
 }
 
 /// Returns a random word with the given starting character and language.
 ///
+/// Matching is case-sensitive; see [`get_starts_with_case`] to ignore case.
+///
 /// # Example
 /// ```
 /// use random_word::Lang;
@@ -115,7 +659,2711 @@ pub fn all_starts_with(char: char, lang: Lang) -> Option<&'static [&'static str]
 /// ```
 #[inline(always)]
 pub fn get_starts_with(char: char, lang: Lang) -> Option<&'static str> {
+    get_starts_with_rng(char, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given starting character and language,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_starts_with_rng('c', Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_starts_with_rng(char: char, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
     words::get_starts_with(char, lang)?
-        .choose(&mut rng())
+        .choose(rng)
         .copied()
 }
+
+/// Returns all words with the given length, measured in `unit`, and language.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, LengthUnit};
+/// let words = random_word::all_len_in(5, LengthUnit::Bytes, Lang::En);
+/// assert!(words.unwrap().iter().all(|w| w.len() == 5));
+/// ```
+#[inline(always)]
+pub fn all_len_in(len: usize, unit: LengthUnit, lang: Lang) -> Option<&'static [&'static str]> {
+    unit.bucket(len, lang)
+}
+
+/// Returns a random word with the given length, measured in `unit`, and
+/// language.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, LengthUnit};
+/// let word = random_word::get_len_in(5, LengthUnit::Bytes, Lang::En);
+/// assert_eq!(word.unwrap().len(), 5);
+/// ```
+#[inline(always)]
+pub fn get_len_in(len: usize, unit: LengthUnit, lang: Lang) -> Option<&'static str> {
+    get_len_in_with_rng(len, unit, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given length, measured in `unit`, and
+/// language, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{Lang, LengthUnit};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_len_in_with_rng(5, LengthUnit::Chars, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_len_in_with_rng(
+    len: usize,
+    unit: LengthUnit,
+    lang: Lang,
+    rng: &mut impl Rng,
+) -> Option<&'static str> {
+    all_len_in(len, unit, lang)?.choose(rng).copied()
+}
+
+/// Returns all words with the given language whose UTF-8 encoding is at
+/// most `max` bytes long, ordered by byte length.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_max_bytes(6, Lang::En).unwrap();
+/// assert!(words.iter().all(|w| w.len() <= 6));
+/// ```
+pub fn all_max_bytes(max: usize, lang: Lang) -> Option<Vec<&'static str>> {
+    let words = len_buckets_in(&(0..=max), LengthUnit::Bytes, lang).concat();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language whose UTF-8 encoding is
+/// at most `max` bytes long.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_max_bytes(6, Lang::En);
+/// assert!(word.unwrap().len() <= 6);
+/// ```
+#[inline(always)]
+pub fn get_max_bytes(max: usize, lang: Lang) -> Option<&'static str> {
+    get_max_bytes_with_rng(max, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language whose UTF-8 encoding is
+/// at most `max` bytes long, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_max_bytes_with_rng(6, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+pub fn get_max_bytes_with_rng(max: usize, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    choose_across(&len_buckets_in(&(0..=max), LengthUnit::Bytes, lang), rng)
+}
+
+/// Returns whether `word` is in the given language's word list.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert!(random_word::contains("horse", Lang::En));
+/// assert!(!random_word::contains("Horse", Lang::En));
+/// assert!(!random_word::contains("hrose", Lang::En));
+/// ```
+#[inline(always)]
+pub fn contains(word: &str, lang: Lang) -> bool {
+    words::get(lang).binary_search(&word).is_ok()
+}
+
+/// Returns whether `word` is in the given language's word list, ignoring
+/// case.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert!(random_word::contains_ignore_case("Horse", Lang::En));
+/// assert!(!random_word::contains_ignore_case("Hrose", Lang::En));
+/// ```
+#[inline(always)]
+pub fn contains_ignore_case(word: &str, lang: Lang) -> bool {
+    words::sorted_folded(lang)
+        .binary_search_by(|probe| words::cmp_folded(probe, word))
+        .is_ok()
+}
+
+/// Returns whether `word` is unlikely to be confused with another word of
+/// the given language when dictated over the phone or handwritten.
+///
+/// Curated homophone groups are shipped for English, German, Spanish,
+/// French and Russian, and Japanese words sharing a kana reading count as
+/// homophones. Words one look-alike letter sequence from another, such as
+/// "rn" and "m" or "cl" and "d", are unsafe in every language. Chinese has
+/// no homophone data.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert!(random_word::is_transcription_safe("zebra", Lang::En));
+/// assert!(!random_word::is_transcription_safe("their", Lang::En));
+/// assert!(!random_word::is_transcription_safe("modern", Lang::En));
+/// ```
+#[inline(always)]
+pub fn is_transcription_safe(word: &str, lang: Lang) -> bool {
+    !words::confusable(lang).contains(word)
+}
+
+/// Returns the word at `index` in the given language's stable ordering.
+///
+/// The stable ordering is the byte-wise lexicographic order of [`all`]. It
+/// only changes when the word list itself changes, which is reflected by
+/// [`sha256`].
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let index = random_word::index_of("horse", Lang::En).unwrap();
+/// assert_eq!(random_word::word_at(index, Lang::En), Some("horse"));
+/// ```
+#[inline(always)]
+pub fn word_at(index: usize, lang: Lang) -> Option<&'static str> {
+    words::get(lang).get(index).copied()
+}
+
+/// Returns the index of `word` in the given language's stable ordering.
+///
+/// If a list contains the same entry more than once, the first index is
+/// returned.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert!(random_word::index_of("horse", Lang::En).is_some());
+/// assert!(random_word::index_of("hrose", Lang::En).is_none());
+/// ```
+#[inline(always)]
+pub fn index_of(word: &str, lang: Lang) -> Option<usize> {
+    let words = words::get(lang);
+    let index = words.partition_point(|probe| *probe < word);
+    (words.get(index) == Some(&word)).then_some(index)
+}
+
+/// Returns all words with the given language within a lexicographic range.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_range("cat".."cats", Lang::En);
+/// assert_eq!(words.first(), Some(&"cat"));
+/// assert!(words.iter().all(|w| ("cat".."cats").contains(w)));
+///
+/// let tail = random_word::all_range("zy".., Lang::En);
+/// assert!(tail.iter().all(|w| *w >= "zy"));
+/// ```
+pub fn all_range<'a>(range: impl RangeBounds<&'a str>, lang: Lang) -> &'static [&'static str] {
+    let words = words::get(lang);
+    let start = match range.start_bound() {
+        Bound::Included(start) => words.partition_point(|word| word < start),
+        Bound::Excluded(start) => words.partition_point(|word| word <= start),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => words.partition_point(|word| word <= end),
+        Bound::Excluded(end) => words.partition_point(|word| word < end),
+        Bound::Unbounded => words.len(),
+    };
+    &words[start..end.max(start)]
+}
+
+/// Returns all words starting with the given prefix and language, in
+/// byte-wise lexicographic order.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_starts_with_str("pre", Lang::En).unwrap();
+/// assert!(words.iter().all(|w| w.starts_with("pre")));
+/// ```
+pub fn all_starts_with_str(prefix: &str, lang: Lang) -> Option<&'static [&'static str]> {
+    let words = words::get(lang);
+    let words = words::prefix_range(words, prefix);
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word starting with the given prefix and language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_starts_with_str("pre", Lang::En);
+/// assert!(word.unwrap().starts_with("pre"));
+/// ```
+#[inline(always)]
+pub fn get_starts_with_str(prefix: &str, lang: Lang) -> Option<&'static str> {
+    get_starts_with_str_rng(prefix, lang, &mut default_rng())
+}
+
+/// Returns a random word starting with the given prefix and language,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_starts_with_str_rng("pre", Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_starts_with_str_rng(prefix: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_starts_with_str(prefix, lang)?.choose(rng).copied()
+}
+
+/// Returns an iterator over the words starting with the given prefix and
+/// language, in byte-wise lexicographic order.
+///
+/// The sorted word list acts as an implicit trie: the first match is found
+/// by binary search and matches are streamed from there, so no per-prefix
+/// index is built and iteration can stop early.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words: Vec<&str> = random_word::prefix_iter("qu", Lang::En).take(3).collect();
+/// assert_eq!(words.len(), 3);
+/// assert!(words.iter().all(|w| w.starts_with("qu")));
+/// ```
+pub fn prefix_iter<'a>(prefix: &'a str, lang: Lang) -> impl Iterator<Item = &'static str> + 'a {
+    let words = words::get(lang);
+    let start = words.partition_point(|word| *word < prefix);
+    words[start..].iter().copied().take_while(move |word| word.starts_with(prefix))
+}
+
+/// Returns the characters that follow `prefix` in words with the given
+/// language, in order. These are the children of `prefix` in the implicit
+/// trie of the sorted word list, found without visiting every match.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let chars = random_word::next_chars("q", Lang::En);
+/// assert!(chars.contains(&'u'));
+/// assert!(!chars.contains(&'q'));
+/// ```
+pub fn next_chars(prefix: &str, lang: Lang) -> Vec<char> {
+    let mut words = all_starts_with_str(prefix, lang).unwrap_or_default();
+    let mut chars = Vec::new();
+    let mut child = String::from(prefix);
+    while let Some(first) = words.first() {
+        match first[prefix.len()..].chars().next() {
+            // The prefix is itself a word.
+            None => words = &words[1..],
+            Some(char) => {
+                chars.push(char);
+                child.truncate(prefix.len());
+                child.push(char);
+                words = &words[words.partition_point(|word| word.starts_with(child.as_str()))..];
+            }
+        }
+    }
+    chars
+}
+
+/// Whether character filters distinguish upper and lower case.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CaseSensitivity {
+    /// `'C'` only matches `'C'`.
+    #[default]
+    Sensitive,
+    /// `'C'` matches both `'c'` and `'C'`.
+    Insensitive,
+}
+
+impl CaseSensitivity {
+    /// Returns whether `a` and `b` are equal under this sensitivity.
+    #[inline(always)]
+    pub(crate) fn eq(self, a: char, b: char) -> bool {
+        match self {
+            CaseSensitivity::Sensitive => a == b,
+            CaseSensitivity::Insensitive => words::fold(a) == words::fold(b),
+        }
+    }
+}
+
+/// Returns all words with the given starting character and language,
+/// optionally ignoring case.
+///
+/// # Example
+/// ```
+/// use random_word::{CaseSensitivity, Lang};
+/// let words = random_word::all_starts_with_case('C', CaseSensitivity::Insensitive, Lang::En);
+/// assert!(words.is_some());
+/// assert!(random_word::all_starts_with_case('C', CaseSensitivity::Sensitive, Lang::En).is_none());
+/// ```
+#[inline(always)]
+pub fn all_starts_with_case(
+    char: char,
+    case: CaseSensitivity,
+    lang: Lang,
+) -> Option<&'static [&'static str]> {
+    match case {
+        CaseSensitivity::Sensitive => words::get_starts_with(char, lang),
+        CaseSensitivity::Insensitive => words::get_starts_with_folded(char, lang).map(|boxed| &**boxed),
+    }
+}
+
+/// Returns a random word with the given starting character and language,
+/// optionally ignoring case.
+///
+/// # Example
+/// ```
+/// use random_word::{CaseSensitivity, Lang};
+/// let word = random_word::get_starts_with_case('C', CaseSensitivity::Insensitive, Lang::En);
+/// assert!(word.unwrap().starts_with('c'));
+/// ```
+#[inline(always)]
+pub fn get_starts_with_case(char: char, case: CaseSensitivity, lang: Lang) -> Option<&'static str> {
+    get_starts_with_case_rng(char, case, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given starting character and language,
+/// optionally ignoring case, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{CaseSensitivity, Lang};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_starts_with_case_rng('C', CaseSensitivity::Insensitive, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_starts_with_case_rng(
+    char: char,
+    case: CaseSensitivity,
+    lang: Lang,
+    rng: &mut impl Rng,
+) -> Option<&'static str> {
+    all_starts_with_case(char, case, lang)?.choose(rng).copied()
+}
+
+/// Returns all words with the given ending character and language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_ends_with('t', Lang::En);
+/// assert!(words.is_some());
+/// ```
+#[inline(always)]
+pub fn all_ends_with(char: char, lang: Lang) -> Option<&'static [&'static str]> {
+    words::get_ends_with(char, lang).map(|boxed| &**boxed)
+}
+
+/// Returns a random word with the given ending character and language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_ends_with('t', Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_ends_with(char: char, lang: Lang) -> Option<&'static str> {
+    get_ends_with_rng(char, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given ending character and language,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_ends_with_rng('t', Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_ends_with_rng(char: char, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    words::get_ends_with(char, lang)?
+        .choose(rng)
+        .copied()
+}
+
+/// Returns all words ending with the given suffix and language, ordered by
+/// their spelling read backwards.
+///
+/// Matches are found by binary search over a reverse-sorted copy of the
+/// word list, built on first use.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_ends_with_str("ing", Lang::En).unwrap();
+/// assert!(words.iter().all(|w| w.ends_with("ing")));
+/// ```
+pub fn all_ends_with_str(suffix: &str, lang: Lang) -> Option<&'static [&'static str]> {
+    let words = words::sorted_reversed(lang);
+    let start = words.partition_point(|word| words::cmp_reversed(word, suffix).is_lt());
+    let len = words[start..].partition_point(|word| word.ends_with(suffix));
+    (len > 0).then(|| &words[start..start + len])
+}
+
+/// Returns a random word ending with the given suffix and language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_ends_with_str("tion", Lang::En);
+/// assert!(word.unwrap().ends_with("tion"));
+/// ```
+#[inline(always)]
+pub fn get_ends_with_str(suffix: &str, lang: Lang) -> Option<&'static str> {
+    get_ends_with_str_rng(suffix, lang, &mut default_rng())
+}
+
+/// Returns a random word ending with the given suffix and language, using
+/// the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_ends_with_str_rng("ness", Lang::En, &mut rng);
+/// assert!(word.unwrap().ends_with("ness"));
+/// ```
+#[inline(always)]
+pub fn get_ends_with_str_rng(suffix: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_ends_with_str(suffix, lang)?.choose(rng).copied()
+}
+
+/// Returns all words containing `pattern`, in dictionary order.
+///
+/// Patterns of three or more characters are answered from a trigram index
+/// built on first use; shorter patterns fall back to a scan.
+fn containing(pattern: &str, lang: Lang) -> Vec<&'static str> {
+    let words = words::get(lang);
+    let chars: Vec<char> = pattern.chars().collect();
+    if chars.len() < 3 {
+        return words.iter().copied().filter(|word| word.contains(pattern)).collect();
+    }
+
+    let index = words::trigrams(lang);
+    let mut postings = Vec::with_capacity(chars.len() - 2);
+    for window in chars.windows(3) {
+        match index.get(&[window[0], window[1], window[2]]) {
+            Some(list) => postings.push(&**list),
+            None => return Vec::new(),
+        }
+    }
+    postings.sort_unstable_by_key(|list| list.len());
+
+    postings[0]
+        .iter()
+        .filter(|id| postings[1..].iter().all(|list| list.binary_search(id).is_ok()))
+        .map(|&id| words[id as usize])
+        .filter(|word| word.contains(pattern))
+        .collect()
+}
+
+/// Returns all words containing the given substring and language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_containing("ight", Lang::En);
+/// assert!(words.is_some());
+/// ```
+pub fn all_containing(pattern: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let words = containing(pattern, lang);
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word containing the given substring and language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_containing("ight", Lang::En);
+/// assert!(word.unwrap().contains("ight"));
+/// ```
+#[inline(always)]
+pub fn get_containing(pattern: &str, lang: Lang) -> Option<&'static str> {
+    get_containing_rng(pattern, lang, &mut default_rng())
+}
+
+/// Returns a random word containing the given substring and language,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_containing_rng("ight", Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+pub fn get_containing_rng(pattern: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    containing(pattern, lang).choose(rng).copied()
+}
+
+/// Returns all words with the given language matching `regex`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use regex::Regex;
+/// let regex = Regex::new("^c.t$").unwrap();
+/// let words = random_word::all_matching(&regex, Lang::En).unwrap();
+/// assert!(words.contains(&"cat"));
+/// ```
+#[cfg(feature = "regex")]
+pub fn all_matching(regex: &regex::Regex, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get(lang)
+        .iter()
+        .copied()
+        .filter(|word| regex.is_match(word))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language matching `regex`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// use regex::Regex;
+/// let regex = Regex::new("^[aeiou]{2}").unwrap();
+/// let word = random_word::get_matching(&regex, Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[cfg(feature = "regex")]
+#[inline(always)]
+pub fn get_matching(regex: &regex::Regex, lang: Lang) -> Option<&'static str> {
+    get_matching_rng(regex, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language matching `regex`, using
+/// the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// use regex::Regex;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let regex = Regex::new("^[aeiou]{2}").unwrap();
+/// let word = random_word::get_matching_rng(&regex, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[cfg(feature = "regex")]
+#[inline(always)]
+pub fn get_matching_rng(regex: &regex::Regex, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    get_where_with_rng(lang, |word| regex.is_match(word), rng)
+}
+
+/// Characters that are easily confused when read aloud, handwritten or
+/// typed on a phone: `l`, `I`, `1` and `|`, `O` and `0`, apostrophes and
+/// hyphens.
+pub const AMBIGUOUS_CHARS: &str = "lI1|O0'-";
+
+/// Returns all words with the given language containing none of the
+/// characters in `chars`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_excluding(random_word::AMBIGUOUS_CHARS, Lang::En).unwrap();
+/// assert!(words.iter().all(|w| !w.contains('l')));
+/// ```
+pub fn all_excluding(chars: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get(lang)
+        .iter()
+        .copied()
+        .filter(|word| !word.contains(|c| chars.contains(c)))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language containing none of the
+/// characters in `chars`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_excluding("aeiou", Lang::En);
+/// assert!(!word.unwrap().contains(['a', 'e', 'i', 'o', 'u']));
+/// ```
+#[inline(always)]
+pub fn get_excluding(chars: &str, lang: Lang) -> Option<&'static str> {
+    get_excluding_rng(chars, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language containing none of the
+/// characters in `chars`, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_excluding_rng(random_word::AMBIGUOUS_CHARS, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_excluding_rng(chars: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    get_where_with_rng(lang, |word| !word.contains(|c| chars.contains(c)), rng)
+}
+
+/// A class of characters every character of a word must belong to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CharClass {
+    /// ASCII characters, i.e. no accents or non-Latin scripts.
+    Ascii,
+    /// ASCII letters `a-z` and `A-Z`.
+    AsciiAlphabetic,
+    /// ASCII lowercase letters `a-z`.
+    AsciiLowercase,
+    /// Alphabetic characters of any script.
+    Alphabetic,
+    /// Lowercase characters of any script.
+    Lowercase,
+}
+
+impl CharClass {
+    /// Returns whether `char` belongs to this class.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::CharClass;
+    /// assert!(CharClass::Alphabetic.contains('é'));
+    /// assert!(!CharClass::Ascii.contains('é'));
+    /// ```
+    #[inline(always)]
+    pub fn contains(self, char: char) -> bool {
+        match self {
+            CharClass::Ascii => char.is_ascii(),
+            CharClass::AsciiAlphabetic => char.is_ascii_alphabetic(),
+            CharClass::AsciiLowercase => char.is_ascii_lowercase(),
+            CharClass::Alphabetic => char.is_alphabetic(),
+            CharClass::Lowercase => char.is_lowercase(),
+        }
+    }
+
+    /// Returns whether every character of `word` belongs to this class.
+    #[inline(always)]
+    pub fn matches(self, word: &str) -> bool {
+        word.chars().all(|char| self.contains(char))
+    }
+}
+
+/// Returns all words with the given language made only of characters in
+/// `class`.
+///
+/// # Example
+/// ```
+/// use random_word::{CharClass, Lang};
+/// let words = random_word::all_in_class(CharClass::AsciiLowercase, Lang::En);
+/// assert!(words.is_some());
+/// ```
+pub fn all_in_class(class: CharClass, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get(lang)
+        .iter()
+        .copied()
+        .filter(|word| class.matches(word))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language made only of characters
+/// in `class`.
+///
+/// # Example
+/// ```
+/// use random_word::{CharClass, Lang};
+/// let word = random_word::get_in_class(CharClass::AsciiLowercase, Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_in_class(class: CharClass, lang: Lang) -> Option<&'static str> {
+    get_in_class_rng(class, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language made only of characters
+/// in `class`, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{CharClass, Lang};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_in_class_rng(CharClass::Ascii, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_in_class_rng(class: CharClass, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    get_where_with_rng(lang, |word| class.matches(word), rng)
+}
+
+/// Returns all ASCII-only words with the given language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_ascii(Lang::En).unwrap();
+/// assert!(words.iter().all(|w| w.is_ascii()));
+/// ```
+#[inline(always)]
+pub fn all_ascii(lang: Lang) -> Option<Vec<&'static str>> {
+    all_in_class(CharClass::Ascii, lang)
+}
+
+/// Returns a random ASCII-only word with the given language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_ascii(Lang::En);
+/// assert!(word.unwrap().is_ascii());
+/// ```
+#[inline(always)]
+pub fn get_ascii(lang: Lang) -> Option<&'static str> {
+    get_in_class(CharClass::Ascii, lang)
+}
+
+/// The scripts the written form of a Japanese word is in, leaving out its
+/// kana reading.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum JaScript {
+    /// Hiragana only, as in "ありがとう".
+    Hiragana,
+    /// Katakana only, as in "テレビ".
+    Katakana,
+    /// Kana only, hiragana or katakana, with no kanji, as in "アテになる".
+    Kana,
+    /// At least one kanji, as in "拾う \[ひろう\]".
+    Kanji,
+}
+
+impl JaScript {
+    /// Returns whether the written form of `word`, before any reading in
+    /// brackets, is in this script. The long vowel mark "ー" and the
+    /// middle dot "・" count as either kana.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::JaScript;
+    /// assert!(JaScript::Kana.matches("アテになる"));
+    /// assert!(!JaScript::Katakana.matches("アテになる"));
+    /// assert!(JaScript::Kanji.matches("拾う [ひろう]"));
+    /// assert!(!JaScript::Kana.matches("拾う [ひろう]"));
+    /// ```
+    pub fn matches(self, word: &str) -> bool {
+        let written = word.split(" [").next().unwrap_or(word);
+        match self {
+            JaScript::Hiragana => written.chars().all(ja_script::is_hiragana),
+            JaScript::Katakana => written.chars().all(ja_script::is_katakana),
+            JaScript::Kana => written.chars().all(|c| ja_script::is_hiragana(c) || ja_script::is_katakana(c)),
+            JaScript::Kanji => written.chars().any(ja_script::is_kanji),
+        }
+    }
+}
+
+/// Returns all words with the given language written in `script`, in the
+/// order of [`all`], or `None` if the language isn't Japanese.
+///
+/// Every word is classified once, when any script is first asked for.
+///
+/// # Example
+/// ```
+/// use random_word::{JaScript, Lang};
+/// if let Ok(lang) = "ja".parse::<Lang>() {
+///     let words = random_word::all_ja_script(JaScript::Kana, lang).unwrap();
+///     assert!(words.contains(&"テレビ"));
+/// }
+/// assert_eq!(random_word::all_ja_script(JaScript::Kana, Lang::En), None);
+/// ```
+#[inline(always)]
+pub fn all_ja_script(script: JaScript, lang: Lang) -> Option<&'static [&'static str]> {
+    ja_script::get(script, lang)
+}
+
+/// Returns a random word with the given language written in `script`.
+///
+/// # Example
+/// ```
+/// use random_word::{JaScript, Lang};
+/// if let Ok(lang) = "ja".parse::<Lang>() {
+///     let word = random_word::get_ja_script(JaScript::Hiragana, lang).unwrap();
+///     assert!(JaScript::Hiragana.matches(word));
+/// }
+/// ```
+#[inline(always)]
+pub fn get_ja_script(script: JaScript, lang: Lang) -> Option<&'static str> {
+    get_ja_script_rng(script, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language written in `script`,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{JaScript, Lang};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// assert_eq!(random_word::get_ja_script_rng(JaScript::Kanji, Lang::En, &mut rng), None);
+/// ```
+#[inline(always)]
+pub fn get_ja_script_rng(script: JaScript, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    ja_script::get(script, lang)?.choose(rng).copied()
+}
+
+/// Returns whether `word` fits `pattern`, where `_` matches any single
+/// character.
+pub(crate) fn fits_pattern(word: &str, pattern: &str) -> bool {
+    let mut chars = word.chars();
+    pattern
+        .chars()
+        .all(|p| chars.next().is_some_and(|c| p == '_' || p == c))
+        && chars.next().is_none()
+}
+
+/// Returns all words with the given language fitting `pattern`, where `_`
+/// matches any single character.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_pattern("c_t", Lang::En).unwrap();
+/// assert!(words.contains(&"cat") && words.contains(&"cut"));
+/// ```
+pub fn all_pattern(pattern: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get_len(pattern.chars().count(), lang)?
+        .iter()
+        .copied()
+        .filter(|word| fits_pattern(word, pattern))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language fitting `pattern`, where
+/// `_` matches any single character.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_pattern("h_r_e", Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_pattern(pattern: &str, lang: Lang) -> Option<&'static str> {
+    get_pattern_rng(pattern, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language fitting `pattern`, where
+/// `_` matches any single character, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_pattern_rng("h_r_e", Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_pattern_rng(pattern: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_pattern(pattern, lang)?.choose(rng).copied()
+}
+
+/// Returns whether `word` can be spelled with the characters of `letters`,
+/// each used at most once, where `?` in `letters` stands for any character.
+pub(crate) fn spelled_from(word: &str, letters: &str) -> bool {
+    let mut available: Vec<char> = letters.chars().collect();
+    word.chars().all(|char| {
+        let position = available
+            .iter()
+            .position(|&c| c == char)
+            .or_else(|| available.iter().position(|&c| c == '?'));
+        position.map(|i| available.swap_remove(i)).is_some()
+    })
+}
+
+/// Returns all words with the given language that can be spelled from the
+/// characters of `letters`, each used at most once, like a Scrabble rack.
+/// A `?` in `letters` is a blank that stands for any character.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_from_letters("aetrsn", Lang::En).unwrap();
+/// assert!(words.contains(&"astern") && words.contains(&"tan"));
+/// assert!(!words.contains(&"tatter"));
+///
+/// let words = random_word::all_from_letters("qu?z", Lang::En).unwrap();
+/// assert!(words.contains(&"quiz"));
+/// ```
+pub fn all_from_letters(letters: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let max_len = letters.chars().count();
+    let has_blank = letters.contains('?');
+    let rack = words::signature(letters);
+    let words: Vec<&'static str> = words::get(lang)
+        .iter()
+        .zip(words::signatures(lang).iter())
+        .filter(|&(word, &sig)| {
+            (has_blank || sig & !rack == 0)
+                && word.chars().count() <= max_len
+                && spelled_from(word, letters)
+        })
+        .map(|(&word, _)| word)
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns all words with the given language made of exactly the same
+/// characters as `word`, including `word` itself if it is in the list.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::anagrams_of("listen", Lang::En).unwrap();
+/// assert!(words.contains(&"silent") && words.contains(&"tinsel"));
+/// ```
+#[inline(always)]
+pub fn anagrams_of(word: &str, lang: Lang) -> Option<&'static [&'static str]> {
+    words::get_anagrams(word, lang).map(|boxed| &**boxed)
+}
+
+/// Returns whether `word` reads the same backwards, ignoring case.
+pub(crate) fn is_palindrome(word: &str) -> bool {
+    word.chars()
+        .map(words::fold)
+        .eq(word.chars().rev().map(words::fold))
+}
+
+/// Returns whether no letter occurs more than once in `word`, ignoring case.
+/// Non-alphabetic characters such as hyphens may repeat.
+pub(crate) fn is_isogram(word: &str) -> bool {
+    let mut seen = Vec::new();
+    word.chars()
+        .filter(|char| char.is_alphabetic())
+        .map(words::fold)
+        .all(|char| {
+            let new = !seen.contains(&char);
+            seen.push(char);
+            new
+        })
+}
+
+/// Returns all palindromes with the given language, ignoring case.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_palindromes(Lang::En).unwrap();
+/// assert!(words.contains(&"kayak"));
+/// ```
+pub fn all_palindromes(lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get(lang)
+        .iter()
+        .copied()
+        .filter(|word| is_palindrome(word))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random palindrome with the given language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_palindrome(Lang::En).unwrap();
+/// assert!(word.chars().eq(word.chars().rev()));
+/// ```
+#[inline(always)]
+pub fn get_palindrome(lang: Lang) -> Option<&'static str> {
+    get_palindrome_rng(lang, &mut default_rng())
+}
+
+/// Returns a random palindrome with the given language, using the
+/// supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_palindrome_rng(Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_palindrome_rng(lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_palindromes(lang)?.choose(rng).copied()
+}
+
+/// Returns all isograms (words without a repeated letter) with the given
+/// language, ignoring case.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_isograms(Lang::En).unwrap();
+/// assert!(words.contains(&"uncopyrightable"));
+/// assert!(!words.contains(&"letter"));
+/// ```
+pub fn all_isograms(lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get(lang)
+        .iter()
+        .copied()
+        .filter(|word| is_isogram(word))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random isogram with the given language.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_isogram(Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_isogram(lang: Lang) -> Option<&'static str> {
+    get_isogram_rng(lang, &mut default_rng())
+}
+
+/// Returns a random isogram with the given language, using the supplied
+/// RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_isogram_rng(Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_isogram_rng(lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    get_where_with_rng(lang, is_isogram, rng)
+}
+
+/// Returns all words with the given language whose consonant/vowel
+/// structure fits `pattern`, where `C` is a consonant, `V` a vowel and `_`
+/// any letter.
+///
+/// Returns `None` for Japanese and Chinese, which have no vowel letters.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_cv_pattern("CVCVC", Lang::En).unwrap();
+/// assert!(words.contains(&"lemon"));
+/// assert!(!words.contains(&"apple"));
+/// ```
+pub fn all_cv_pattern(pattern: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let vowels = phonetics::vowels(lang)?;
+    let words: Vec<&'static str> = words::get_len(pattern.chars().count(), lang)?
+        .iter()
+        .copied()
+        .filter(|word| phonetics::fits_cv_pattern(word, pattern, vowels))
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language whose consonant/vowel
+/// structure fits `pattern`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_cv_pattern("CVCV", Lang::En);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_cv_pattern(pattern: &str, lang: Lang) -> Option<&'static str> {
+    get_cv_pattern_rng(pattern, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language whose consonant/vowel
+/// structure fits `pattern`, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_cv_pattern_rng("CVCVC", Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_cv_pattern_rng(pattern: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_cv_pattern(pattern, lang)?.choose(rng).copied()
+}
+
+/// Returns the estimated number of syllables of `word` with the given
+/// language.
+///
+/// Estimates are rule-based: vowel groups for alphabetic languages, morae
+/// of the kana reading for Japanese and characters for Chinese. Returns
+/// `None` if the word has no estimate, such as a Japanese word without a
+/// kana reading.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::count_syllables("battery", Lang::En), Some(3));
+/// assert_eq!(random_word::count_syllables("horse", Lang::En), Some(1));
+/// ```
+#[inline(always)]
+pub fn count_syllables(word: &str, lang: Lang) -> Option<usize> {
+    phonetics::syllables(word, lang)
+}
+
+/// Returns all words with the given language and estimated syllable count.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::all_syllables(2, Lang::En).unwrap();
+/// assert!(words.contains(&"staple"));
+/// ```
+#[inline(always)]
+pub fn all_syllables(count: usize, lang: Lang) -> Option<&'static [&'static str]> {
+    words::syllable_index(lang).get(&count).map(|words| &**words)
+}
+
+/// Returns a random word with the given language and estimated syllable
+/// count.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_syllables(3, Lang::En).unwrap();
+/// assert_eq!(random_word::count_syllables(word, Lang::En), Some(3));
+/// ```
+#[inline(always)]
+pub fn get_syllables(count: usize, lang: Lang) -> Option<&'static str> {
+    get_syllables_rng(count, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language and estimated syllable
+/// count, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_syllables_rng(1, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_syllables_rng(count: usize, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_syllables(count, lang)?.choose(rng).copied()
+}
+
+/// Returns all words with the given language that rhyme with `word`,
+/// excluding `word` itself.
+///
+/// Rhymes are approximated from spelling: words rhyme when they share the
+/// ending from their second-to-last sounding vowel group, so "station"
+/// rhymes with "nation". Returns `None` for Japanese and Chinese.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::rhymes_with("station", Lang::En).unwrap();
+/// assert!(words.contains(&"nation"));
+/// assert!(!words.contains(&"station"));
+/// ```
+pub fn rhymes_with(word: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get_rhymes(word, lang)?
+        .iter()
+        .copied()
+        .filter(|rhyme| words::cmp_folded(rhyme, word).is_ne())
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns a random word with the given language that rhymes with `word`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_rhyme("cat", Lang::En).unwrap();
+/// assert!(word.ends_with("at"));
+/// ```
+#[inline(always)]
+pub fn get_rhyme(word: &str, lang: Lang) -> Option<&'static str> {
+    get_rhyme_rng(word, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language that rhymes with `word`,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_rhyme_rng("make", Lang::En, &mut rng);
+/// assert!(word.unwrap().ends_with("ake"));
+/// ```
+#[inline(always)]
+pub fn get_rhyme_rng(word: &str, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    rhymes_with(word, lang)?.choose(rng).copied()
+}
+
+/// Returns all words with the given language that sound like `word`,
+/// excluding `word` itself.
+///
+/// Words are compared by phonetic key: Metaphone for English, Soundex for
+/// German, Spanish and French, and the kana reading for Japanese, so that
+/// Japanese results are homophones. Returns `None` for Russian and Chinese.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::sounds_like("nite", Lang::En).unwrap();
+/// assert!(words.contains(&"night"));
+/// assert!(words.contains(&"knight"));
+/// ```
+pub fn sounds_like(word: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    let words: Vec<&'static str> = words::get_sounds_like(word, lang)?
+        .iter()
+        .copied()
+        .filter(|similar| words::cmp_folded(similar, word).is_ne())
+        .collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns all words with the given language whose consonant sounds
+/// encode `digits` in the major system, or `None` if there are none.
+///
+/// The major system maps consonant sounds to digits: 0 s/z, 1 t/d/th, 2 n,
+/// 3 m, 4 r, 5 l, 6 j/sh/ch, 7 k/hard g, 8 f/v, 9 p/b. Vowels and w, h and
+/// y are ignored. Sounds are approximated from spelling with Metaphone, so
+/// only English is supported.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::major_words("314", Lang::En).unwrap();
+/// assert!(words.contains(&"meteor"));
+/// ```
+#[inline(always)]
+pub fn major_words(digits: &str, lang: Lang) -> Option<&'static [&'static str]> {
+    words::get_major(digits, lang).map(|words| &words[..])
+}
+
+/// Maximum number of sequences [`mnemonic_for_number`] returns.
+const MAX_MNEMONICS: usize = 16;
+
+/// Returns sequences of words whose consonant sounds spell `number` in the
+/// major system, as in [`major_words`].
+///
+/// Every sequence uses as few words as possible, and each word is the most
+/// common of the words for its digits. Up to 16 sequences are returned,
+/// splitting the number differently. Returns `None` if `number` is empty,
+/// has characters other than ASCII digits, or can't be spelled.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let sequences = random_word::mnemonic_for_number("314159", Lang::En).unwrap();
+/// for words in &sequences {
+///     let digits: String = words.iter().map(|word| random_word::major_digits(word, Lang::En).unwrap()).collect();
+///     assert_eq!(digits, "314159");
+/// }
+/// ```
+pub fn mnemonic_for_number(number: &str, lang: Lang) -> Option<Vec<Vec<&'static str>>> {
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let len = number.len();
+    // best[i][j] is the most common word for number[i..j].
+    let best: Vec<Vec<Option<&'static str>>> = (0..len)
+        .map(|i| {
+            (0..=len)
+                .map(|j| {
+                    let words = words::get_major(number.get(i..j).filter(|_| j > i)?, lang)?;
+                    words.iter().copied().min_by_key(|word| {
+                        (frequency::rank(word, lang).unwrap_or(usize::MAX), word.len(), *word)
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    // fewest[i] is the fewest words that spell number[i..].
+    let mut fewest = vec![None; len + 1];
+    fewest[len] = Some(0);
+    for i in (0..len).rev() {
+        fewest[i] = (i + 1..=len)
+            .filter(|&j| best[i][j].is_some())
+            .filter_map(|j| fewest[j].map(|n: usize| n + 1))
+            .min();
+    }
+    fewest[0]?;
+
+    let mut sequences = Vec::new();
+    let mut stack = vec![(0, Vec::new())];
+    while let Some((i, words)) = stack.pop() {
+        if i == len {
+            sequences.push(words);
+            if sequences.len() == MAX_MNEMONICS {
+                break;
+            }
+            continue;
+        }
+        for j in i + 1..=len {
+            if let (Some(word), Some(rest)) = (best[i][j], fewest[j])
+                && Some(rest + 1) == fewest[i]
+            {
+                let mut words = words.clone();
+                words.push(word);
+                stack.push((j, words));
+            }
+        }
+    }
+    Some(sequences)
+}
+
+/// Returns the major-system digits that the consonant sounds of `word`
+/// encode, as in [`major_words`], or `None` for languages other than
+/// English and words without consonant sounds.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::major_digits("meteor", Lang::En).as_deref(), Some("314"));
+/// assert_eq!(random_word::major_digits("whey", Lang::En), None);
+/// ```
+#[inline(always)]
+pub fn major_digits(word: &str, lang: Lang) -> Option<String> {
+    phonetics::major_key(word, lang).map(String::from)
+}
+
+/// Returns the words with the given language within `max_dist` edits of
+/// `word`, closest first.
+///
+/// Distances are Levenshtein distances in characters. Words at the same
+/// distance are sorted byte-wise. The first call for a language builds a
+/// BK-tree over its words.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::suggest("recieve", Lang::En, 2).unwrap();
+/// assert!(words.contains(&"receive"));
+/// assert_eq!(random_word::suggest("horse", Lang::En, 1).unwrap()[0], "horse");
+/// ```
+pub fn suggest(word: &str, lang: Lang, max_dist: usize) -> Option<Vec<&'static str>> {
+    let mut found = words::bk_tree(lang).find(word, max_dist);
+    found.sort_unstable();
+    let words: Vec<&'static str> = found.into_iter().map(|(_, word)| word).collect();
+    (!words.is_empty()).then_some(words)
+}
+
+/// Returns the words of `distinct`, a language's list without repeated
+/// entries, grouped by starting character, keeping the groups of at least
+/// `min` words, ordered by character.
+fn initial_buckets<'a>(min: usize, distinct: &'a [&'static str], lang: Lang) -> Vec<&'a [&'static str]> {
+    lang.initials()
+        .iter()
+        .map(|&char| words::prefix_range(distinct, char.encode_utf8(&mut [0; 4])))
+        .filter(|bucket| bucket.len() >= min)
+        .collect()
+}
+
+/// Returns `n` distinct random words with the given language that all
+/// start with the same, randomly chosen, character.
+///
+/// Initials are chosen in proportion to how many distinct words start with
+/// them, among those with at least `n` distinct words.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::get_alliterative(3, Lang::En).unwrap();
+/// let first = words[0].chars().next();
+/// assert!(words.iter().all(|w| w.chars().next() == first));
+/// ```
+#[inline(always)]
+pub fn get_alliterative(n: usize, lang: Lang) -> Option<Vec<&'static str>> {
+    get_alliterative_rng(n, lang, &mut default_rng())
+}
+
+/// Returns `n` distinct random words with the given language that all
+/// start with the same, randomly chosen, character, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let words = random_word::get_alliterative_rng(4, Lang::En, &mut rng);
+/// assert_eq!(words.unwrap().len(), 4);
+/// ```
+pub fn get_alliterative_rng(n: usize, lang: Lang, rng: &mut impl Rng) -> Option<Vec<&'static str>> {
+    let distinct = words::distinct(lang);
+    let buckets = initial_buckets(n, &distinct, lang);
+    let initial = choose_across(&buckets, rng)?.chars().next()?;
+    let bucket = buckets.iter().find(|bucket| bucket[0].starts_with(initial))?;
+    Some(bucket.choose_multiple(rng, n).copied().collect())
+}
+
+/// Returns `n` random words with the given language that all start with
+/// different characters, ignoring case.
+///
+/// Each word is drawn uniformly from the words whose initial has not been
+/// used yet. Returns `None` if fewer than `n` initials exist.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::get_unique_initials(6, Lang::En).unwrap();
+/// let mut initials: Vec<char> = words.iter().filter_map(|w| w.chars().next()).collect();
+/// initials.sort();
+/// initials.dedup();
+/// assert_eq!(initials.len(), 6);
+/// ```
+#[inline(always)]
+pub fn get_unique_initials(n: usize, lang: Lang) -> Option<Vec<&'static str>> {
+    get_unique_initials_rng(n, lang, &mut default_rng())
+}
+
+/// Returns `n` random words with the given language that all start with
+/// different characters, ignoring case, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let words = random_word::get_unique_initials_rng(4, Lang::En, &mut rng);
+/// assert_eq!(words.unwrap().len(), 4);
+/// ```
+pub fn get_unique_initials_rng(n: usize, lang: Lang, rng: &mut impl Rng) -> Option<Vec<&'static str>> {
+    let mut buckets: Vec<(char, &'static [&'static str])> = words::starts_with_folded_index(lang)
+        .iter()
+        .map(|(&char, bucket)| (char, &**bucket))
+        .collect();
+    if buckets.len() < n {
+        return None;
+    }
+    buckets.sort_unstable_by_key(|&(char, _)| char);
+
+    let mut words = Vec::with_capacity(n);
+    for _ in 0..n {
+        let total: usize = buckets.iter().map(|(_, bucket)| bucket.len()).sum();
+        let mut index = rng.random_range(0..total);
+        let position = buckets
+            .iter()
+            .position(|(_, bucket)| {
+                let found = index < bucket.len();
+                if !found {
+                    index -= bucket.len();
+                }
+                found
+            })
+            .expect("index is within the total bucket length");
+        let (_, bucket) = buckets.swap_remove(position);
+        words.push(bucket[index]);
+    }
+    Some(words)
+}
+
+/// Returns `n` random words with the given language that pairwise differ
+/// by at least `min_edit_distance` edits, so that none can be mistaken for
+/// another, as with "track", "tract" and "trace".
+///
+/// Each word is drawn uniformly from the distinct words far enough from
+/// those already chosen, and no word is returned twice, even when
+/// `min_edit_distance` is 0. Returns `None` if no such word is found after a
+/// bounded number of draws.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::get_n_distinct(4, Lang::En, 3).unwrap();
+/// assert_eq!(words.len(), 4);
+/// ```
+#[inline(always)]
+pub fn get_n_distinct(n: usize, lang: Lang, min_edit_distance: usize) -> Option<Vec<&'static str>> {
+    get_n_distinct_rng(n, lang, min_edit_distance, &mut default_rng())
+}
+
+/// Returns `n` random words with the given language that pairwise differ
+/// by at least `min_edit_distance` edits, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let words = random_word::get_n_distinct_rng(6, Lang::En, 4, &mut rng);
+/// assert_eq!(words.unwrap().len(), 6);
+/// ```
+pub fn get_n_distinct_rng(
+    n: usize,
+    lang: Lang,
+    min_edit_distance: usize,
+    rng: &mut impl Rng,
+) -> Option<Vec<&'static str>> {
+    let words = words::distinct(lang);
+    if n > words.len() {
+        return None;
+    }
+    // The words are always distinct, even for a distance of 0.
+    let min_edit_distance = min_edit_distance.max(1);
+    let mut chosen: Vec<&'static str> = Vec::with_capacity(n);
+    for _ in 0..n.saturating_mul(REJECTION_ATTEMPTS) {
+        if chosen.len() == n {
+            break;
+        }
+        let word = *words.choose(rng)?;
+        if chosen.iter().all(|other| bktree::levenshtein(other, word) >= min_edit_distance) {
+            chosen.push(word);
+        }
+    }
+    (chosen.len() == n).then_some(chosen)
+}
+
+/// Returns one random word with the given language per character of
+/// `target`, so that the initials spell it. Initials are matched ignoring
+/// case and whitespace in `target` is skipped.
+///
+/// Returns `None` if some character starts no word.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::get_acrostic("RUST", Lang::En).unwrap();
+/// let initials: String = words.iter().filter_map(|w| w.chars().next()).collect();
+/// assert_eq!(initials, "rust");
+/// ```
+#[inline(always)]
+pub fn get_acrostic(target: &str, lang: Lang) -> Option<Vec<&'static str>> {
+    get_acrostic_rng(target, lang, &mut default_rng())
+}
+
+/// Returns one random word with the given language per character of
+/// `target`, so that the initials spell it, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let words = random_word::get_acrostic_rng("team rust", Lang::En, &mut rng);
+/// assert_eq!(words.unwrap().len(), 8);
+/// ```
+pub fn get_acrostic_rng(target: &str, lang: Lang, rng: &mut impl Rng) -> Option<Vec<&'static str>> {
+    target
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .map(|char| get_starts_with_case_rng(char, CaseSensitivity::Insensitive, lang, rng))
+        .collect()
+}
+
+/// A frequency tier: the given number of most common words of a language.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Tier {
+    /// The 1,000 most common words.
+    Top1k,
+    /// The 5,000 most common words.
+    Top5k,
+    /// The 10,000 most common words.
+    Top10k,
+}
+
+impl Tier {
+    /// Returns the number of words in this tier.
+    #[inline(always)]
+    pub fn size(self) -> usize {
+        match self {
+            Tier::Top1k => 1_000,
+            Tier::Top5k => 5_000,
+            Tier::Top10k => 10_000,
+        }
+    }
+
+    /// Returns whether `word` is among the words of this tier with the
+    /// given language.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, Tier};
+    /// assert!(Tier::Top1k.contains("water", Lang::En));
+    /// assert!(!Tier::Top10k.contains("zymurgy", Lang::En));
+    /// ```
+    #[inline(always)]
+    pub fn contains(self, word: &str, lang: Lang) -> bool {
+        frequency::rank(word, lang).is_some_and(|rank| rank < self.size())
+    }
+}
+
+/// Returns the most common words with the given language, most frequent
+/// first.
+///
+/// Returns `None` if the language has no frequency data. Only English
+/// currently ships frequency data, for `Lang::En` and `Lang::En10k`.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Tier};
+/// let words = random_word::all_common(Tier::Top5k, Lang::En).unwrap();
+/// assert_eq!(words.len(), 5_000);
+/// assert_eq!(words[0], "the");
+/// ```
+#[inline(always)]
+pub fn all_common(tier: Tier, lang: Lang) -> Option<&'static [&'static str]> {
+    top_n(tier.size(), lang)
+}
+
+/// Returns the `n` most common words with the given language, most frequent
+/// first, or every ranked word if it has fewer.
+///
+/// Smaller `n` gives more memorable words but fewer bits per word; see
+/// [`top_n_entropy_bits`]. Returns `None` if the language has no frequency
+/// data. English and `Lang::En10k` ship frequency data.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::top_n(2_000, Lang::En).unwrap();
+/// assert_eq!(words.len(), 2_000);
+/// assert_eq!(words[0], "the");
+/// ```
+#[inline(always)]
+pub fn top_n(n: usize, lang: Lang) -> Option<&'static [&'static str]> {
+    frequency::ranked(lang).map(|words| &words[..n.min(words.len())])
+}
+
+/// Returns the entropy in bits of one word drawn uniformly from
+/// [`top_n`]`(n, lang)`, or `None` if the language has no frequency data.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::top_n_entropy_bits(1_024, Lang::En), Some(10.0));
+/// ```
+#[inline(always)]
+pub fn top_n_entropy_bits(n: usize, lang: Lang) -> Option<f64> {
+    top_n(n, lang).map(|words| pool_entropy(words.len()))
+}
+
+/// Returns a random word among the most common words with the given
+/// language.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Tier};
+/// let word = random_word::get_common(Tier::Top1k, Lang::En).unwrap();
+/// assert!(Tier::Top1k.contains(word, Lang::En));
+/// ```
+#[inline(always)]
+pub fn get_common(tier: Tier, lang: Lang) -> Option<&'static str> {
+    get_common_rng(tier, lang, &mut default_rng())
+}
+
+/// Returns a random word among the most common words with the given
+/// language, using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{Lang, Tier};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_common_rng(Tier::Top10k, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_common_rng(tier: Tier, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    all_common(tier, lang)?.choose(rng).copied()
+}
+
+/// Returns the frequency rank of `word` with the given language, where `0`
+/// is the most common word.
+///
+/// Returns `None` if the word or the language has no frequency data.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::frequency_rank("the", Lang::En), Some(0));
+/// assert_eq!(random_word::frequency_rank("zymurgy", Lang::En), None);
+/// ```
+#[inline(always)]
+pub fn frequency_rank(word: &str, lang: Lang) -> Option<usize> {
+    frequency::rank(word, lang)
+}
+
+/// How [`get_weighted`] weighs words.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum WeightModel {
+    /// Every word of the language is equally likely.
+    #[default]
+    Uniform,
+    /// Words with frequency data are weighted by Zipf's law, `1 / rank`, so
+    /// the most common word is twice as likely as the second.
+    Zipf,
+    /// Words with frequency data are weighted by the Zipf-Mandelbrot law
+    /// `1 / (rank + 2.7)`, which fits English corpus counts more closely
+    /// than [`Zipf`](Self::Zipf) by flattening the top ranks.
+    Corpus,
+}
+
+impl WeightModel {
+    /// Returns the unnormalized weight of the word at the zero-based
+    /// frequency `rank`.
+    #[inline(always)]
+    fn weight(self, rank: usize) -> f64 {
+        match self {
+            WeightModel::Uniform => 1.0,
+            WeightModel::Zipf => 1.0 / (rank + 1) as f64,
+            WeightModel::Corpus => 1.0 / (rank as f64 + 3.7),
+        }
+    }
+
+    /// Returns the Shannon entropy in bits of one word drawn by
+    /// [`get_weighted`] with this model and the given language.
+    ///
+    /// Weighted models have far less entropy than [`Uniform`](Self::Uniform),
+    /// and an attacker guessing the most likely words first needs fewer
+    /// guesses than the Shannon entropy suggests. Returns `None` if the
+    /// language has no frequency data for a weighted model.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WeightModel};
+    /// let uniform = WeightModel::Uniform.entropy_bits(Lang::En).unwrap();
+    /// let zipf = WeightModel::Zipf.entropy_bits(Lang::En).unwrap();
+    /// assert!(zipf < uniform);
+    /// ```
+    pub fn entropy_bits(self, lang: Lang) -> Option<f64> {
+        let words = match self {
+            WeightModel::Uniform => return Some(entropy_bits(lang)),
+            _ => frequency::ranked(lang)?,
+        };
+        let total: f64 = (0..words.len()).map(|rank| self.weight(rank)).sum();
+        Some(
+            (0..words.len())
+                .map(|rank| self.weight(rank) / total)
+                .map(|p| -p * p.log2())
+                .sum(),
+        )
+    }
+}
+
+/// Returns a random word with the given language, weighted by `model`.
+///
+/// Weighted models draw only from words with frequency data, proportionally
+/// to how often they occur, which gives natural-sounding words. Returns
+/// `None` if the language has no frequency data for a weighted model.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, WeightModel};
+/// let word = random_word::get_weighted(WeightModel::Zipf, Lang::En).unwrap();
+/// assert!(random_word::frequency_rank(word, Lang::En).is_some());
+/// ```
+#[inline(always)]
+pub fn get_weighted(model: WeightModel, lang: Lang) -> Option<&'static str> {
+    get_weighted_rng(model, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language, weighted by `model`,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{Lang, WeightModel};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_weighted_rng(WeightModel::Corpus, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+pub fn get_weighted_rng(model: WeightModel, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    match model {
+        WeightModel::Uniform => Some(get_with_rng(lang, rng)),
+        _ => {
+            let index = frequency::weighted(model, lang)?.sample(rng);
+            frequency::ranked(lang).map(|words| words[index])
+        }
+    }
+}
+
+/// A part of speech.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pos {
+    /// Nouns, such as "horse".
+    Noun,
+    /// Verbs in their base form, such as "staple".
+    Verb,
+    /// Adjectives, such as "correct".
+    Adjective,
+    /// Adverbs, such as "quickly".
+    Adverb,
+}
+
+impl Pos {
+    /// Returns whether `word` is tagged with this part of speech with the
+    /// given language. A word may have several parts of speech.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, Pos};
+    /// assert!(Pos::Adjective.contains("correct", Lang::En));
+    /// assert!(Pos::Verb.contains("staple", Lang::En));
+    /// assert!(!Pos::Adverb.contains("horse", Lang::En));
+    /// ```
+    #[inline(always)]
+    pub fn contains(self, word: &str, lang: Lang) -> bool {
+        pos::get(self, lang).is_some_and(|words| words.binary_search(&word).is_ok())
+    }
+}
+
+/// Returns all words with the given language tagged with `pos`.
+///
+/// Returns `None` if the language has no part-of-speech data. Only English
+/// currently ships part-of-speech data.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Pos};
+/// let nouns = random_word::all_pos(Pos::Noun, Lang::En).unwrap();
+/// assert!(nouns.contains(&"zebra"));
+/// ```
+#[inline(always)]
+pub fn all_pos(pos: Pos, lang: Lang) -> Option<&'static [&'static str]> {
+    pos::get(pos, lang)
+}
+
+/// Returns a random word with the given language tagged with `pos`.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Pos};
+/// let name = [Pos::Adjective, Pos::Noun].map(|pos| random_word::get_pos(pos, Lang::En).unwrap());
+/// assert!(Pos::Noun.contains(name[1], Lang::En));
+/// ```
+#[inline(always)]
+pub fn get_pos(pos: Pos, lang: Lang) -> Option<&'static str> {
+    get_pos_rng(pos, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language tagged with `pos`, using
+/// the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{Lang, Pos};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_pos_rng(Pos::Adverb, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_pos_rng(pos: Pos, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    pos::get(pos, lang)?.choose(rng).copied()
+}
+
+/// Returns the base forms of the given language's words, without plurals
+/// and conjugations, in the order of [`all`]. Inflected near-duplicates such
+/// as "runs" and "running" add little entropy for the memory they cost.
+///
+/// English lists drop a word when it is a regular inflection of another
+/// word of the same list: endings in -s, -es, -ies, -ed and -ing, allowing
+/// for a dropped final "e" or a doubled consonant. Only the spelling is
+/// compared, so nouns such as "building" that share a form with a verb go
+/// too. Japanese and Chinese entries are dictionary forms already, so they
+/// are all kept. Returns `None` for German, Spanish, French and Russian,
+/// which have no lemma data.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let lemmas = random_word::all_lemmas(Lang::En).unwrap();
+/// assert!(lemmas.contains(&"run"));
+/// assert!(!lemmas.contains(&"runs") && !lemmas.contains(&"running"));
+/// ```
+#[inline(always)]
+pub fn all_lemmas(lang: Lang) -> Option<&'static [&'static str]> {
+    lemma::lemmas(lang)
+}
+
+/// Returns whether `word` is a base form of the given language, as listed
+/// by [`all_lemmas`], or `None` if the language has no lemma data.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::is_lemma("city", Lang::En), Some(true));
+/// assert_eq!(random_word::is_lemma("cities", Lang::En), Some(false));
+/// ```
+#[inline(always)]
+pub fn is_lemma(word: &str, lang: Lang) -> Option<bool> {
+    Some(lemma::lemmas(lang)?.binary_search(&word).is_ok())
+}
+
+/// Returns the level of `word` in the six-level HSK vocabulary, from 1 for
+/// beginners to 6, or `None` if the word isn't in the vocabulary.
+///
+/// `word` is an entry of the Chinese list, or its simplified form alone;
+/// entries sharing a simplified form share its level. Only Chinese ships
+/// HSK data, so other languages always return `None`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// if let Ok(lang) = "zh".parse::<Lang>() {
+///     assert_eq!(random_word::hsk_level("愛 爱", lang), Some(1));
+///     assert_eq!(random_word::hsk_level("爱", lang), Some(1));
+/// }
+/// assert_eq!(random_word::hsk_level("love", Lang::En), None);
+/// ```
+#[inline(always)]
+pub fn hsk_level(word: &str, lang: Lang) -> Option<u8> {
+    hsk::level(word, lang)
+}
+
+/// Returns all words with the given language at HSK `level`, in the order
+/// of [`all`].
+///
+/// Returns `None` if the language has no HSK data, which is every language
+/// but Chinese, or if `level` isn't between 1 and 6.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// if let Ok(lang) = "zh".parse::<Lang>() {
+///     let words = random_word::all_hsk(1, lang).unwrap();
+///     assert!(words.contains(&"愛 爱"));
+/// }
+/// assert_eq!(random_word::all_hsk(1, Lang::En), None);
+/// ```
+#[inline(always)]
+pub fn all_hsk(level: u8, lang: Lang) -> Option<&'static [&'static str]> {
+    hsk::get(level, lang)
+}
+
+/// Returns a random word with the given language at HSK `level`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// if let Ok(lang) = "zh".parse::<Lang>() {
+///     let word = random_word::get_hsk(2, lang).unwrap();
+///     assert_eq!(random_word::hsk_level(word, lang), Some(2));
+/// }
+/// ```
+#[inline(always)]
+pub fn get_hsk(level: u8, lang: Lang) -> Option<&'static str> {
+    get_hsk_rng(level, lang, &mut default_rng())
+}
+
+/// Returns a random word with the given language at HSK `level`, using the
+/// supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// assert_eq!(random_word::get_hsk_rng(1, Lang::En, &mut rng), None);
+/// ```
+#[inline(always)]
+pub fn get_hsk_rng(level: u8, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    hsk::get(level, lang)?.choose(rng).copied()
+}
+
+/// Returns `word` of the given language written in `script`.
+///
+/// Japanese entries are romanized from their kana reading, Chinese entries
+/// from the Pinyin of each character, and Russian words letter by letter.
+/// Chinese characters take their most common reading, so words with a
+/// character read differently in them come out wrong. [`Script::Native`]
+/// returns `word` unchanged.
+///
+/// Returns `None` if the language has no romanization, which is every
+/// language but Japanese, Chinese and Russian, or if `word` has a character
+/// that can't be romanized.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Script};
+/// if let Ok(lang) = "ja".parse::<Lang>() {
+///     assert_eq!(random_word::romanize("拾う [ひろう]", Script::Ascii, lang).as_deref(), Some("hirou"));
+/// }
+/// if let Ok(lang) = "zh".parse::<Lang>() {
+///     assert_eq!(random_word::romanize("中文 中文", Script::Latin, lang).as_deref(), Some("zhōngwén"));
+/// }
+/// ```
+#[cfg(feature = "romanization")]
+#[inline(always)]
+pub fn romanize(word: &str, script: Script, lang: Lang) -> Option<String> {
+    romanization::romanize(word, script, lang)
+}
+
+/// Returns the words of the given language written in `script`, sorted
+/// byte-wise, or `None` if the language has no romanization.
+///
+/// Words that romanize alike, such as the Japanese homophones "橋 [はし]" and
+/// "箸 [はし]", appear once, and words that can't be romanized are left out,
+/// so [`entropy_bits`] of the native list overstates the entropy of a word
+/// drawn from this one. [`Script::Native`] returns [`all`].
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Script};
+/// assert_eq!(random_word::all_script(Script::Native, Lang::En), Some(random_word::all(Lang::En)));
+/// if let Ok(lang) = "ru".parse::<Lang>() {
+///     let words = random_word::all_script(Script::Ascii, lang).unwrap();
+///     assert!(words.iter().all(|word| word.is_ascii()));
+/// }
+/// ```
+#[cfg(feature = "romanization")]
+#[inline(always)]
+pub fn all_script(script: Script, lang: Lang) -> Option<&'static [&'static str]> {
+    romanization::all(script, lang)
+}
+
+/// Returns a random word of the given language written in `script`, drawn
+/// from [`all_script`].
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Script};
+/// if let Ok(lang) = "zh".parse::<Lang>() {
+///     let word = random_word::get_script(Script::Ascii, lang).unwrap();
+///     assert!(word.is_ascii());
+/// }
+/// ```
+#[cfg(feature = "romanization")]
+#[inline(always)]
+pub fn get_script(script: Script, lang: Lang) -> Option<&'static str> {
+    get_script_rng(script, lang, &mut default_rng())
+}
+
+/// Returns a random word of the given language written in `script`, using
+/// the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{Lang, Script};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_script_rng(Script::Native, Lang::En, &mut rng);
+/// assert!(word.is_some());
+/// ```
+#[cfg(feature = "romanization")]
+#[inline(always)]
+pub fn get_script_rng(script: Script, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    romanization::all(script, lang)?.choose(rng).copied()
+}
+
+/// Number of random draws [`get_where`] attempts before filtering the
+/// whole list.
+const REJECTION_ATTEMPTS: usize = 64;
+
+/// Returns a random word with the given language satisfying `predicate`.
+///
+/// Words are first drawn at random and tested, which is fast when many
+/// words match. If no match is found after a few attempts, every word is
+/// tested and one is picked from the matches. Either way, every matching
+/// word is equally likely.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::get_where(Lang::En, |w| w.contains('q') && !w.contains('u'));
+/// assert!(word.is_some());
+/// ```
+#[inline(always)]
+pub fn get_where(lang: Lang, predicate: impl Fn(&str) -> bool) -> Option<&'static str> {
+    get_where_with_rng(lang, predicate, &mut default_rng())
+}
+
+/// Returns a random word with the given language satisfying `predicate`,
+/// using the supplied RNG.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::Lang;
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let word = random_word::get_where_with_rng(Lang::En, |w| w.len() > 12, &mut rng);
+/// assert!(word.unwrap().len() > 12);
+/// ```
+pub fn get_where_with_rng(
+    lang: Lang,
+    predicate: impl Fn(&str) -> bool,
+    rng: &mut impl Rng,
+) -> Option<&'static str> {
+    let words = words::get(lang);
+    for _ in 0..REJECTION_ATTEMPTS {
+        let word = words.choose(rng).expect("array is empty");
+        if predicate(word) {
+            return Some(word);
+        }
+    }
+    let matches: Vec<&'static str> = words.iter().copied().filter(|word| predicate(word)).collect();
+    matches.choose(rng).copied()
+}
+
+/// Returns the entropy in bits of one word drawn uniformly from a pool of
+/// `pool_size` words. An empty pool has no entropy.
+#[inline(always)]
+pub(crate) fn pool_entropy(pool_size: usize) -> f64 {
+    (pool_size.max(1) as f64).log2()
+}
+
+/// Returns the entropy in bits of drawing `n` distinct words uniformly from
+/// a pool of `pool_size` words, the base-2 logarithm of the falling
+/// factorial `pool_size! / (pool_size - n)!`.
+pub(crate) fn distinct_entropy(pool_size: usize, n: usize) -> f64 {
+    (0..n).map(|i| pool_entropy(pool_size.saturating_sub(i))).sum()
+}
+
+/// Returns the entropy in bits of one word drawn uniformly at random with
+/// the given language. Entries a list repeats count once, as they do for
+/// [`Passphrase`], so that the figure isn't overstated.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let bits = random_word::entropy_bits(Lang::En);
+/// let mut words = random_word::all(Lang::En).to_vec();
+/// words.dedup();
+/// assert_eq!(bits, (words.len() as f64).log2());
+/// ```
+#[inline(always)]
+pub fn entropy_bits(lang: Lang) -> f64 {
+    pool_entropy(words::distinct(lang).len())
+}
+
+/// Returns the entropy in bits of a passphrase of `n_words` words, each
+/// drawn independently and uniformly at random with the given language.
+///
+/// The entropy does not depend on the separator, which an attacker is
+/// assumed to know.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let bits = random_word::passphrase_entropy(4, Lang::En);
+/// assert_eq!(bits, 4.0 * random_word::entropy_bits(Lang::En));
+/// ```
+#[inline(always)]
+pub fn passphrase_entropy(n_words: usize, lang: Lang) -> f64 {
+    n_words as f64 * entropy_bits(lang)
+}
+
+/// Returns the fewest words drawn uniformly with the given language whose
+/// combined entropy is at least `bits`.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let n = random_word::words_for_entropy(80.0, Lang::En);
+/// assert!(random_word::passphrase_entropy(n, Lang::En) >= 80.0);
+/// assert!(random_word::passphrase_entropy(n - 1, Lang::En) < 80.0);
+/// ```
+#[inline(always)]
+pub fn words_for_entropy(bits: f64, lang: Lang) -> usize {
+    (bits / entropy_bits(lang)).ceil().max(1.0) as usize
+}
+
+/// Returns how many rolls of a six-sided die [`from_dice_rolls`] reads per
+/// word with the given language: the most whose outcomes all map to distinct
+/// words. Entries a list repeats count once.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let dice = random_word::dice_per_word(Lang::En);
+/// assert!(6usize.pow(dice as u32) <= Lang::En.word_count());
+/// assert!(6usize.pow(dice as u32 + 1) > Lang::En.word_count());
+/// ```
+pub fn dice_per_word(lang: Lang) -> usize {
+    let count = words::distinct(lang).len();
+    let mut dice = 0;
+    while 6usize.pow(dice as u32 + 1) <= count {
+        dice += 1;
+    }
+    dice
+}
+
+/// Returns the words selected by rolls of a physical six-sided die, each
+/// roll from 1 to 6, reading [`dice_per_word`] rolls per word.
+///
+/// Each group of rolls is read as a base-6 number `i` and picks the word
+/// at index `i * len / 6^dice` of the distinct words of [`all`], which
+/// skips entries a list repeats, spreading the `6^dice` outcomes evenly
+/// over the whole list. Each word carries `dice * log2(6)` bits of entropy.
+///
+/// The mapping changes with the word list, including under the `clean`
+/// feature, which drops words from it. Record [`Lang::dataset_version`]
+/// with printed dice tables to notice when they no longer match.
+///
+/// Returns [`Error::DiceRollOutOfRange`] for a roll outside 1 to 6, and
+/// [`Error::DiceRollCount`] if the number of rolls isn't a multiple of
+/// [`dice_per_word`].
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let dice = random_word::dice_per_word(Lang::En);
+/// let rolls: Vec<u8> = [3, 1, 4, 1, 5, 6, 2, 6, 5, 3, 5, 2, 4, 6].iter().copied().cycle().take(2 * dice).collect();
+/// let words = random_word::from_dice_rolls(&rolls, Lang::En).unwrap();
+/// assert_eq!(words.len(), 2);
+/// assert_eq!(words, random_word::from_dice_rolls(&rolls, Lang::En).unwrap());
+/// ```
+pub fn from_dice_rolls(rolls: &[u8], lang: Lang) -> Result<Vec<&'static str>, Error> {
+    let dice = dice_per_word(lang);
+    if let Some(&roll) = rolls.iter().find(|roll| !(1..=6).contains(*roll)) {
+        return Err(Error::DiceRollOutOfRange(roll));
+    }
+    if dice == 0 || !rolls.len().is_multiple_of(dice) {
+        return Err(Error::DiceRollCount {
+            per_word: dice,
+            rolls: rolls.len(),
+        });
+    }
+    let words = words::distinct(lang);
+    let outcomes = 6u64.pow(dice as u32);
+    Ok(rolls
+        .chunks(dice)
+        .map(|group| {
+            let index = group.iter().fold(0, |index, &roll| index * 6 + (roll - 1) as u64);
+            words[(index * words.len() as u64 / outcomes) as usize]
+        })
+        .collect())
+}
+
+/// Returns `word` with letters replaced by look-alike digits and symbols,
+/// such as `p455w0rd` for "password" at [`LeetLevel::Light`].
+///
+/// The result is fully determined by the word, so it adds no strength
+/// against an attacker who tries leet spellings, as
+/// [`estimate`] does. Use [`Transform::Leet`] with `random: true` in a
+/// passphrase for substitutions that count.
+///
+/// # Example
+/// ```
+/// use random_word::LeetLevel;
+/// assert_eq!(random_word::leet("password", LeetLevel::Light), "p455w0rd");
+/// assert_eq!(random_word::leet("Big lizards", LeetLevel::Heavy), "8!9 1!2@rd$");
+/// ```
+#[inline(always)]
+pub fn leet(word: &str, level: LeetLevel) -> String {
+    transform::leet(word, level)
+}
+
+/// Fills a [`Template`] such as `"{adj}-{noun}-{4:digits}"` with words of
+/// the given language.
+///
+/// Returns [`Error::InvalidTemplate`] if the template doesn't parse, and
+/// [`Error::NoWordsForFilter`] if a placeholder matches no words. Parse the
+/// template once with [`Template::parse`] to fill it many times.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let password = random_word::generate_template("{5:word}{2:digits}{symbol}", Lang::En).unwrap();
+/// assert_eq!(password.chars().count(), 8);
+/// ```
+#[inline(always)]
+pub fn generate_template(template: &str, lang: Lang) -> Result<String, Error> {
+    Template::parse(template)?.generate(lang)
+}
+
+/// Returns the number of bits each word carries in [`encode_bytes`]: the
+/// largest `k` such that the language has at least `2^k` distinct words.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::bits_per_word(Lang::En), 17);
+/// ```
+#[inline(always)]
+pub fn bits_per_word(lang: Lang) -> u32 {
+    codec::bits_per_word(lang)
+}
+
+/// Encodes `bytes` as words of the given language, so that keys, UUIDs and
+/// tokens can be read and typed as words. Decode them with
+/// [`decode_words`].
+///
+/// Words come from a subset of [`bits_per_word`]`(lang)` bits' worth of
+/// words, spread evenly over the list like [`from_dice_rolls`], and each
+/// carries that many bits. A final word holds the leftover bits and marks
+/// where the data ends, so any number of bytes round-trips. The mapping
+/// only changes if the word list does.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let key = [0xde, 0xad, 0xbe, 0xef];
+/// let words = random_word::encode_bytes(&key, Lang::En);
+/// // 17 bits in the first word, and the 15 left over in the second.
+/// assert_eq!(words.len(), 2);
+/// assert_eq!(random_word::decode_words(&words, Lang::En).unwrap(), key);
+/// ```
+#[inline(always)]
+pub fn encode_bytes(bytes: &[u8], lang: Lang) -> Vec<&'static str> {
+    codec::encode(bytes, lang)
+}
+
+/// Decodes words produced by [`encode_bytes`] back into bytes.
+///
+/// Returns [`Error::InvalidWordCount`] for no words, [`Error::UnknownWord`]
+/// with the position of a word outside the encoding's subset, and
+/// [`Error::InvalidPadding`] if the last word doesn't end the data on a
+/// byte boundary, which catches a missing word unless each word carries a
+/// whole number of bytes.
+///
+/// # Example
+/// ```
+/// use random_word::{Error, Lang};
+/// let words = random_word::encode_bytes(b"hi", Lang::En);
+/// assert_eq!(random_word::decode_words(&words, Lang::En), Ok(b"hi".to_vec()));
+/// assert_eq!(random_word::decode_words(&["hrose"], Lang::En), Err(Error::UnknownWord(0)));
+/// ```
+#[inline(always)]
+pub fn decode_words<S: AsRef<str>>(words: &[S], lang: Lang) -> Result<Vec<u8>, Error> {
+    codec::decode(words, lang)
+}
+
+/// Estimates how many guesses an attacker needs to find `input`, in the
+/// style of zxcvbn.
+///
+/// The input is split into the cheapest sequence of dictionary words from
+/// every enabled language, repeated characters, sequences, years, reused
+/// separators and individually guessed characters. Only the first 128
+/// characters are considered.
+///
+/// # Example
+/// ```
+/// let weak = random_word::estimate("aaaaaa123");
+/// let strong = random_word::estimate("correct-horse-battery-staple");
+/// assert!(weak.bits < strong.bits);
+/// assert_eq!(strong.matches.len(), 7);
+/// ```
+#[inline(always)]
+pub fn estimate(input: &str) -> Estimate {
+    estimate::estimate(input)
+}
+
+/// Decompresses, verifies and indexes the words of the given language up
+/// front, so the first lookup doesn't pay for it.
+///
+/// Returns [`Error::CorruptDictionary`] if the embedded data doesn't match
+/// its SHA-256 hash.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// random_word::preload(Lang::En).unwrap();
+/// ```
+#[inline(always)]
+pub fn preload(lang: Lang) -> Result<(), Error> {
+    preload::preload(lang, Indexes::ALL)
+}
+
+/// Like [`preload`], but builds only the selected indexes.
+///
+/// # Example
+/// ```
+/// use random_word::{Indexes, Lang};
+/// random_word::preload_with(Lang::En, Indexes::NONE).unwrap();
+/// ```
+#[inline(always)]
+pub fn preload_with(lang: Lang, indexes: Indexes) -> Result<(), Error> {
+    preload::preload(lang, indexes)
+}
+
+/// Calls [`preload`] for every enabled language, stopping at the first
+/// error.
+///
+/// With the `rayon` feature, the languages are loaded in parallel on the
+/// global rayon pool, and the error returned is that of any failing
+/// language rather than the first in [`Lang::ALL`].
+///
+/// # Example
+/// ```
+/// random_word::preload_all().unwrap();
+/// ```
+pub fn preload_all() -> Result<(), Error> {
+    #[cfg(feature = "rayon")]
+    use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+    #[cfg(feature = "rayon")]
+    return Lang::ALL.par_iter().try_for_each(|&lang| preload(lang));
+    #[cfg(not(feature = "rayon"))]
+    Lang::ALL.iter().try_for_each(|&lang| preload(lang))
+}
+
+/// Drops the lookup indexes of the given language that are built on demand
+/// and held only internally, returning whether any was built. They are
+/// rebuilt if needed again, so long-running processes can call this after a
+/// burst of lookups to return the memory.
+///
+/// This releases the indexes behind [`suggest`], [`all_containing`],
+/// [`all_from_letters`], [`is_transcription_safe`], [`rhymes_with`],
+/// [`sounds_like`], [`encode_bytes`] and [`decode_words`]. Calls running on
+/// other threads keep their copy until they return.
+///
+/// The word list itself and the indexes built by [`preload`] stay: every
+/// `&'static str` this crate returns points into them. [`Lang::word_count`],
+/// [`Lang::lengths`] and [`Lang::initials`] are read from the precomputed
+/// index alone and never decompress the word list. Under the `clean`
+/// feature they count the words left, which loads the list, unless the
+/// `lean` feature is enabled too: the list is then decompressed to count
+/// them and dropped again, and only kept once words are asked for.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// random_word::suggest("helo", Lang::En, 1);
+/// assert!(random_word::release_indexes(Lang::En));
+/// assert!(!random_word::release_indexes(Lang::En));
+/// assert!(random_word::suggest("helo", Lang::En, 1).unwrap().contains(&"hello"));
+/// ```
+#[inline(always)]
+pub fn release_indexes(lang: Lang) -> bool {
+    words::release(lang)
+}
+
+/// Returns the compressed word data embedded for the given language.
+///
+/// The bytes are a single brotli stream (default encoder parameters:
+/// quality 11, window 22), or with the `zstd` feature a single zstd frame
+/// (level 19). Decompressed, it holds the entries of [`all`] in the same
+/// byte-wise order, front-coded: each entry is a byte counting the leading
+/// bytes it shares with the previous entry, at most 255, then the rest of
+/// the entry in UTF-8 and a `\n`. Japanese entries may carry a kana reading
+/// as `word [reading]`, and Chinese entries are `traditional simplified`
+/// pairs separated by a space.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let bytes = random_word::raw_compressed(Lang::En);
+/// assert!(!bytes.is_empty());
+/// ```
+#[inline(always)]
+pub fn raw_compressed(lang: Lang) -> &'static [u8] {
+    words::raw(lang)
+}
+
+/// Returns the expected SHA-256 of the word list for the given language, as
+/// a lowercase hex string. The hash covers the entries of [`all`] in order,
+/// each followed by a `\n`, so it doesn't depend on how they are stored.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let hash = random_word::sha256(Lang::En);
+/// assert_eq!(hash.len(), 64);
+/// ```
+#[inline(always)]
+pub fn sha256(lang: Lang) -> &'static str {
+    words::sha256(lang)
+}
+
+/// Decompresses and expands the embedded word data for the given language
+/// and checks its SHA-256 against the expected hash, and its precomputed
+/// index against the words.
+///
+/// The data is decompressed afresh rather than read from the words in use,
+/// so corrupt data that fails to decompress or parse makes this return
+/// `false` instead of panicking.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert!(random_word::verify(Lang::En));
+/// ```
+pub fn verify(lang: Lang) -> bool {
+    check_data(lang, words::raw(lang), words::raw_index(lang)).is_ok()
+}
+
+/// Checks the compressed words `raw` and index `raw_index` against the
+/// expected hash of the given language, returning
+/// [`Error::CorruptDictionary`] if they don't match or don't decompress.
+fn check_data(lang: Lang, raw: &[u8], raw_index: &[u8]) -> Result<(), Error> {
+    match words::check(raw, raw_index, words::sha256(lang)) {
+        Ok(true) => Ok(()),
+        _ => Err(Error::CorruptDictionary(lang)),
+    }
+}
+
+/// Checks the embedded word data of the given language like [`try_verify`]
+/// unless its words are already loaded, so that the `try_` functions report
+/// corrupt data rather than panic while loading it.
+fn try_load(lang: Lang) -> Result<(), Error> {
+    if words::is_loaded(lang) {
+        return Ok(());
+    }
+    check_data(lang, words::raw(lang), words::raw_index(lang))
+}
+
+/// Checks the embedded word data for the given language like [`verify`],
+/// returning [`Error::CorruptDictionary`] on a mismatch.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// assert_eq!(random_word::try_verify(Lang::En), Ok(()));
+/// ```
+pub fn try_verify(lang: Lang) -> Result<(), Error> {
+    check_data(lang, words::raw(lang), words::raw_index(lang))
+}
+
+/// Returns a random word with the given language, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// Like every `try_` function, this checks the data with [`try_verify`]
+/// before the words of the language are first loaded, which would panic on
+/// corrupt data, so the first call also pays for the check.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::try_get(Lang::En).unwrap();
+/// assert!(!word.is_empty());
+/// ```
+#[inline(always)]
+pub fn try_get(lang: Lang) -> Result<&'static str, Error> {
+    try_load(lang)?;
+    words::get(lang)
+        .choose(&mut default_rng())
+        .copied()
+        .ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns all words with the given length and language,
+/// [`Error::NoWordsForFilter`] if there are none, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::{Error, Lang};
+/// assert!(random_word::try_all_len(5, Lang::En).is_ok());
+/// assert_eq!(random_word::try_all_len(500, Lang::En), Err(Error::NoWordsForFilter));
+/// ```
+#[inline(always)]
+pub fn try_all_len(len: usize, lang: Lang) -> Result<&'static [&'static str], Error> {
+    try_load(lang)?;
+    all_len(len, lang).ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns a random word with the given length and language,
+/// [`Error::NoWordsForFilter`] if there are none, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::try_get_len(5, Lang::En).unwrap();
+/// assert_eq!(word.len(), 5);
+/// ```
+#[inline(always)]
+pub fn try_get_len(len: usize, lang: Lang) -> Result<&'static str, Error> {
+    try_load(lang)?;
+    get_len(len, lang).ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns all words with the given starting character and language,
+/// [`Error::NoWordsForFilter`] if there are none, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::try_all_starts_with('c', Lang::En).unwrap();
+/// assert!(words.iter().all(|w| w.starts_with('c')));
+/// ```
+#[inline(always)]
+pub fn try_all_starts_with(char: char, lang: Lang) -> Result<&'static [&'static str], Error> {
+    try_load(lang)?;
+    all_starts_with(char, lang).ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns a random word with the given starting character and language,
+/// [`Error::NoWordsForFilter`] if there are none, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::{Error, Lang};
+/// assert!(random_word::try_get_starts_with('c', Lang::En).is_ok());
+/// assert_eq!(random_word::try_get_starts_with('1', Lang::En), Err(Error::NoWordsForFilter));
+/// ```
+#[inline(always)]
+pub fn try_get_starts_with(char: char, lang: Lang) -> Result<&'static str, Error> {
+    try_load(lang)?;
+    get_starts_with(char, lang).ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns all words with the given ending character and language,
+/// [`Error::NoWordsForFilter`] if there are none, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let words = random_word::try_all_ends_with('t', Lang::En).unwrap();
+/// assert!(words.iter().all(|w| w.ends_with('t')));
+/// ```
+#[inline(always)]
+pub fn try_all_ends_with(char: char, lang: Lang) -> Result<&'static [&'static str], Error> {
+    try_load(lang)?;
+    all_ends_with(char, lang).ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns a random word with the given ending character and language,
+/// [`Error::NoWordsForFilter`] if there are none, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::Lang;
+/// let word = random_word::try_get_ends_with('t', Lang::En).unwrap();
+/// assert!(word.ends_with('t'));
+/// ```
+#[inline(always)]
+pub fn try_get_ends_with(char: char, lang: Lang) -> Result<&'static str, Error> {
+    try_load(lang)?;
+    get_ends_with(char, lang).ok_or(Error::NoWordsForFilter)
+}
+
+/// Returns a random word with the given language satisfying `predicate`,
+/// [`Error::NoWordsForFilter`] if no word does, or
+/// [`Error::CorruptDictionary`] if the embedded data is corrupt.
+///
+/// # Example
+/// ```
+/// use random_word::{Error, Lang};
+/// assert!(random_word::try_get_where(Lang::En, |w| w.contains("qu")).is_ok());
+/// assert_eq!(random_word::try_get_where(Lang::En, |w| w.is_empty()), Err(Error::NoWordsForFilter));
+/// ```
+#[inline(always)]
+pub fn try_get_where(lang: Lang, predicate: impl Fn(&str) -> bool) -> Result<&'static str, Error> {
+    try_load(lang)?;
+    get_where(lang, predicate).ok_or(Error::NoWordsForFilter)
+}
@@ -0,0 +1,292 @@
+use crate::Dictionary;
+use std::borrow::Cow;
+use std::io;
+use std::path::Path;
+use std::{fmt, fs};
+
+/// The error type of [`Dictionary::from_path`], `Dictionary::from_url` and
+/// `Pack::open`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The file is named or looks like a brotli- or zstd-compressed list but
+    /// doesn't decompress.
+    Decompress(io::Error),
+    /// The line with the given number, counting from 1, isn't valid UTF-8.
+    InvalidUtf8(usize),
+    /// The line with the given number, counting from 1, doesn't fit the
+    /// detected format, such as a CSV row with an unterminated quote or too
+    /// few columns, or has a word with control characters.
+    MalformedLine(usize),
+    /// The CSV data has no header named `word` and no column of words.
+    NoWordColumn,
+    /// The list has no words.
+    Empty,
+    /// The file isn't a pack this version of the crate reads, or one of its
+    /// lists isn't valid UTF-8 (feature = "mmap").
+    #[cfg(feature = "mmap")]
+    InvalidPack,
+    /// The request failed or the server answered with an error status
+    /// (feature = "http").
+    #[cfg(feature = "http")]
+    Http(Box<dyn std::error::Error + Send + Sync>),
+    /// The response is larger than the given limit in bytes
+    /// (feature = "http").
+    #[cfg(feature = "http")]
+    TooLarge(u64),
+    /// The SHA-256 of the response doesn't match the expected hash
+    /// (feature = "http").
+    #[cfg(feature = "http")]
+    ChecksumMismatch,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "failed to read the word list: {}", err),
+            LoadError::Decompress(err) => write!(f, "failed to decompress the word list: {}", err),
+            LoadError::InvalidUtf8(line) => write!(f, "line {} is not valid UTF-8", line),
+            LoadError::MalformedLine(line) => write!(f, "line {} is malformed", line),
+            LoadError::NoWordColumn => write!(f, "the CSV data has no word column"),
+            LoadError::Empty => write!(f, "the word list has no words"),
+            #[cfg(feature = "mmap")]
+            LoadError::InvalidPack => write!(f, "the file is not a valid word pack"),
+            #[cfg(feature = "http")]
+            LoadError::Http(err) => write!(f, "failed to download the word list: {}", err),
+            #[cfg(feature = "http")]
+            LoadError::TooLarge(max) => write!(f, "the word list is larger than {} bytes", max),
+            #[cfg(feature = "http")]
+            LoadError::ChecksumMismatch => write!(f, "the word list does not match its SHA-256"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(err) | LoadError::Decompress(err) => Some(err),
+            #[cfg(feature = "http")]
+            LoadError::Http(err) => Some(&**err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(err: io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+/// The layouts of word list files.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+    /// One word per line.
+    Plain,
+    /// A dice roll and a word per line, as in diceware lists.
+    Diceware,
+    /// Comma-separated values with a word column.
+    Csv,
+}
+
+impl Dictionary {
+    /// Reads a dictionary from a word list file, detecting its format:
+    ///
+    /// * one word per line, like [`from_file`](Self::from_file);
+    /// * diceware lists, with a dice roll such as `11111` before each word;
+    /// * CSV with a header naming a `word` column, or else whose first
+    ///   non-numeric column holds the words;
+    /// * any of these compressed with brotli, detected from a `.br`
+    ///   extension or from data that isn't UTF-8, or with the `zstd` feature
+    ///   compressed with zstd, detected from a `.zst` extension or the zstd
+    ///   magic number.
+    ///
+    /// Surrounding whitespace is trimmed, empty lines and lines starting
+    /// with `#` are skipped, and repeated words are kept once.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Dictionary;
+    /// let path = std::env::temp_dir().join("random_word_diceware_example.txt");
+    /// std::fs::write(&path, "11111\tabacus\n11112\tabdomen\n").unwrap();
+    /// let dictionary = Dictionary::from_path(&path).unwrap();
+    /// assert_eq!(dictionary.all(), ["abacus", "abdomen"]);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+        parse(fs::read(path)?, &name)
+    }
+}
+
+/// Parses a word list, using the file `name` as a hint for its compression
+/// and format.
+pub(crate) fn parse(bytes: Vec<u8>, name: &str) -> Result<Dictionary, LoadError> {
+    let mut name = name.to_ascii_lowercase();
+    #[cfg(feature = "zstd")]
+    if name.ends_with(".zst") || bytes.starts_with(&ZSTD_MAGIC) {
+        let bytes = zstd::stream::decode_all(bytes.as_slice()).map_err(LoadError::Decompress)?;
+        return parse_text(bytes, name.strip_suffix(".zst").unwrap_or(&name));
+    }
+    let compressed = name.ends_with(".br");
+    if compressed {
+        name.truncate(name.len() - 3);
+    }
+    let bytes = match std::str::from_utf8(&bytes) {
+        Ok(_) if !compressed => bytes,
+        Ok(_) => decompress(&bytes)?,
+        // Brotli streams have no signature, so text that isn't UTF-8 is
+        // tried as one before reporting where the UTF-8 breaks.
+        Err(err) => decompress(&bytes).map_err(|_| {
+            LoadError::InvalidUtf8(bytes[..err.valid_up_to()].iter().filter(|&&b| b == b'\n').count() + 1)
+        })?,
+    };
+    parse_text(bytes, &name)
+}
+
+/// Parses a decompressed word list.
+fn parse_text(bytes: Vec<u8>, name: &str) -> Result<Dictionary, LoadError> {
+    let text = String::from_utf8(bytes).map_err(|err| {
+        let valid = &err.as_bytes()[..err.utf8_error().valid_up_to()];
+        LoadError::InvalidUtf8(valid.iter().filter(|&&b| b == b'\n').count() + 1)
+    })?;
+
+    let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .peekable();
+    let format = match lines.peek() {
+        _ if name.ends_with(".csv") => Format::Csv,
+        Some((_, line)) if line.contains(',') => Format::Csv,
+        Some((_, line)) if diceware_word(line).is_some() => Format::Diceware,
+        _ => Format::Plain,
+    };
+
+    let mut words: Vec<(usize, Cow<str>)> = Vec::new();
+    match format {
+        Format::Plain => words.extend(lines.map(|(number, line)| (number, Cow::Borrowed(line)))),
+        Format::Diceware => {
+            for (number, line) in lines {
+                let word = diceware_word(line).ok_or(LoadError::MalformedLine(number))?;
+                words.push((number, Cow::Borrowed(word)));
+            }
+        }
+        Format::Csv => {
+            let mut column = None;
+            for (number, line) in lines {
+                let fields = csv_fields(line).ok_or(LoadError::MalformedLine(number))?;
+                let index = match column {
+                    Some(index) => index,
+                    None => {
+                        let header = fields.iter().position(|field| {
+                            field.eq_ignore_ascii_case("word") || field.eq_ignore_ascii_case("words")
+                        });
+                        let index = header
+                            .or_else(|| fields.iter().position(|field| !is_number(field)))
+                            .ok_or(LoadError::NoWordColumn)?;
+                        column = Some(index);
+                        if header.is_some() {
+                            continue;
+                        }
+                        index
+                    }
+                };
+                let mut fields = fields;
+                if index >= fields.len() {
+                    return Err(LoadError::MalformedLine(number));
+                }
+                words.push((number, fields.swap_remove(index)));
+            }
+        }
+    }
+
+    if let Some((number, _)) = words.iter().find(|(_, word)| word.contains(char::is_control)) {
+        return Err(LoadError::MalformedLine(*number));
+    }
+    let dictionary = Dictionary::new(words.iter().map(|(_, word)| word.trim()));
+    if dictionary.is_empty() {
+        return Err(LoadError::Empty);
+    }
+    Ok(dictionary)
+}
+
+/// The first bytes of every zstd frame.
+#[cfg(feature = "zstd")]
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+#[cfg(feature = "brotli")]
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, LoadError> {
+    use std::io::Read;
+    let mut decompressed = Vec::new();
+    brotli::Decompressor::new(bytes, crate::words::DECODE_BUFFER_SIZE)
+        .read_to_end(&mut decompressed)
+        .map_err(LoadError::Decompress)?;
+    Ok(decompressed)
+}
+
+/// Without the `brotli` feature, brotli lists can't be read.
+#[cfg(not(feature = "brotli"))]
+fn decompress(_: &[u8]) -> Result<Vec<u8>, LoadError> {
+    Err(LoadError::Decompress(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "brotli support requires the `brotli` feature",
+    )))
+}
+
+fn is_number(field: &str) -> bool {
+    !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Returns the word of a diceware line such as `11111 abacus`.
+fn diceware_word(line: &str) -> Option<&str> {
+    let (roll, word) = line.split_once(char::is_whitespace)?;
+    (is_number(roll) && !word.trim().is_empty()).then(|| word.trim())
+}
+
+/// Splits a CSV line into its fields, unquoting quoted ones, or returns
+/// `None` if a quote is unterminated.
+fn csv_fields(line: &str) -> Option<Vec<Cow<'_, str>>> {
+    let mut fields = Vec::new();
+    let mut rest = line;
+    loop {
+        let trimmed = rest.trim_start();
+        if let Some(quoted) = trimmed.strip_prefix('"') {
+            let mut field = String::new();
+            let mut chars = quoted.char_indices();
+            let end = loop {
+                match chars.next()? {
+                    (i, '"') if quoted[i + 1..].starts_with('"') => {
+                        field.push('"');
+                        chars.next();
+                    }
+                    (i, '"') => break i + 1,
+                    (_, c) => field.push(c),
+                }
+            };
+            fields.push(Cow::Owned(field));
+            let after = quoted[end..].trim_start();
+            match after.strip_prefix(',') {
+                Some(next) => rest = next,
+                None if after.is_empty() => return Some(fields),
+                None => return None,
+            }
+        } else {
+            match rest.split_once(',') {
+                Some((field, next)) => {
+                    fields.push(Cow::Borrowed(field.trim()));
+                    rest = next;
+                }
+                None => {
+                    fields.push(Cow::Borrowed(rest.trim()));
+                    return Some(fields);
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,23 @@
-use random_word::Lang;
+use random_word::{Blocklist, Lang, Passphrase, PassphraseOptions};
 use std::env;
 
 fn print_usage(program: &str) {
     eprintln!("Usage: {} [OPTIONS] [num_words] [separator]", program);
+    eprintln!("       {} verify", program);
+    eprintln!("       {} estimate [passphrase]", program);
+    #[cfg(feature = "mmap")]
+    eprintln!("       {} pack <output> [lang...]", program);
     eprintln!("\nOptions:");
     eprintln!("  -s, --stats    Show password strength statistics");
+    eprintln!("  -r, --repeats  Allow the same word to appear more than once");
+    eprintln!("  -b, --blocklist <file>");
+    eprintln!("                 Never use the words listed in the file, one per line");
     eprintln!("  -h, --help     Show this help message");
+    eprintln!("\nCommands:");
+    eprintln!("  verify         Check embedded wordlists against their SHA-256 hashes");
+    eprintln!("  estimate       Estimate the strength of a passphrase (read from stdin if omitted)");
+    #[cfg(feature = "mmap")]
+    eprintln!("  pack           Write the given languages, or all enabled ones, to a pack file for Pack::open");
     eprintln!("\nArguments:");
     eprintln!("  num_words      Number of words to generate (default: 4)");
     eprintln!("  separator      Character to separate words (default: -)");
@@ -15,10 +27,73 @@ fn print_usage(program: &str) {
     eprintln!("  {} -s 6         # Generate 6 words and show stats", program);
 }
 
-fn calculate_entropy(num_words: usize, dictionary_size: usize) -> f64 {
-    // Entropy = log2(combinations) = log2(dictionary_size^num_words)
-    // = num_words * log2(dictionary_size)
-    (num_words as f64) * (dictionary_size as f64).log2()
+fn run_verify() -> bool {
+    let mut all_ok = true;
+    for &lang in Lang::ALL {
+        if random_word::verify(lang) {
+            println!("{}: {}ok{} ({})", lang, GREEN, RESET, lang.dataset_version());
+        } else {
+            println!("{}: {}FAILED{} (expected sha256 {})", lang, RED, RESET, random_word::sha256(lang));
+            all_ok = false;
+        }
+    }
+    all_ok
+}
+
+#[cfg(feature = "mmap")]
+fn run_pack(output: &str, codes: &[String]) -> Result<(), String> {
+    let langs = if codes.is_empty() {
+        Lang::ALL.to_vec()
+    } else {
+        codes.iter().map(|code| code.parse::<Lang>().map_err(|err| err.to_string())).collect::<Result<_, _>>()?
+    };
+    let dictionaries: Vec<_> = langs.iter().map(|lang| lang.to_dictionary()).collect();
+    let file = std::fs::File::create(output).map_err(|err| format!("{}: {}", output, err))?;
+    random_word::Pack::write(
+        std::io::BufWriter::new(file),
+        langs.iter().map(|lang| lang.code()).zip(&dictionaries),
+    )
+    .map_err(|err| format!("{}: {}", output, err))?;
+    for (lang, dictionary) in langs.iter().zip(&dictionaries) {
+        println!("{}: {} words", lang.code(), dictionary.len());
+    }
+    Ok(())
+}
+
+fn run_estimate(passphrase: Option<&str>) {
+    let input = match passphrase {
+        Some(passphrase) => passphrase.to_string(),
+        None => {
+            let mut line = String::new();
+            if std::io::stdin().read_line(&mut line).is_err() {
+                eprintln!("Error: could not read passphrase from stdin");
+                std::process::exit(1);
+            }
+            // Trim in place, so the line isn't copied.
+            let len = line.trim_end_matches(['\r', '\n']).len();
+            line.truncate(len);
+            line
+        }
+    };
+    #[cfg(feature = "zeroize")]
+    let input = zeroize::Zeroizing::new(input);
+    let estimate = random_word::estimate(&input);
+    let (strength, strength_color) = get_strength_rating(estimate.bits);
+
+    println!("{}━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━{}", BOLD, RESET);
+    println!("{}Passphrase Strength Estimate{}", BOLD, RESET);
+    println!("{}━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━{}", BOLD, RESET);
+    for m in &estimate.matches {
+        println!("  {:<20} {}{:?}{} ({:.1} bits)", m.token, DIM, m.kind, RESET, m.bits);
+    }
+    println!("Guesses:             {}~2^{:.1}{}", BOLD, estimate.bits, RESET);
+    println!("Score (0-4):         {}{}{}", BOLD, estimate.score(), RESET);
+    println!("Offline crack time:  {}{:.2e} s{} at 10^10 guesses/s", BOLD, estimate.crack_seconds(1e10), RESET);
+    println!("Strength rating:     {}{}{}{}", BOLD, strength_color, strength, RESET);
+    #[cfg(feature = "breach")]
+    if random_word::breach::is_breached(&input) {
+        println!("{}Warning: this passphrase appears in lists of leaked passwords{}", RED, RESET);
+    }
 }
 
 // ANSI color codes
@@ -46,7 +121,7 @@ fn get_strength_rating(entropy: f64) -> (&'static str, &'static str) {
 }
 
 fn print_stats(num_words: usize, dictionary_size: usize, entropy: f64, password_len: usize) {
-    let combinations = format!("~{:.2e}", (dictionary_size as f64).powi(num_words as i32));
+    let combinations = format!("~{:.2e}", entropy.exp2());
     let (strength, strength_color) = get_strength_rating(entropy);
 
     eprintln!("\n{}━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━{}", BOLD, RESET);
@@ -71,13 +146,52 @@ fn print_stats(num_words: usize, dictionary_size: usize, entropy: f64, password_
 fn main() {
     let args: Vec<String> = env::args().collect();
 
+    if args.get(1).map(String::as_str) == Some("estimate") {
+        run_estimate(args.get(2).map(String::as_str));
+        return;
+    }
+
+    #[cfg(feature = "mmap")]
+    if args.get(1).map(String::as_str) == Some("pack") {
+        let Some(output) = args.get(2) else {
+            print_usage(&args[0]);
+            std::process::exit(1);
+        };
+        if let Err(err) = run_pack(output, &args[3..]) {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.get(1).map(String::as_str) == Some("verify") {
+        if !run_verify() {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Parse flags
     let mut show_stats = false;
+    let mut allow_repeats = false;
+    let mut blocklist = Blocklist::default();
     let mut positional_args = Vec::new();
 
-    for arg in args.iter().skip(1) {
+    let mut flags = args.iter().skip(1);
+    while let Some(arg) = flags.next() {
         match arg.as_str() {
             "-s" | "--stats" => show_stats = true,
+            "-r" | "--repeats" => allow_repeats = true,
+            "-b" | "--blocklist" => {
+                let Some(path) = flags.next() else {
+                    print_usage(&args[0]);
+                    std::process::exit(1);
+                };
+                blocklist = Blocklist::from_file(path).unwrap_or_else(|err| {
+                    eprintln!("Error: {}: {}", path, err);
+                    std::process::exit(1);
+                });
+            }
             "-h" | "--help" => {
                 print_usage(&args[0]);
                 return;
@@ -108,23 +222,43 @@ fn main() {
         std::process::exit(1);
     }
 
-    // Get dictionary size
-    let dictionary_size = random_word::all(Lang::En).len();
-
-    // Generate random words
-    let words: Vec<&str> = (0..num_words)
-        .map(|_| random_word::get(Lang::En))
-        .collect();
+    // Get dictionary size, counting each distinct word the blocklist allows
+    let dictionary_size = blocklist.apply(&Lang::En).len();
+    if dictionary_size == 0 {
+        eprintln!("Error: the blocklist bans every word");
+        std::process::exit(1);
+    }
+    if !allow_repeats && num_words > dictionary_size {
+        eprintln!(
+            "Error: only {} distinct words are available; pass -r to allow repeated words",
+            dictionary_size
+        );
+        std::process::exit(1);
+    }
 
-    // Join them with the separator
-    let password = words.join(separator);
+    // Generate the passphrase
+    let passphrase = Passphrase::generate(PassphraseOptions {
+        words: num_words,
+        lang: Lang::En,
+        separator: separator.to_string(),
+        allow_repeats,
+        blocklist,
+        ..Default::default()
+    });
+    #[cfg(feature = "zeroize")]
+    let password = passphrase.to_zeroizing();
+    #[cfg(not(feature = "zeroize"))]
+    let password = passphrase.to_string();
 
     // Print the password
-    println!("{}", password);
+    println!("{}", password.as_str());
+    #[cfg(feature = "breach")]
+    if random_word::breach::is_breached(&password) {
+        eprintln!("{}Warning: this passphrase appears in lists of leaked passwords{}", RED, RESET);
+    }
 
     // Show statistics if requested
     if show_stats {
-        let entropy = calculate_entropy(num_words, dictionary_size);
-        print_stats(num_words, dictionary_size, entropy, password.len());
+        print_stats(num_words, dictionary_size, passphrase.entropy_bits(), password.len());
     }
 }
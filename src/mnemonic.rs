@@ -0,0 +1,147 @@
+//! BIP-39 mnemonic encoding of binary entropy as words.
+//!
+//! Each wordlist is behind its own feature, such as `bip39-en`. Words are
+//! kept in the standard order, which differs from the sorted order of the
+//! [`Lang`](crate::Lang) word lists, and in Unicode NFKD form as the
+//! standard requires.
+//!
+//! # Example
+//! ```
+//! use random_word::mnemonic::{self, Wordlist};
+//! let entropy = [0u8; 16];
+//! let words = mnemonic::encode(&entropy, Wordlist::English).unwrap();
+//! assert_eq!(words.len(), 12);
+//! assert_eq!(words[11], "about");
+//! assert_eq!(mnemonic::decode(&words, Wordlist::English).unwrap(), entropy);
+//! ```
+
+use crate::Error;
+use crate::words::{Words, decompress};
+use ahash::AHashMap;
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+
+macro_rules! bip39_wordlists {
+    ($($feat:literal => $file_stem:ident : $Variant:ident : $name:literal),* $(,)?) => {
+        /// A standard BIP-39 wordlist of 2048 words.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub enum Wordlist {
+            $(
+                #[cfg(feature = $feat)]
+                #[doc = concat!("The ", $name, " wordlist (feature = \"", $feat, "\")")]
+                $Variant,
+            )*
+        }
+
+        $(
+            #[cfg(feature = $feat)]
+            paste::paste! {
+                static [<$file_stem:upper _RAW>]: &[u8] = include_compressed!(concat!("bip39/", stringify!($file_stem)));
+                static [<$file_stem:upper _TEXT>]: OnceLock<String> = OnceLock::new();
+                static [<$file_stem:upper>]: OnceLock<Words> = OnceLock::new();
+                static [<$file_stem:upper _INDEX>]: OnceLock<AHashMap<&'static str, u16>> = OnceLock::new();
+            }
+        )*
+
+        /// Returns the words of `list` in standard order.
+        pub fn words(list: Wordlist) -> &'static [&'static str] {
+            match list {
+                $(
+                    #[cfg(feature = $feat)]
+                    Wordlist::$Variant => paste::paste! {
+                        [<$file_stem:upper>].get_or_init(|| {
+                            [<$file_stem:upper _TEXT>].get_or_init(|| decompress([<$file_stem:upper _RAW>])).lines().collect()
+                        })
+                    },
+                )*
+            }
+        }
+
+        fn index(list: Wordlist) -> &'static AHashMap<&'static str, u16> {
+            match list {
+                $(
+                    #[cfg(feature = $feat)]
+                    Wordlist::$Variant => paste::paste! {
+                        [<$file_stem:upper _INDEX>].get_or_init(|| {
+                            words(list).iter().enumerate().map(|(i, &word)| (word, i as u16)).collect()
+                        })
+                    },
+                )*
+            }
+        }
+    };
+}
+
+bip39_wordlists! {
+    "bip39-en" => en : English : "English",
+    "bip39-es" => es : Spanish : "Spanish",
+    "bip39-fr" => fr : French : "French",
+    "bip39-it" => it : Italian : "Italian",
+    "bip39-pt" => pt : Portuguese : "Portuguese",
+    "bip39-cs" => cs : Czech : "Czech",
+    "bip39-ja" => ja : Japanese : "Japanese",
+    "bip39-ko" => ko : Korean : "Korean",
+    "bip39-zh-hans" => zh_hans : ChineseSimplified : "simplified Chinese",
+    "bip39-zh-hant" => zh_hant : ChineseTraditional : "traditional Chinese",
+}
+
+/// Encodes `entropy` as a mnemonic: 12, 15, 18, 21 or 24 words for 16, 20,
+/// 24, 28 or 32 bytes.
+///
+/// Returns [`Error::InvalidEntropyLength`] for any other length.
+pub fn encode(entropy: &[u8], list: Wordlist) -> Result<Vec<&'static str>, Error> {
+    if !(16..=32).contains(&entropy.len()) || !entropy.len().is_multiple_of(4) {
+        return Err(Error::InvalidEntropyLength(entropy.len()));
+    }
+    let checksum = Sha256::digest(entropy)[0];
+    let checksum_bits = entropy.len() / 4;
+    let words = words(list);
+    let bit = |i: usize| -> u16 {
+        let byte = entropy.get(i / 8).copied().unwrap_or(checksum);
+        u16::from(byte >> (7 - i % 8) & 1)
+    };
+    let total_bits = entropy.len() * 8 + checksum_bits;
+    Ok((0..total_bits / 11)
+        .map(|w| words[(0..11).fold(0, |index, b| index << 1 | bit(w * 11 + b)) as usize])
+        .collect())
+}
+
+/// Decodes a mnemonic back into its entropy, checking its checksum.
+///
+/// Words are compared after NFKD normalization, so accented words match
+/// whether they were typed composed or decomposed. Returns
+/// [`Error::InvalidWordCount`] unless there are 12, 15, 18, 21 or 24
+/// words, [`Error::UnknownWord`] with the position of a word not in
+/// `list`, and [`Error::InvalidChecksum`] if the checksum doesn't match.
+///
+/// # Example
+/// ```
+/// use random_word::Error;
+/// use random_word::mnemonic::{self, Wordlist};
+/// let mut words = vec!["abandon"; 12];
+/// assert_eq!(mnemonic::decode(&words, Wordlist::English), Err(Error::InvalidChecksum));
+/// words[11] = "about";
+/// assert_eq!(mnemonic::decode(&words, Wordlist::English), Ok(vec![0; 16]));
+/// ```
+pub fn decode(mnemonic: &[&str], list: Wordlist) -> Result<Vec<u8>, Error> {
+    if !(12..=24).contains(&mnemonic.len()) || !mnemonic.len().is_multiple_of(3) {
+        return Err(Error::InvalidWordCount(mnemonic.len()));
+    }
+    let index = index(list);
+    let mut bits = Vec::with_capacity(mnemonic.len() * 11);
+    for (position, word) in mnemonic.iter().enumerate() {
+        let normalized: String = word.nfkd().collect();
+        let &i = index.get(normalized.as_str()).ok_or(Error::UnknownWord(position))?;
+        bits.extend((0..11).rev().map(|b| (i >> b & 1) as u8));
+    }
+    let checksum_bits = mnemonic.len() / 3;
+    let (data, checksum) = bits.split_at(bits.len() - checksum_bits);
+    let entropy: Vec<u8> = data.chunks(8).map(|byte| byte.iter().fold(0, |acc, &b| acc << 1 | b)).collect();
+    let expected = Sha256::digest(&entropy)[0];
+    let valid = checksum
+        .iter()
+        .enumerate()
+        .all(|(i, &b)| expected >> (7 - i) & 1 == b);
+    if valid { Ok(entropy) } else { Err(Error::InvalidChecksum) }
+}
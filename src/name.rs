@@ -0,0 +1,140 @@
+//! Readable random names for servers, containers and other resources, in
+//! the style of Heroku and Docker, such as `brave-otter-7421`.
+//!
+//! Names are built from the part-of-speech lists, so only languages with
+//! part-of-speech data can be used.
+//!
+//! # Example
+//! ```
+//! use random_word::Lang;
+//! use random_word::name::{self, Generator, Style};
+//!
+//! let name = name::generate(Style::AdjNoun, Lang::En).unwrap();
+//! assert_eq!(name.split('-').count(), 2);
+//!
+//! let generator = Generator::new(Style::AdjNoun, Lang::En).unwrap().suffix_digits(4);
+//! let name = generator.generate();
+//! assert!(name.rsplit('-').next().unwrap().chars().all(|c| c.is_ascii_digit()));
+//! // The chance that two of a thousand names are the same.
+//! assert!(generator.collision_probability(1000) < 0.001);
+//! ```
+
+use crate::{Lang, Pos, default_rng, pos};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+/// The parts of speech a name is made of.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Style {
+    /// An adjective and a noun, such as `brave-otter`.
+    AdjNoun,
+    /// Two adjectives and a noun, such as `brave-quiet-otter`.
+    AdjAdjNoun,
+    /// An adverb, an adjective and a noun, such as `very-brave-otter`.
+    AdverbAdjNoun,
+    /// A verb and a noun, such as `chase-otter`.
+    VerbNoun,
+}
+
+impl Style {
+    /// Returns the parts of speech of the words of a name, in order.
+    pub fn parts(self) -> &'static [Pos] {
+        match self {
+            Style::AdjNoun => &[Pos::Adjective, Pos::Noun],
+            Style::AdjAdjNoun => &[Pos::Adjective, Pos::Adjective, Pos::Noun],
+            Style::AdverbAdjNoun => &[Pos::Adverb, Pos::Adjective, Pos::Noun],
+            Style::VerbNoun => &[Pos::Verb, Pos::Noun],
+        }
+    }
+}
+
+/// Generates names of one style, with an optional numeric suffix.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    parts: Vec<&'static [&'static str]>,
+    separator: String,
+    suffix_digits: u32,
+}
+
+impl Generator {
+    /// Creates a generator of hyphen-separated names without a suffix.
+    ///
+    /// Returns `None` if the language has no part-of-speech data.
+    pub fn new(style: Style, lang: Lang) -> Option<Self> {
+        let parts = style
+            .parts()
+            .iter()
+            .map(|&part| pos::get(part, lang))
+            .collect::<Option<_>>()?;
+        Some(Self {
+            parts,
+            separator: String::from("-"),
+            suffix_digits: 0,
+        })
+    }
+
+    /// Sets the string placed between words and before the suffix.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Appends a random number of exactly `digits` digits, such as `7421`
+    /// for 4. Leading zeros are kept.
+    pub fn suffix_digits(mut self, digits: u32) -> Self {
+        self.suffix_digits = digits;
+        self
+    }
+
+    /// Generates a name.
+    #[inline(always)]
+    pub fn generate(&self) -> String {
+        self.generate_rng(&mut default_rng())
+    }
+
+    /// Generates a name using the supplied RNG.
+    pub fn generate_rng(&self, rng: &mut impl Rng) -> String {
+        let mut name = String::new();
+        for (i, words) in self.parts.iter().enumerate() {
+            if i > 0 {
+                name.push_str(&self.separator);
+            }
+            name.push_str(words.choose(rng).expect("part-of-speech lists are not empty"));
+        }
+        if self.suffix_digits > 0 {
+            name.push_str(&self.separator);
+            name.extend((0..self.suffix_digits).map(|_| char::from(b'0' + rng.random_range(0..10))));
+        }
+        name
+    }
+
+    /// Returns the number of distinct names the generator can produce.
+    pub fn combinations(&self) -> f64 {
+        let words: f64 = self.parts.iter().map(|words| words.len() as f64).product();
+        words * 10f64.powi(self.suffix_digits as i32)
+    }
+
+    /// Returns the probability that at least two of `names` generated names
+    /// are the same, using the birthday approximation.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Lang;
+    /// use random_word::name::{Generator, Style};
+    /// let short = Generator::new(Style::AdjNoun, Lang::En).unwrap();
+    /// let long = short.clone().suffix_digits(4);
+    /// assert!(long.collision_probability(10_000) < short.collision_probability(10_000));
+    /// ```
+    pub fn collision_probability(&self, names: u64) -> f64 {
+        let names = names as f64;
+        -(-names * (names - 1.0) / (2.0 * self.combinations())).exp_m1()
+    }
+}
+
+/// Generates a hyphen-separated name of the given style, without a suffix.
+///
+/// Returns `None` if the language has no part-of-speech data.
+#[inline(always)]
+pub fn generate(style: Style, lang: Lang) -> Option<String> {
+    Some(Generator::new(style, lang)?.generate())
+}
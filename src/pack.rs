@@ -0,0 +1,141 @@
+use crate::{Dictionary, LoadError};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::Path;
+
+/// The first bytes of every pack file, ending in the format version.
+const MAGIC: &[u8; 8] = b"RWPACK\0\x01";
+
+/// Word lists read from a memory-mapped pack file, written by
+/// [`Pack::write`] or `passgen pack` (feature = "mmap").
+///
+/// A pack holds each list uncompressed, in the same layout the embedded
+/// languages decompress to, so the words are used in place: processes
+/// opening the same file share its pages through the page cache instead of
+/// each decompressing a copy. Only the slice of word references each
+/// [`Dictionary`] keeps is per process.
+///
+/// Like the text of [`Dictionary::new`], the mapping lives as long as the
+/// program, so that lookups return `&'static str`; open a pack once.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Pack};
+/// let path = std::env::temp_dir().join("random_word_pack_example.pack");
+/// Pack::write(std::fs::File::create(&path).unwrap(), [("en", &Lang::En.to_dictionary())]).unwrap();
+///
+/// // SAFETY: nothing modifies the file while the program runs.
+/// let pack = unsafe { Pack::open(&path) }.unwrap();
+/// let words = pack.dictionary("en").unwrap();
+/// assert_eq!(words.all(), random_word::all(Lang::En));
+/// ```
+pub struct Pack {
+    lists: Box<[(&'static str, &'static str)]>,
+}
+
+impl Pack {
+    /// Memory-maps a pack file and checks its layout.
+    ///
+    /// Returns [`LoadError::Io`] if the file can't be opened or mapped, and
+    /// [`LoadError::InvalidPack`] if it isn't a pack this version reads or
+    /// a list isn't valid UTF-8.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated for the rest of the
+    /// program, since the words returned point into it. Write a new file
+    /// and rename it over the old one to replace a pack.
+    pub unsafe fn open(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let file = File::open(path)?;
+        // SAFETY: upheld by the caller.
+        let map = unsafe { Mmap::map(&file) }?;
+        // Only a valid pack is kept for the rest of the program.
+        let spans = Self::parse(&map).ok_or(LoadError::InvalidPack)?;
+        let map: &'static Mmap = Box::leak(Box::new(map));
+        // SAFETY: `parse` checked that every span is in bounds and UTF-8.
+        let str_at = |span: Range<usize>| unsafe { std::str::from_utf8_unchecked(&map[span]) };
+        Ok(Self {
+            lists: spans.into_iter().map(|(code, text)| (str_at(code), str_at(text))).collect(),
+        })
+    }
+
+    /// Checks the layout of a pack, returning where the code and the text
+    /// of each list are.
+    fn parse(bytes: &[u8]) -> Option<Vec<(Range<usize>, Range<usize>)>> {
+        let mut rest = bytes.strip_prefix(MAGIC)?;
+        let count = u32::from_le_bytes(*take(&mut rest)?);
+        let mut spans = Vec::new();
+        for _ in 0..count {
+            let [len] = *take::<1>(&mut rest)?;
+            let start = bytes.len() - rest.len();
+            let code = start..start + usize::from(len);
+            std::str::from_utf8(rest.get(..usize::from(len))?).ok()?;
+            rest = &rest[usize::from(len)..];
+            let start = usize::try_from(u64::from_le_bytes(*take(&mut rest)?)).ok()?;
+            let len = usize::try_from(u64::from_le_bytes(*take(&mut rest)?)).ok()?;
+            let text = start..start.checked_add(len)?;
+            std::str::from_utf8(bytes.get(text.clone())?).ok()?;
+            spans.push((code, text));
+        }
+        Some(spans)
+    }
+
+    /// Returns the codes of the lists in the pack, in the order written.
+    pub fn codes(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.lists.iter().map(|&(code, _)| code)
+    }
+
+    /// Returns the list with the given code as a [`Dictionary`], or `None`
+    /// if the pack has no such list. Each call builds a new dictionary;
+    /// clone it rather than calling this again.
+    pub fn dictionary(&self, code: &str) -> Option<Dictionary> {
+        let &(_, text) = self.lists.iter().find(|&&(name, _)| name == code)?;
+        Some(Dictionary::from_words(text.split_terminator('\n')))
+    }
+
+    /// Writes a pack file holding the given dictionaries, each under a code
+    /// such as a [`Lang::code`](crate::Lang::code), to be read by
+    /// [`open`](Self::open).
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if a code is longer than 255 bytes or a word has a `\n`.
+    pub fn write<'a>(
+        mut out: impl Write,
+        lists: impl IntoIterator<Item = (&'a str, &'a Dictionary)>,
+    ) -> io::Result<()> {
+        let lists: Vec<(&str, &Dictionary)> = lists.into_iter().collect();
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, message.to_owned());
+        let mut header = Vec::from(*MAGIC);
+        header.extend((lists.len() as u32).to_le_bytes());
+        let header_len = header.len() + lists.iter().map(|(code, _)| 1 + code.len() + 16).sum::<usize>();
+
+        let mut offset = header_len as u64;
+        for &(code, dictionary) in &lists {
+            if dictionary.all().iter().any(|word| word.contains('\n')) {
+                return Err(invalid("a packed word has a line break"));
+            }
+            let len = dictionary.all().iter().map(|word| word.len() as u64 + 1).sum::<u64>();
+            header.push(u8::try_from(code.len()).map_err(|_| invalid("a pack code is longer than 255 bytes"))?);
+            header.extend_from_slice(code.as_bytes());
+            header.extend(offset.to_le_bytes());
+            header.extend(len.to_le_bytes());
+            offset += len;
+        }
+        out.write_all(&header)?;
+        for (_, dictionary) in lists {
+            for word in dictionary.all() {
+                out.write_all(word.as_bytes())?;
+                out.write_all(b"\n")?;
+            }
+        }
+        out.flush()
+    }
+}
+
+/// Splits `N` bytes off the front of `bytes`.
+fn take<'a, const N: usize>(bytes: &mut &'a [u8]) -> Option<&'a [u8; N]> {
+    let (value, rest) = bytes.split_first_chunk::<N>()?;
+    *bytes = rest;
+    Some(value)
+}
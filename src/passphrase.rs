@@ -0,0 +1,689 @@
+use crate::{
+    Blocklist, Capitalize, Error, Lang, Policy, REJECTION_ATTEMPTS, RequiredClass, Transform, WordQuery, all, all_len,
+    default_rng, distinct_entropy, pool_entropy, words,
+};
+use ahash::{AHashSet, RandomState};
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom, index};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The assembled text of a passphrase: wiped on drop with the `zeroize`
+/// feature.
+#[cfg(feature = "zeroize")]
+type Secret = zeroize::Zeroizing<String>;
+#[cfg(not(feature = "zeroize"))]
+type Secret = String;
+
+/// Settings for [`Passphrase::generate`].
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, PassphraseOptions};
+/// let options = PassphraseOptions { words: 6, lang: Lang::En, ..Default::default() };
+/// assert_eq!(options.separator, "-");
+/// ```
+#[derive(Clone, Debug)]
+pub struct PassphraseOptions {
+    /// The number of words.
+    pub words: usize,
+    /// The language the words are drawn from.
+    pub lang: Lang,
+    /// The string placed between words.
+    pub separator: String,
+    /// Transforms applied in order after the words are drawn.
+    pub transforms: Vec<Transform>,
+    /// Restricts words to those matching the query, whose source then
+    /// takes the place of `lang`. Passphrases from a
+    /// [`Dictionary`](crate::Dictionary) use `WordQuery::new(dictionary)`.
+    pub filter: Option<WordQuery>,
+    /// Whether a word may appear more than once. Off by default, which
+    /// slightly lowers the entropy of each further word.
+    pub allow_repeats: bool,
+    /// Words never drawn, on top of `filter`, such as brand names. Empty by
+    /// default.
+    pub blocklist: Blocklist,
+}
+
+impl PassphraseOptions {
+    /// Returns the words passphrases are drawn from, each once: some lists
+    /// repeat entries, which would otherwise be drawn twice as often and
+    /// could appear twice without `allow_repeats`.
+    fn pool(&self) -> Cow<'static, [&'static str]> {
+        let pool = match &self.filter {
+            Some(query) => Cow::Owned(query.all()),
+            None => {
+                let distinct = words::distinct(self.lang);
+                match all(self.lang) {
+                    words if words.len() == distinct.len() => Cow::Borrowed(words),
+                    _ => Cow::Owned(distinct.to_vec()),
+                }
+            }
+        };
+        if self.blocklist.is_empty() {
+            return pool;
+        }
+        Cow::Owned(pool.iter().copied().filter(|word| !self.blocklist.contains(word)).collect())
+    }
+
+    /// Returns the entropy in bits of drawing `self.words` words from a pool
+    /// of `pool_size` words: `words * log2(pool_size)` with repeats, and the
+    /// logarithm of the falling factorial without.
+    fn entropy(&self, pool_size: usize) -> f64 {
+        if self.allow_repeats {
+            self.words as f64 * pool_entropy(pool_size)
+        } else {
+            distinct_entropy(pool_size, self.words)
+        }
+    }
+
+    /// Returns the number of `char`s of a passphrase that aren't from its
+    /// words: the separators and the characters inserted by transforms.
+    fn fixed_len(&self) -> usize {
+        let random_separator = self.transforms.contains(&Transform::RandomSeparator);
+        let separator = if random_separator { 1 } else { self.separator.chars().count() };
+        let inserted = self
+            .transforms
+            .iter()
+            .filter(|t| matches!(t, Transform::InsertDigit | Transform::InsertSymbol))
+            .count();
+        let inserted = if self.words == 0 { 0 } else { inserted };
+        self.words.saturating_sub(1) * separator + inserted
+    }
+}
+
+impl Default for PassphraseOptions {
+    /// Four English words separated by hyphens, or words of the first
+    /// enabled language without the `en` feature.
+    fn default() -> Self {
+        #[cfg(feature = "en")]
+        let lang = Lang::En;
+        #[cfg(not(feature = "en"))]
+        let lang = Lang::ALL[0];
+        Self {
+            words: 4,
+            lang,
+            separator: String::from("-"),
+            transforms: Vec::new(),
+            filter: None,
+            allow_repeats: false,
+            blocklist: Blocklist::default(),
+        }
+    }
+}
+
+/// A passphrase of distinct words drawn uniformly at random, with optional
+/// [`Transform`]s applied.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Passphrase, PassphraseOptions};
+/// let passphrase = Passphrase::generate(PassphraseOptions {
+///     words: 4,
+///     lang: Lang::En,
+///     separator: " ".into(),
+///     ..Default::default()
+/// });
+/// assert_eq!(passphrase.words().len(), 4);
+/// assert_eq!(passphrase.to_string().split(' ').count(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Passphrase {
+    words: Vec<&'static str>,
+    rendered: Vec<String>,
+    separators: Vec<String>,
+    entropy_bits: f64,
+}
+
+impl Passphrase {
+    /// Generates a passphrase with the given options.
+    ///
+    /// # Panics
+    /// Panics if `options.filter` and `options.blocklist` leave no words, or
+    /// fewer than `options.words` distinct words without
+    /// `options.allow_repeats`.
+    #[inline(always)]
+    pub fn generate(options: PassphraseOptions) -> Self {
+        Self::generate_rng(options, &mut default_rng())
+    }
+
+    /// Generates a passphrase with the given options, using the supplied
+    /// RNG.
+    ///
+    /// # Panics
+    /// Panics if `options.filter` and `options.blocklist` leave no words, or
+    /// fewer than `options.words` distinct words without
+    /// `options.allow_repeats`.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::{SeedableRng, rngs::StdRng};
+    /// use random_word::{Passphrase, PassphraseOptions};
+    /// let a = Passphrase::generate_rng(PassphraseOptions::default(), &mut StdRng::seed_from_u64(42));
+    /// let b = Passphrase::generate_rng(PassphraseOptions::default(), &mut StdRng::seed_from_u64(42));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn generate_rng(options: PassphraseOptions, rng: &mut impl Rng) -> Self {
+        let pool = options.pool();
+        Self::draw(&options, &pool, rng)
+    }
+
+    /// Generates `count` passphrases with the same options, building the
+    /// word pool of `options.filter` once rather than for each passphrase.
+    /// Passphrases may repeat; see
+    /// [`generate_unique_batch`](Self::generate_unique_batch).
+    ///
+    /// # Panics
+    /// Panics like [`generate`](Self::generate).
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Passphrase, PassphraseOptions};
+    /// let batch = Passphrase::generate_batch(100, PassphraseOptions::default());
+    /// assert_eq!(batch.len(), 100);
+    /// ```
+    #[inline(always)]
+    pub fn generate_batch(count: usize, options: PassphraseOptions) -> Vec<Self> {
+        Self::generate_batch_rng(count, options, &mut default_rng())
+    }
+
+    /// Generates `count` passphrases like
+    /// [`generate_batch`](Self::generate_batch), using the supplied RNG.
+    pub fn generate_batch_rng(count: usize, options: PassphraseOptions, rng: &mut impl Rng) -> Vec<Self> {
+        let pool = options.pool();
+        (0..count).map(|_| Self::draw(&options, &pool, rng)).collect()
+    }
+
+    /// Generates `count` passphrases with the same options, no two of which
+    /// read the same. A repeat is drawn again, which leaves every passphrase
+    /// in the batch uniform over the ones not yet taken.
+    ///
+    /// Returns [`Error::TooFewPassphrases`] if the options can't produce
+    /// `count` distinct passphrases, or so few more that repeats keep being
+    /// drawn.
+    ///
+    /// # Panics
+    /// Panics like [`generate`](Self::generate).
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Error, Lang, Passphrase, PassphraseOptions, WordQuery};
+    /// let batch = Passphrase::generate_unique_batch(100, PassphraseOptions::default()).unwrap();
+    /// let mut texts: Vec<String> = batch.iter().map(|p| p.to_string()).collect();
+    /// texts.sort();
+    /// texts.dedup();
+    /// assert_eq!(texts.len(), 100);
+    ///
+    /// let options = PassphraseOptions {
+    ///     words: 1,
+    ///     filter: Some(WordQuery::new(Lang::En).len(2..=2)),
+    ///     ..Default::default()
+    /// };
+    /// let words = random_word::all_len(2, Lang::En).unwrap().len();
+    /// assert_eq!(Passphrase::generate_unique_batch(words + 1, options), Err(Error::TooFewPassphrases));
+    /// ```
+    #[inline(always)]
+    pub fn generate_unique_batch(count: usize, options: PassphraseOptions) -> Result<Vec<Self>, Error> {
+        Self::generate_unique_batch_rng(count, options, &mut default_rng())
+    }
+
+    /// Generates `count` distinct passphrases like
+    /// [`generate_unique_batch`](Self::generate_unique_batch), using the
+    /// supplied RNG.
+    pub fn generate_unique_batch_rng(
+        count: usize,
+        options: PassphraseOptions,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<Self>, Error> {
+        let pool = options.pool();
+        // Hashes rather than texts are kept, so no extra copies of the
+        // secrets are left behind. A collision only costs a redraw.
+        let hasher = RandomState::new();
+        let mut seen = AHashSet::with_capacity(count);
+        let mut batch = Vec::with_capacity(count);
+        while batch.len() < count {
+            let passphrase = (0..REJECTION_ATTEMPTS)
+                .map(|_| Self::draw(&options, &pool, rng))
+                .find(|passphrase| seen.insert(hasher.hash_one(passphrase.render().as_str())))
+                .ok_or(Error::TooFewPassphrases)?;
+            batch.push(passphrase);
+        }
+        Ok(batch)
+    }
+
+    /// Generates a passphrase whose text, separators and transforms
+    /// included, is `len` `char`s long, for systems that cap password
+    /// length. Word lengths are chosen from the length index so that every
+    /// passphrase of `options` that fits is equally likely, and
+    /// [`entropy_bits`](Self::entropy_bits) counts only those.
+    ///
+    /// Returns [`Error::NoWordsForFilter`] if no passphrase of
+    /// `options.words` words fits.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Passphrase, PassphraseOptions};
+    /// let passphrase = Passphrase::generate_within_len(16..=20, PassphraseOptions::default()).unwrap();
+    /// assert!((16..=20).contains(&passphrase.to_string().chars().count()));
+    /// assert_eq!(passphrase.words().len(), 4);
+    /// ```
+    #[inline(always)]
+    pub fn generate_within_len(len: RangeInclusive<usize>, options: PassphraseOptions) -> Result<Self, Error> {
+        Self::generate_within_len_rng(len, options, &mut default_rng())
+    }
+
+    /// Generates a passphrase like
+    /// [`generate_within_len`](Self::generate_within_len), using the
+    /// supplied RNG.
+    pub fn generate_within_len_rng(
+        len: RangeInclusive<usize>,
+        options: PassphraseOptions,
+        rng: &mut impl Rng,
+    ) -> Result<Self, Error> {
+        // Separators and inserted characters take a fixed share of the
+        // length, and the words share the rest.
+        let fixed = options.fixed_len();
+        let max = len.end().checked_sub(fixed).ok_or(Error::NoWordsForFilter)?;
+        let min = len.start().saturating_sub(fixed);
+        let pool = options.pool();
+        let by_len: Vec<Cow<'static, [&'static str]>> = match pool {
+            Cow::Owned(pool) => {
+                let mut by_len = vec![Vec::new(); max + 1];
+                for word in pool {
+                    if let Some(words) = by_len.get_mut(word.chars().count()) {
+                        words.push(word);
+                    }
+                }
+                by_len.into_iter().map(Cow::Owned).collect()
+            }
+            Cow::Borrowed(_) => (0..=max)
+                .map(|len| Cow::Borrowed(all_len(len, options.lang).unwrap_or_default()))
+                .collect(),
+        };
+        let table = LenTable::new(&by_len, options.words, options.allow_repeats);
+        let (combinations, totals) = table.combinations(min..=max);
+        if combinations == 0.0 {
+            return Err(Error::NoWordsForFilter);
+        }
+        for _ in 0..REJECTION_ATTEMPTS {
+            let mut lens = table.sample(&totals, rng);
+            lens.shuffle(rng);
+            let mut words = vec![""; lens.len()];
+            for (len, words_of_len) in by_len.iter().enumerate() {
+                let positions: Vec<usize> = (0..lens.len()).filter(|&i| lens[i] == len).collect();
+                let picks: Vec<&'static str> = if options.allow_repeats {
+                    positions.iter().map(|_| *words_of_len.choose(rng).expect("non-empty")).collect()
+                } else {
+                    index::sample(rng, words_of_len.len(), positions.len())
+                        .into_iter()
+                        .map(|i| words_of_len[i])
+                        .collect()
+                };
+                for (position, word) in positions.into_iter().zip(picks) {
+                    words[position] = word;
+                }
+            }
+            let passphrase = Self::finish(words, combinations.log2(), &options, rng);
+            // Transforms keep the length of almost all words, but a few
+            // letters change length with their case, such as "ß".
+            if len.contains(&passphrase.render().chars().count()) {
+                return Ok(passphrase);
+            }
+        }
+        Err(Error::NoWordsForFilter)
+    }
+
+    /// Draws a passphrase from `pool`, the pool of `options`.
+    fn draw(options: &PassphraseOptions, pool: &[&'static str], rng: &mut impl Rng) -> Self {
+        assert!(!pool.is_empty(), "the passphrase filter and blocklist leave no words");
+        let words: Vec<&'static str> = if options.allow_repeats {
+            (0..options.words)
+                .map(|_| *pool.choose(rng).expect("non-empty"))
+                .collect()
+        } else {
+            assert!(options.words <= pool.len(), "not enough words for a passphrase without repeats");
+            index::sample(rng, pool.len(), options.words)
+                .into_iter()
+                .map(|i| pool[i])
+                .collect()
+        };
+        Self::finish(words, options.entropy(pool.len()), options, rng)
+    }
+
+    /// Renders drawn words and applies the transforms of `options`, given
+    /// the entropy of the draw.
+    fn finish(words: Vec<&'static str>, mut entropy_bits: f64, options: &PassphraseOptions, rng: &mut impl Rng) -> Self {
+        let mut rendered: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+        let mut separators = vec![options.separator.clone(); options.words.saturating_sub(1)];
+        for &transform in &options.transforms {
+            entropy_bits += transform.apply(&mut rendered, &mut separators, rng);
+        }
+        Self {
+            words,
+            rendered,
+            separators,
+            entropy_bits,
+        }
+    }
+
+    /// Generates a passphrase with the fewest words of the given language
+    /// whose entropy is at least `bits`.
+    ///
+    /// Returns [`Error::InvalidEntropyTarget`] if `bits` is infinite or NaN,
+    /// and [`Error::NoWordsForFilter`] if even a passphrase of every word of
+    /// the language falls short of it.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Error, Lang, Passphrase};
+    /// let passphrase = Passphrase::with_min_entropy(80.0, Lang::En).unwrap();
+    /// assert!(passphrase.entropy_bits() >= 80.0);
+    /// assert_eq!(Passphrase::with_min_entropy(f64::NAN, Lang::En), Err(Error::InvalidEntropyTarget));
+    /// ```
+    pub fn with_min_entropy(bits: f64, lang: Lang) -> Result<Self, Error> {
+        let options = PassphraseOptions {
+            lang,
+            ..Default::default()
+        };
+        Self::with_min_entropy_options(bits, options)
+    }
+
+    /// Generates a passphrase like [`with_min_entropy`](Self::with_min_entropy),
+    /// choosing `options.words` from the size of the pool left by
+    /// `options.filter`. Transforms can only add entropy and are not relied
+    /// on.
+    ///
+    /// Returns [`Error::InvalidEntropyTarget`] if `bits` is infinite or NaN,
+    /// and [`Error::NoWordsForFilter`] if the filter leaves too few words to
+    /// reach `bits`.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, Passphrase, PassphraseOptions, WordQuery};
+    /// let options = PassphraseOptions {
+    ///     filter: Some(WordQuery::new(Lang::En).len(3..=5)),
+    ///     ..Default::default()
+    /// };
+    /// let passphrase = Passphrase::with_min_entropy_options(60.0, options).unwrap();
+    /// assert!(passphrase.entropy_bits() >= 60.0);
+    /// assert!(passphrase.words().len() > random_word::words_for_entropy(60.0, Lang::En));
+    /// ```
+    pub fn with_min_entropy_options(bits: f64, mut options: PassphraseOptions) -> Result<Self, Error> {
+        if !bits.is_finite() {
+            return Err(Error::InvalidEntropyTarget);
+        }
+        let pool_size = options.pool().len();
+        if pool_size < 2 {
+            return Err(Error::NoWordsForFilter);
+        }
+        options.words = if options.allow_repeats {
+            let per_word = pool_entropy(pool_size);
+            let words = (bits / per_word).ceil().max(1.0) as usize;
+            // Rounding can leave the product a hair short of `bits`.
+            words + usize::from((words as f64 * per_word) < bits)
+        } else {
+            // Each further word adds the entropy of the words left, so the
+            // sum is kept rather than recomputed.
+            let mut total = 0.0;
+            let mut words = 0;
+            while words == 0 || total < bits {
+                if words == pool_size {
+                    return Err(Error::NoWordsForFilter);
+                }
+                total += pool_entropy(pool_size - words);
+                words += 1;
+            }
+            words
+        };
+        Ok(Self::generate(options))
+    }
+
+    /// Generates a passphrase that satisfies `policy`.
+    ///
+    /// Transforms are added to `options` for required digits, symbols and
+    /// uppercase letters the options don't already produce, and words are
+    /// added while the result is too short. Candidates that still break the
+    /// policy are discarded and drawn again. Returns
+    /// [`Error::PolicyUnsatisfiable`] if no candidate passes.
+    ///
+    /// The reported entropy is that of the adjusted options, an upper bound
+    /// that is close when few candidates are discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Passphrase, PassphraseOptions, Policy, RequiredClass};
+    /// let policy = Policy::new()
+    ///     .min_len(20)
+    ///     .require(RequiredClass::Uppercase)
+    ///     .require(RequiredClass::Digit);
+    /// let passphrase = Passphrase::generate_for(PassphraseOptions::default(), &policy).unwrap();
+    /// assert!(policy.allows(&passphrase.to_string()));
+    /// ```
+    #[inline(always)]
+    pub fn generate_for(options: PassphraseOptions, policy: &Policy) -> Result<Self, Error> {
+        Self::generate_for_rng(options, policy, &mut default_rng())
+    }
+
+    /// Generates a passphrase that satisfies `policy` like
+    /// [`generate_for`](Self::generate_for), using the supplied RNG.
+    pub fn generate_for_rng(mut options: PassphraseOptions, policy: &Policy, rng: &mut impl Rng) -> Result<Self, Error> {
+        for &class in policy.required() {
+            let transform = match class {
+                RequiredClass::Uppercase => Transform::Capitalize(Capitalize::First),
+                RequiredClass::Digit => Transform::InsertDigit,
+                RequiredClass::Symbol => Transform::InsertSymbol,
+                RequiredClass::Lowercase => continue,
+            };
+            let covered = options.transforms.iter().any(|&t| match class {
+                RequiredClass::Uppercase => matches!(t, Transform::Capitalize(_)),
+                _ => t == transform,
+            });
+            if !covered {
+                options.transforms.push(transform);
+            }
+        }
+        options.words = options.words.max(1);
+        let pool_size = options.pool().len();
+        for _ in 0..REJECTION_ATTEMPTS {
+            let passphrase = Self::generate_rng(options.clone(), rng);
+            let text = passphrase.render();
+            if text.chars().count() < policy.min() {
+                if !options.allow_repeats && options.words == pool_size {
+                    break;
+                }
+                options.words += 1;
+                continue;
+            }
+            if policy.allows(&text) {
+                return Ok(passphrase);
+            }
+        }
+        Err(Error::PolicyUnsatisfiable)
+    }
+
+    /// Returns the words of the passphrase in order, as drawn from the
+    /// word list before any transforms.
+    #[inline(always)]
+    pub fn words(&self) -> &[&'static str] {
+        &self.words
+    }
+
+    /// Returns the entropy in bits of the process that generated this
+    /// passphrase, including its transforms, assuming the attacker knows the
+    /// options.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, Passphrase, PassphraseOptions};
+    /// let passphrase = Passphrase::generate(PassphraseOptions::default());
+    /// // Slightly less than four independent words, as words don't repeat.
+    /// assert!(passphrase.entropy_bits() < random_word::passphrase_entropy(4, Lang::En));
+    /// assert!(passphrase.entropy_bits() > random_word::passphrase_entropy(4, Lang::En) - 0.001);
+    /// ```
+    #[inline(always)]
+    pub fn entropy_bits(&self) -> f64 {
+        self.entropy_bits
+    }
+
+    /// Returns the passphrase in a buffer that is wiped when dropped, unlike
+    /// the `String` from `to_string`.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Passphrase, PassphraseOptions};
+    /// let passphrase = Passphrase::generate(PassphraseOptions::default());
+    /// let secret = passphrase.to_zeroizing();
+    /// assert_eq!(secret.as_str(), passphrase.to_string());
+    /// ```
+    #[cfg(feature = "zeroize")]
+    #[inline(always)]
+    pub fn to_zeroizing(&self) -> zeroize::Zeroizing<String> {
+        self.render()
+    }
+
+    /// Assembles the passphrase into a buffer of exactly the right capacity,
+    /// so it is never reallocated and copied.
+    fn render(&self) -> Secret {
+        let len = self.rendered.iter().chain(&self.separators).map(String::len).sum();
+        let mut text = Secret::from(String::with_capacity(len));
+        for (i, word) in self.rendered.iter().enumerate() {
+            if i > 0 {
+                text.push_str(&self.separators[i - 1]);
+            }
+            text.push_str(word);
+        }
+        text
+    }
+}
+
+/// Wipes the rendered words and separators. The drawn words themselves
+/// point into the embedded word list and hold no copy of the text.
+#[cfg(feature = "zeroize")]
+impl Drop for Passphrase {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+        self.rendered.zeroize();
+        self.separators.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Passphrase {}
+
+impl fmt::Display for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, word) in self.rendered.iter().enumerate() {
+            if i > 0 {
+                f.write_str(&self.separators[i - 1])?;
+            }
+            f.write_str(word)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the ways to pick word lengths for a passphrase, by length
+/// multiset.
+///
+/// `layers[i][j][t]` is the number of ways, up to ordering, to pick `j`
+/// words of total length `t` from the words of length `i` or less. Dividing
+/// by the `k!` orderings of each length and multiplying by `words!` at the
+/// end counts every ordered passphrase once.
+struct LenTable {
+    layers: Vec<Vec<Vec<f64>>>,
+    /// The word count of each length.
+    counts: Vec<usize>,
+    words: usize,
+    allow_repeats: bool,
+}
+
+impl LenTable {
+    fn new(by_len: &[Cow<'static, [&'static str]>], words: usize, allow_repeats: bool) -> Self {
+        let max = by_len.len().saturating_sub(1);
+        let counts: Vec<usize> = by_len.iter().map(|words| words.len()).collect();
+        let mut empty = vec![vec![0.0; max + 1]; words + 1];
+        empty[0][0] = 1.0;
+        let mut layers = vec![empty];
+        for (len, &count) in counts.iter().enumerate().skip(1) {
+            let prev = layers.last().expect("non-empty");
+            let mut layer = prev.clone();
+            for k in 1..=words {
+                let ways = Self::ways(count, k, allow_repeats);
+                if ways == 0.0 || k * len > max {
+                    break;
+                }
+                for j in 0..=words - k {
+                    for t in 0..=max - k * len {
+                        layer[j + k][t + k * len] += prev[j][t] * ways;
+                    }
+                }
+            }
+            layers.push(layer);
+        }
+        Self {
+            layers,
+            counts,
+            words,
+            allow_repeats,
+        }
+    }
+
+    /// Returns the number of ways to fill `k` slots from `count` words,
+    /// divided by the `k!` orderings of the slots.
+    fn ways(count: usize, k: usize, allow_repeats: bool) -> f64 {
+        (0..k)
+            .map(|i| {
+                let choices = if allow_repeats { count } else { count.saturating_sub(i) };
+                choices as f64 / (i + 1) as f64
+            })
+            .product()
+    }
+
+    /// Returns the number of passphrases with total word length in `lens`,
+    /// and the count of each total.
+    fn combinations(&self, lens: RangeInclusive<usize>) -> (f64, Vec<f64>) {
+        let last = &self.layers.last().expect("non-empty")[self.words];
+        let totals: Vec<f64> = (0..last.len())
+            .map(|t| if lens.contains(&t) { last[t] } else { 0.0 })
+            .collect();
+        let orderings: f64 = (1..=self.words).map(|i| i as f64).product();
+        (totals.iter().sum::<f64>() * orderings, totals)
+    }
+
+    /// Draws word lengths, one per word in ascending order, with total
+    /// length weighted by `totals`.
+    fn sample(&self, totals: &[f64], rng: &mut impl Rng) -> Vec<usize> {
+        let mut t = weighted(totals.iter().copied(), rng);
+        let mut j = self.words;
+        let mut lens = Vec::with_capacity(self.words);
+        for len in (1..self.layers.len()).rev() {
+            let prev = &self.layers[len - 1];
+            let weights = (0..=j).map(|k| match t.checked_sub(k * len) {
+                Some(rest) => prev[j - k][rest] * Self::ways(self.counts[len], k, self.allow_repeats),
+                None => 0.0,
+            });
+            let k = weighted(weights, rng);
+            lens.extend(std::iter::repeat_n(len, k));
+            j -= k;
+            t -= k * len;
+        }
+        lens
+    }
+}
+
+/// Returns an index drawn with probability proportional to its weight.
+fn weighted(weights: impl Iterator<Item = f64> + Clone, rng: &mut impl Rng) -> usize {
+    let mut target = rng.random::<f64>() * weights.clone().sum::<f64>();
+    let mut last = 0;
+    for (i, weight) in weights.enumerate() {
+        if weight > 0.0 {
+            last = i;
+            target -= weight;
+            if target < 0.0 {
+                break;
+            }
+        }
+    }
+    last
+}
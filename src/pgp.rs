@@ -0,0 +1,85 @@
+//! The PGP word list, for reading fingerprints and hashes aloud.
+//!
+//! Bytes at even positions are spelled with two-syllable words and bytes at
+//! odd positions with three-syllable words, so a swapped, repeated or
+//! dropped word is detected when decoding. Enabled by the `pgp` feature.
+//!
+//! # Example
+//! ```
+//! use random_word::pgp;
+//! let words = pgp::fingerprint_to_words(&[0xe5, 0x82, 0x94, 0xf2]);
+//! assert_eq!(words, ["topmost", "istanbul", "pluto", "vagabond"]);
+//! assert_eq!(pgp::words_to_fingerprint(&words).unwrap(), [0xe5, 0x82, 0x94, 0xf2]);
+//! ```
+
+use crate::Error;
+use ahash::AHashMap;
+use std::sync::OnceLock;
+
+static EVEN: OnceLock<Box<[&'static str]>> = OnceLock::new();
+static ODD: OnceLock<Box<[&'static str]>> = OnceLock::new();
+static INDEX: OnceLock<AHashMap<&'static str, (u8, bool)>> = OnceLock::new();
+
+/// Returns the 256 two-syllable words used for bytes at even positions,
+/// indexed by byte value.
+pub fn even_words() -> &'static [&'static str] {
+    EVEN.get_or_init(|| include_str!("pgp/even.txt").lines().collect())
+}
+
+/// Returns the 256 three-syllable words used for bytes at odd positions,
+/// indexed by byte value.
+pub fn odd_words() -> &'static [&'static str] {
+    ODD.get_or_init(|| include_str!("pgp/odd.txt").lines().collect())
+}
+
+/// Maps each word to its byte and whether it belongs to the even list.
+fn index() -> &'static AHashMap<&'static str, (u8, bool)> {
+    INDEX.get_or_init(|| {
+        let even = even_words().iter().enumerate().map(|(i, &word)| (word, (i as u8, true)));
+        let odd = odd_words().iter().enumerate().map(|(i, &word)| (word, (i as u8, false)));
+        even.chain(odd).collect()
+    })
+}
+
+/// Spells `bytes` with one word per byte, alternating between the even and
+/// odd lists.
+pub fn fingerprint_to_words(bytes: &[u8]) -> Vec<&'static str> {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(i, &byte)| {
+            let list = if i % 2 == 0 { even_words() } else { odd_words() };
+            list[byte as usize]
+        })
+        .collect()
+}
+
+/// Reads bytes back from words spelled by [`fingerprint_to_words`],
+/// ignoring case.
+///
+/// Returns [`Error::UnknownWord`] with the position of a word in neither
+/// list, and [`Error::WordOutOfOrder`] with the position of a word from the
+/// wrong list, which usually means a word was skipped or swapped.
+///
+/// # Example
+/// ```
+/// use random_word::{Error, pgp};
+/// assert_eq!(pgp::words_to_fingerprint(&["Aardvark", "adroitness"]), Ok(vec![0, 0]));
+/// assert_eq!(pgp::words_to_fingerprint(&["adroitness"]), Err(Error::WordOutOfOrder(0)));
+/// ```
+pub fn words_to_fingerprint<S: AsRef<str>>(words: &[S]) -> Result<Vec<u8>, Error> {
+    let index = index();
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let word = word.as_ref().to_lowercase();
+            let &(byte, even) = index.get(word.as_str()).ok_or(Error::UnknownWord(i))?;
+            if even == (i % 2 == 0) {
+                Ok(byte)
+            } else {
+                Err(Error::WordOutOfOrder(i))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,569 @@
+use crate::{Lang, words};
+
+/// Returns the lowercase vowel letters of the given language, or `None` for
+/// languages not written with an alphabet.
+pub(crate) fn vowels(lang: Lang) -> Option<&'static str> {
+    match lang {
+        #[cfg(feature = "de")]
+        Lang::De => Some("aeiouyäöü"),
+        #[cfg(feature = "en")]
+        Lang::En => Some("aeiouy"),
+        #[cfg(feature = "es")]
+        Lang::Es => Some("aeiouáéíóúü"),
+        #[cfg(feature = "fr")]
+        Lang::Fr => Some("aeiouyàâæéèêëîïôœùûüÿ"),
+        #[cfg(feature = "ja")]
+        Lang::Ja => None,
+        #[cfg(feature = "ru")]
+        Lang::Ru => Some("аеёиоуыэюя"),
+        #[cfg(feature = "zh")]
+        Lang::Zh => None,
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => Some("aeiouy"),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => Some("aeiouy"),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => Some("aeiouy"),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => Some("aeiouy"),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => Some("aeiouy"),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => Some("aeiouy"),
+    }
+}
+
+/// Returns whether `char` is a vowel of the given alphabet, ignoring case.
+#[inline(always)]
+pub(crate) fn is_vowel(char: char, vowels: &str) -> bool {
+    vowels.contains(words::fold(char))
+}
+
+/// Returns whether `word` has the consonant/vowel structure of `pattern`,
+/// where `C` is a consonant, `V` a vowel and `_` any letter.
+pub(crate) fn fits_cv_pattern(word: &str, pattern: &str, vowels: &str) -> bool {
+    let mut chars = word.chars();
+    pattern.chars().all(|p| {
+        chars.next().is_some_and(|c| {
+            c.is_alphabetic()
+                && match p.to_ascii_uppercase() {
+                    'C' => !is_vowel(c, vowels),
+                    'V' => is_vowel(c, vowels),
+                    _ => true,
+                }
+        })
+    }) && chars.next().is_none()
+}
+
+/// Estimates the number of syllables of `word`, or returns `None` if the
+/// word has no estimate (for example Japanese words without a kana reading).
+///
+/// Alphabetic languages count vowel groups with a few language-specific
+/// adjustments for silent endings and hiatus. Japanese counts morae of the
+/// kana reading and Chinese counts Han characters.
+pub(crate) fn syllables(word: &str, lang: Lang) -> Option<usize> {
+    let count = match lang {
+        #[cfg(feature = "de")]
+        Lang::De => vowel_groups(word, "aeiouyäöü"),
+        #[cfg(feature = "en")]
+        Lang::En => english_syllables(word),
+        #[cfg(feature = "es")]
+        Lang::Es => spanish_syllables(word),
+        #[cfg(feature = "fr")]
+        Lang::Fr => french_syllables(word),
+        #[cfg(feature = "ja")]
+        Lang::Ja => morae(word),
+        #[cfg(feature = "ru")]
+        Lang::Ru => word.chars().filter(|&c| is_vowel(c, "аеёиоуыэюя")).count(),
+        #[cfg(feature = "zh")]
+        Lang::Zh => han_characters(word),
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => english_syllables(word),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => english_syllables(word),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => english_syllables(word),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => english_syllables(word),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => english_syllables(word),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => english_syllables(word),
+    };
+    (count > 0).then_some(count)
+}
+
+/// Counts maximal runs of vowels in `word`.
+#[cfg_attr(
+    not(any(
+        feature = "de",
+        feature = "en",
+        feature = "fr",
+        feature = "eff-long",
+        feature = "eff-short1",
+        feature = "eff-short2",
+        feature = "en-10k",
+        feature = "en-gb",
+        feature = "en-us"
+    )),
+    allow(dead_code)
+)]
+fn vowel_groups(word: &str, vowels: &str) -> usize {
+    let mut groups = 0;
+    let mut in_group = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c, vowels);
+        if vowel && !in_group {
+            groups += 1;
+        }
+        in_group = vowel;
+    }
+    groups
+}
+
+#[cfg(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us"))]
+fn english_syllables(word: &str) -> usize {
+    let word = word.to_lowercase();
+    // A leading "y" is a consonant, as in "yes".
+    let body = word.strip_prefix('y').unwrap_or(&word);
+    let count = vowel_groups(body, "aeiouy");
+    let count = if count > 1 && english_silent_ending(&word) { count - 1 } else { count };
+    count.max(word.chars().any(char::is_alphabetic) as usize)
+}
+
+/// Returns whether the last vowel group of lowercase `word` is silent:
+/// "make", "played", "makes", but not "table", "wanted" or "boxes".
+#[cfg(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us"))]
+fn english_silent_ending(word: &str) -> bool {
+    let stem_end = |suffix: &str| word.strip_suffix(suffix).and_then(|stem| stem.chars().next_back());
+    let consonant = |c: char| !is_vowel(c, "aeiouy");
+    (word.ends_with('e')
+        && !word.ends_with("ee")
+        && !(word.ends_with("le") && stem_end("le").is_some_and(consonant)))
+        || stem_end("ed").is_some_and(|c| c != 'e' && !"td".contains(c))
+        || stem_end("es").is_some_and(|c| consonant(c) && !"sxzhcg".contains(c))
+}
+
+#[cfg(feature = "es")]
+fn spanish_syllables(word: &str) -> usize {
+    const STRONG: &str = "aeoáéóíú";
+    let mut count = 0;
+    let mut prev: Option<char> = None;
+    for c in word.chars().map(crate::words::fold) {
+        if is_vowel(c, "aeiouáéíóúü") {
+            // Two strong vowels, or an accented weak one, form a hiatus.
+            let hiatus = prev.is_some_and(|p| STRONG.contains(p) && STRONG.contains(c));
+            if prev.is_none() || hiatus {
+                count += 1;
+            }
+            prev = Some(c);
+        } else {
+            prev = None;
+        }
+    }
+    count
+}
+
+#[cfg(feature = "fr")]
+fn french_syllables(word: &str) -> usize {
+    let word = word.to_lowercase();
+    let count = vowel_groups(&word, "aeiouyàâæéèêëîïôœùûüÿ");
+    if count > 1 && french_silent_ending(&word) { count - 1 } else { count }
+}
+
+/// Returns whether lowercase `word` ends in a silent "e", "es" or the verb
+/// ending "ent".
+#[cfg(feature = "fr")]
+fn french_silent_ending(word: &str) -> bool {
+    ["e", "es", "ent"].iter().any(|ending| {
+        word.strip_suffix(ending)
+            .and_then(|stem| stem.chars().next_back())
+            .is_some_and(|c| !is_vowel(c, "aeiouyàâæéèêëîïôœùûüÿ"))
+    })
+}
+
+/// Counts the morae of the kana reading of a `word [reading]` entry, or of
+/// the word itself when it is written in kana.
+#[cfg(feature = "ja")]
+fn morae(word: &str) -> usize {
+    let reading = word
+        .split_once(" [")
+        .map_or(word, |(_, reading)| reading.trim_end_matches(']'));
+    let is_kana = |c: char| matches!(c, '\u{3041}'..='\u{3096}' | '\u{30A1}'..='\u{30FA}' | 'ー');
+    if !reading.chars().all(is_kana) {
+        return 0;
+    }
+    // Small ya, yu, yo and vowels merge with the preceding kana.
+    reading.chars().filter(|&c| !"ゃゅょゎぁぃぅぇぉャュョヮァィゥェォ".contains(c)).count()
+}
+
+/// Counts the Han characters of the traditional form of a Chinese entry.
+#[cfg(feature = "zh")]
+fn han_characters(word: &str) -> usize {
+    let traditional = word.split_whitespace().next().unwrap_or(word);
+    let is_han = |c: char| matches!(c, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' | '\u{20000}'..='\u{3134F}');
+    if traditional.chars().all(is_han) { traditional.chars().count() } else { 0 }
+}
+
+/// Returns the lowercase spelling of the end of `word` that rhymes, from the
+/// start of its second-to-last sounding vowel group ("station" gives
+/// "ation") or of its only one ("make" gives "ake").
+///
+/// Returns `None` for languages not written with an alphabet and for words
+/// without vowels.
+pub(crate) fn rhyme_key(word: &str, lang: Lang) -> Option<Box<str>> {
+    let vowels = vowels(lang)?;
+    let word = word.to_lowercase();
+    let mut starts = Vec::new();
+    let mut in_group = false;
+    for (i, c) in word.char_indices() {
+        let vowel = is_vowel(c, vowels) && !(i == 0 && c == 'y');
+        if vowel && !in_group {
+            starts.push(i);
+        }
+        in_group = vowel;
+    }
+    if starts.len() > 1 && silent_ending(&word, lang) {
+        starts.pop();
+    }
+    let start = *starts.iter().rev().nth(1).or(starts.last())?;
+    Some(word[start..].into())
+}
+
+/// Returns whether the last vowel group of lowercase `word` is not
+/// pronounced.
+#[cfg_attr(
+    not(any(
+        feature = "en",
+        feature = "fr",
+        feature = "eff-long",
+        feature = "eff-short1",
+        feature = "eff-short2",
+        feature = "en-10k",
+        feature = "en-gb",
+        feature = "en-us"
+    )),
+    allow(unused_variables)
+)]
+fn silent_ending(word: &str, lang: Lang) -> bool {
+    match lang {
+        #[cfg(feature = "en")]
+        Lang::En => english_silent_ending(word),
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => english_silent_ending(word),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => english_silent_ending(word),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => english_silent_ending(word),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => english_silent_ending(word),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => english_silent_ending(word),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => english_silent_ending(word),
+        #[cfg(feature = "fr")]
+        Lang::Fr => french_silent_ending(word),
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
+/// Returns the key under which words that sound alike are grouped:
+/// Metaphone for English, Soundex for the other Latin-script languages and
+/// the hiragana reading for Japanese. Returns `None` for Russian and
+/// Chinese, and for words without letters.
+#[cfg_attr(
+    not(any(
+        feature = "de",
+        feature = "en",
+        feature = "es",
+        feature = "fr",
+        feature = "ja",
+        feature = "eff-long",
+        feature = "eff-short1",
+        feature = "eff-short2",
+        feature = "en-10k",
+        feature = "en-gb",
+        feature = "en-us"
+    )),
+    allow(unused_variables)
+)]
+pub(crate) fn phonetic_key(word: &str, lang: Lang) -> Option<Box<str>> {
+    let key: Option<Box<str>> = match lang {
+        #[cfg(feature = "de")]
+        Lang::De => Some(soundex(word)),
+        #[cfg(feature = "en")]
+        Lang::En => Some(metaphone(word)),
+        #[cfg(feature = "es")]
+        Lang::Es => Some(soundex(word)),
+        #[cfg(feature = "fr")]
+        Lang::Fr => Some(soundex(word)),
+        #[cfg(feature = "ja")]
+        Lang::Ja => Some(hiragana_reading(word)),
+        #[cfg(feature = "ru")]
+        Lang::Ru => None,
+        #[cfg(feature = "zh")]
+        Lang::Zh => None,
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => Some(metaphone(word)),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => Some(metaphone(word)),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => Some(metaphone(word)),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => Some(metaphone(word)),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => Some(metaphone(word)),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => Some(metaphone(word)),
+    };
+    key.filter(|key| !key.is_empty())
+}
+
+/// Returns the major-system digits encoded by the consonant sounds of
+/// `word`, such as `314` for "meteor", read from its Metaphone key. Returns
+/// `None` for languages other than English and for words without
+/// consonant sounds.
+#[cfg_attr(
+    not(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us")),
+    allow(unused_variables, unreachable_code)
+)]
+pub(crate) fn major_key(word: &str, lang: Lang) -> Option<Box<str>> {
+    let key: Box<str> = match lang {
+        #[cfg(feature = "en")]
+        Lang::En => metaphone(word),
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => metaphone(word),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => metaphone(word),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => metaphone(word),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => metaphone(word),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => metaphone(word),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => metaphone(word),
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    let digits: String = key
+        .chars()
+        .filter_map(|c| match c {
+            'S' => Some('0'),
+            'T' | '0' => Some('1'),
+            'N' => Some('2'),
+            'M' => Some('3'),
+            'R' => Some('4'),
+            'L' => Some('5'),
+            'J' | 'X' => Some('6'),
+            'K' => Some('7'),
+            'F' => Some('8'),
+            'P' | 'B' => Some('9'),
+            // Vowels and the semivowels H, W and Y carry no digit.
+            _ => None,
+        })
+        .collect();
+    (!digits.is_empty()).then(|| digits.into())
+}
+
+/// Returns the uppercase ASCII letters of `word`, with accents removed.
+#[cfg(any(
+    feature = "de",
+    feature = "en",
+    feature = "es",
+    feature = "fr",
+    feature = "eff-long",
+    feature = "eff-short1",
+    feature = "eff-short2",
+    feature = "en-10k",
+    feature = "en-gb",
+    feature = "en-us"
+))]
+fn ascii_letters(word: &str) -> Vec<u8> {
+    let mut letters = Vec::with_capacity(word.len());
+    for c in word.chars().flat_map(char::to_uppercase) {
+        let base = match c {
+            'À'..='Å' => "A",
+            'Æ' => "AE",
+            'Ç' => "C",
+            'È'..='Ë' => "E",
+            'Ì'..='Ï' => "I",
+            'Ñ' => "N",
+            'Ò'..='Ö' | 'Ø' => "O",
+            'Œ' => "OE",
+            'Ù'..='Ü' => "U",
+            'Ý' | 'Ÿ' => "Y",
+            'A'..='Z' => {
+                letters.push(c as u8);
+                continue;
+            }
+            _ => "",
+        };
+        letters.extend_from_slice(base.as_bytes());
+    }
+    letters
+}
+
+/// Returns the American Soundex code of `word`, such as `R163` for
+/// "Robert".
+#[cfg(any(feature = "de", feature = "es", feature = "fr"))]
+fn soundex(word: &str) -> Box<str> {
+    fn digit(letter: u8) -> u8 {
+        match letter {
+            b'B' | b'F' | b'P' | b'V' => b'1',
+            b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => b'2',
+            b'D' | b'T' => b'3',
+            b'L' => b'4',
+            b'M' | b'N' => b'5',
+            b'R' => b'6',
+            // H and W don't separate letters with the same code.
+            b'H' | b'W' => b'-',
+            _ => b'0',
+        }
+    }
+
+    let letters = ascii_letters(word);
+    let Some((&first, rest)) = letters.split_first() else {
+        return "".into();
+    };
+    let mut code = vec![first];
+    let mut last = digit(first);
+    for &letter in rest {
+        let d = digit(letter);
+        if d == b'-' {
+            continue;
+        }
+        if d != b'0' && d != last {
+            code.push(d);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        last = d;
+    }
+    code.resize(4, b'0');
+    String::from_utf8(code).expect("soundex codes are ASCII").into()
+}
+
+/// Returns the Metaphone key of `word`, such as `NT` for both "night" and
+/// "nite". `0` stands for "th" and `X` for "sh".
+#[cfg(any(feature = "en", feature = "eff-long", feature = "eff-short1", feature = "eff-short2", feature = "en-10k", feature = "en-gb", feature = "en-us"))]
+fn metaphone(word: &str) -> Box<str> {
+    let mut w = ascii_letters(word);
+    if w.is_empty() {
+        return "".into();
+    }
+    match (w[0], w.get(1).copied()) {
+        (b'A', Some(b'E')) | (b'G' | b'K' | b'P', Some(b'N')) | (b'W', Some(b'R')) => {
+            w.remove(0);
+        }
+        (b'X', _) => w[0] = b'S',
+        (b'W', Some(b'H')) => {
+            w.remove(1);
+        }
+        _ => {}
+    }
+
+    let is_vowel = |c: Option<u8>| matches!(c, Some(b'A' | b'E' | b'I' | b'O' | b'U'));
+    let is_front = |c: Option<u8>| matches!(c, Some(b'E' | b'I' | b'Y'));
+    let at = |i: usize| w.get(i).copied();
+    let mut key = String::new();
+    for i in 0..w.len() {
+        let c = w[i];
+        let prev = i.checked_sub(1).map(|p| w[p]);
+        let (next, after) = (at(i + 1), at(i + 2));
+        if prev == Some(c) && c != b'C' {
+            continue;
+        }
+        match c {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                if i == 0 {
+                    key.push(c as char);
+                }
+            }
+            b'B' => {
+                if !(prev == Some(b'M') && next.is_none()) {
+                    key.push('B');
+                }
+            }
+            b'C' => {
+                if next == Some(b'I') && after == Some(b'A') {
+                    key.push('X');
+                } else if next == Some(b'H') {
+                    key.push(if prev == Some(b'S') { 'K' } else { 'X' });
+                } else if is_front(next) {
+                    if prev != Some(b'S') {
+                        key.push('S');
+                    }
+                } else {
+                    key.push('K');
+                }
+            }
+            b'D' => key.push(if next == Some(b'G') && is_front(after) { 'J' } else { 'T' }),
+            b'G' => {
+                let silent_gh = next == Some(b'H') && !is_vowel(after);
+                let silent_gn = next == Some(b'N') && (after.is_none() || (after == Some(b'E') && at(i + 3) == Some(b'D')));
+                let soft_dg = prev == Some(b'D') && is_front(next);
+                if !(silent_gh || silent_gn || soft_dg) {
+                    key.push(if is_front(next) { 'J' } else { 'K' });
+                }
+            }
+            b'H' => {
+                let after_modifier = matches!(prev, Some(b'C' | b'S' | b'P' | b'T' | b'G'));
+                if !after_modifier && is_vowel(next) {
+                    key.push('H');
+                }
+            }
+            b'K' => {
+                if prev != Some(b'C') {
+                    key.push('K');
+                }
+            }
+            b'P' => key.push(if next == Some(b'H') { 'F' } else { 'P' }),
+            b'Q' => key.push('K'),
+            b'S' => {
+                if next == Some(b'H') || (next == Some(b'I') && matches!(after, Some(b'O' | b'A'))) {
+                    key.push('X');
+                } else {
+                    key.push('S');
+                }
+            }
+            b'T' => {
+                if next == Some(b'I') && matches!(after, Some(b'O' | b'A')) {
+                    key.push('X');
+                } else if next == Some(b'H') {
+                    key.push('0');
+                } else if !(next == Some(b'C') && after == Some(b'H')) {
+                    key.push('T');
+                }
+            }
+            b'V' => key.push('F'),
+            b'W' | b'Y' => {
+                if is_vowel(next) {
+                    key.push(c as char);
+                }
+            }
+            b'X' => key.push_str("KS"),
+            b'Z' => key.push('S'),
+            _ => key.push(c as char),
+        }
+    }
+    key.into()
+}
+
+/// Returns the kana reading of a `word [reading]` entry, or the word itself,
+/// with katakana converted to hiragana so both spellings share a key.
+#[cfg(feature = "ja")]
+fn hiragana_reading(word: &str) -> Box<str> {
+    let reading = word
+        .split_once(" [")
+        .map_or(word, |(_, reading)| reading.trim_end_matches(']'));
+    reading
+        .chars()
+        .map(|c| match c {
+            '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
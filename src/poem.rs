@@ -0,0 +1,146 @@
+//! Lines of verse with a fixed number of syllables, such as haiku.
+//!
+//! Syllables are counted with the same rule-based estimator as
+//! [`count_syllables`](crate::count_syllables), so the meter is only as
+//! good as its estimates. Languages with part-of-speech data get lines
+//! shaped like short phrases, such as an adjective and a noun; other
+//! languages get any words that fit.
+//!
+//! # Example
+//! ```
+//! use random_word::{Lang, poem};
+//!
+//! let haiku = poem::haiku(Lang::En).unwrap();
+//! for (line, syllables) in haiku.iter().zip([5, 7, 5]) {
+//!     let count: usize = line
+//!         .split(' ')
+//!         .map(|word| random_word::count_syllables(word, Lang::En).unwrap())
+//!         .sum();
+//!     assert_eq!(count, syllables);
+//! }
+//! ```
+
+use crate::{Lang, Pos, all_syllables, default_rng, pos};
+use rand::Rng;
+use rand::seq::{IndexedRandom, IteratorRandom, index};
+
+/// The parts of speech of a line, tried in random order.
+const SHAPES: &[&[Pos]] = &[
+    &[Pos::Adjective, Pos::Noun],
+    &[Pos::Adjective, Pos::Adjective, Pos::Noun],
+    &[Pos::Noun, Pos::Verb],
+    &[Pos::Adverb, Pos::Verb],
+    &[Pos::Verb, Pos::Adjective, Pos::Noun],
+    &[Pos::Adjective, Pos::Noun, Pos::Verb],
+    &[Pos::Adjective, Pos::Noun, Pos::Adverb],
+];
+
+/// How many random words are drawn looking for a part of speech before
+/// filtering the whole list.
+const DRAWS: usize = 64;
+
+/// Generates a haiku: three lines of 5, 7 and 5 syllables.
+///
+/// Returns `None` if the language has too few words of some syllable count.
+#[inline(always)]
+pub fn haiku(lang: Lang) -> Option<[String; 3]> {
+    haiku_rng(lang, &mut default_rng())
+}
+
+/// Generates a haiku using the supplied RNG, so a seeded RNG gives the same
+/// poem every time.
+///
+/// # Example
+/// ```
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use random_word::{Lang, poem};
+/// let a = poem::haiku_rng(Lang::En, &mut StdRng::seed_from_u64(7));
+/// let b = poem::haiku_rng(Lang::En, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(a, b);
+/// ```
+pub fn haiku_rng(lang: Lang, rng: &mut impl Rng) -> Option<[String; 3]> {
+    Some([line_rng(5, lang, rng)?, line_rng(7, lang, rng)?, line_rng(5, lang, rng)?])
+}
+
+/// Generates a line of space-separated words with `syllables` syllables in
+/// total.
+///
+/// Returns `None` if no words add up to `syllables`, as for 0.
+#[inline(always)]
+pub fn line(syllables: usize, lang: Lang) -> Option<String> {
+    line_rng(syllables, lang, &mut default_rng())
+}
+
+/// Generates a line of space-separated words with `syllables` syllables in
+/// total, using the supplied RNG.
+pub fn line_rng(syllables: usize, lang: Lang, rng: &mut impl Rng) -> Option<String> {
+    if syllables == 0 {
+        return None;
+    }
+    if pos::get(Pos::Noun, lang).is_some() {
+        let mut shapes = SHAPES.to_vec();
+        while !shapes.is_empty() {
+            let shape = shapes.swap_remove(rng.random_range(0..shapes.len()));
+            if let Some(line) = shaped_line(shape, syllables, lang, rng) {
+                return Some(line);
+            }
+        }
+    }
+    free_line(syllables, lang, rng)
+}
+
+/// Fills `shape` with words whose syllables sum to `syllables`, splitting
+/// them at random.
+fn shaped_line(shape: &[Pos], syllables: usize, lang: Lang, rng: &mut impl Rng) -> Option<String> {
+    if syllables < shape.len() {
+        return None;
+    }
+    // Splitting at a random set of cut points makes every split equally
+    // likely; a few tries get past splits with no matching words.
+    for _ in 0..8 {
+        let mut cuts = index::sample(rng, syllables - 1, shape.len() - 1).into_vec();
+        cuts.sort_unstable();
+        let mut start = 0;
+        let mut words = Vec::with_capacity(shape.len());
+        for (i, &part) in shape.iter().enumerate() {
+            let end = cuts.get(i).map_or(syllables, |cut| cut + 1);
+            match word_with(part, end - start, lang, rng) {
+                Some(word) => words.push(word),
+                None => break,
+            }
+            start = end;
+        }
+        if words.len() == shape.len() {
+            return Some(words.join(" "));
+        }
+    }
+    None
+}
+
+/// Returns a random word with the given part of speech and syllable count.
+fn word_with(part: Pos, syllables: usize, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    let words = all_syllables(syllables, lang)?;
+    // Rejection sampling is uniform over the matching words and usually
+    // much faster than filtering.
+    for _ in 0..DRAWS {
+        let word = *words.choose(rng)?;
+        if part.contains(word, lang) {
+            return Some(word);
+        }
+    }
+    words.iter().copied().filter(|word| part.contains(word, lang)).choose(rng)
+}
+
+/// Fills a line with words of any part of speech.
+fn free_line(syllables: usize, lang: Lang, rng: &mut impl Rng) -> Option<String> {
+    let mut words = Vec::new();
+    let mut remaining = syllables;
+    while remaining > 0 {
+        let (count, choices) = (1..=remaining.min(4))
+            .filter_map(|count| Some((count, all_syllables(count, lang)?)))
+            .choose(rng)?;
+        words.push(*choices.choose(rng)?);
+        remaining -= count;
+    }
+    Some(words.join(" "))
+}
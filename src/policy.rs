@@ -0,0 +1,117 @@
+/// A kind of character a [`Policy`] can require at least one of.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RequiredClass {
+    /// A lowercase letter of any script.
+    Lowercase,
+    /// An uppercase letter of any script.
+    Uppercase,
+    /// An ASCII digit `0-9`.
+    Digit,
+    /// An ASCII punctuation or symbol character.
+    Symbol,
+}
+
+impl RequiredClass {
+    /// Returns whether `char` belongs to this class.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::RequiredClass;
+    /// assert!(RequiredClass::Symbol.contains('$'));
+    /// assert!(!RequiredClass::Digit.contains('x'));
+    /// ```
+    #[inline(always)]
+    pub fn contains(self, char: char) -> bool {
+        match self {
+            RequiredClass::Lowercase => char.is_lowercase(),
+            RequiredClass::Uppercase => char.is_uppercase(),
+            RequiredClass::Digit => char.is_ascii_digit(),
+            RequiredClass::Symbol => char.is_ascii_punctuation(),
+        }
+    }
+}
+
+/// Password rules a generated passphrase must satisfy, such as those
+/// imposed by a website or an organisation.
+///
+/// Lengths are measured in characters. See
+/// [`Passphrase::generate_for`](crate::Passphrase::generate_for).
+///
+/// # Example
+/// ```
+/// use random_word::{Policy, RequiredClass};
+/// let policy = Policy::new()
+///     .min_len(12)
+///     .max_len(64)
+///     .require(RequiredClass::Digit)
+///     .ban("password");
+/// assert!(policy.allows("correct-horse-7"));
+/// assert!(!policy.allows("correct-horse"));
+/// assert!(!policy.allows("my-Password-7-ok"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Policy {
+    min_len: usize,
+    max_len: Option<usize>,
+    required: Vec<RequiredClass>,
+    banned: Vec<String>,
+}
+
+impl Policy {
+    /// Creates a policy that allows every password.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires at least `len` characters.
+    pub fn min_len(mut self, len: usize) -> Self {
+        self.min_len = len;
+        self
+    }
+
+    /// Requires at most `len` characters.
+    pub fn max_len(mut self, len: usize) -> Self {
+        self.max_len = Some(len);
+        self
+    }
+
+    /// Requires at least one character of `class`.
+    pub fn require(mut self, class: RequiredClass) -> Self {
+        if !self.required.contains(&class) {
+            self.required.push(class);
+        }
+        self
+    }
+
+    /// Forbids `substring` anywhere in the password, ignoring case.
+    pub fn ban(mut self, substring: &str) -> Self {
+        self.banned.push(substring.to_lowercase());
+        self
+    }
+
+    /// Returns the minimum length in characters.
+    #[inline(always)]
+    pub(crate) fn min(&self) -> usize {
+        self.min_len
+    }
+
+    /// Returns the required character classes.
+    #[inline(always)]
+    pub(crate) fn required(&self) -> &[RequiredClass] {
+        &self.required
+    }
+
+    /// Returns whether `password` satisfies every rule.
+    pub fn allows(&self, password: &str) -> bool {
+        let len = password.chars().count();
+        if len < self.min_len || self.max_len.is_some_and(|max| len > max) {
+            return false;
+        }
+        if !self.required.iter().all(|&class| password.chars().any(|c| class.contains(c))) {
+            return false;
+        }
+        let folded = password.to_lowercase();
+        !self.banned.iter().any(|banned| folded.contains(banned.as_str()))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{Lang, Pos};
+#[cfg(feature = "en")]
+use crate::words::{self, Words};
+#[cfg(feature = "en")]
+use std::sync::OnceLock;
+
+/// Embedded English word lists, indexed by `Pos as usize`.
+#[cfg(feature = "en")]
+static EN_RAW: [&[u8]; 4] = [
+    include_compressed!("pos/en_noun"),
+    include_compressed!("pos/en_verb"),
+    include_compressed!("pos/en_adjective"),
+    include_compressed!("pos/en_adverb"),
+];
+#[cfg(feature = "en")]
+static EN_TEXT: [OnceLock<String>; 4] = [const { OnceLock::new() }; 4];
+#[cfg(feature = "en")]
+static EN: [OnceLock<Words>; 4] = [const { OnceLock::new() }; 4];
+
+/// Returns the words of the given language tagged with `pos`, sorted
+/// byte-wise, or `None` if the language has no part-of-speech data.
+#[cfg_attr(not(feature = "en"), allow(unused_variables))]
+pub(crate) fn get(pos: Pos, lang: Lang) -> Option<&'static [&'static str]> {
+    match lang {
+        #[cfg(feature = "en")]
+        Lang::En => Some(EN[pos as usize].get_or_init(|| {
+            let words = EN_TEXT[pos as usize]
+                .get_or_init(|| words::decompress(EN_RAW[pos as usize]))
+                .lines();
+            #[cfg(feature = "clean")]
+            let words = {
+                let profanity = crate::profanity::Profanity::of(lang);
+                words.filter(move |word| !profanity.matches(word))
+            };
+            words.collect()
+        })),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Error, Lang, try_verify, words};
+
+/// The lookup indexes [`preload_with`](crate::preload_with) builds ahead of
+/// time. The base word list is always loaded.
+///
+/// Length buckets are otherwise filled one at a time as they are first
+/// requested, and starting-character lookups are binary searches of the base
+/// list, so `starts_with` only precomputes the set of initials.
+///
+/// # Example
+/// ```
+/// use random_word::{Indexes, Lang};
+/// let indexes = Indexes { len: true, ..Indexes::NONE };
+/// random_word::preload_with(Lang::En, indexes).unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Indexes {
+    /// The index used by `all_len` and `get_len`.
+    pub len: bool,
+    /// The index used by `all_starts_with` and `get_starts_with`.
+    pub starts_with: bool,
+    /// The index used by `all_ends_with` and `get_ends_with`.
+    pub ends_with: bool,
+}
+
+impl Indexes {
+    /// Only the base word list.
+    pub const NONE: Self = Self {
+        len: false,
+        starts_with: false,
+        ends_with: false,
+    };
+
+    /// The base word list and every index.
+    pub const ALL: Self = Self {
+        len: true,
+        starts_with: true,
+        ends_with: true,
+    };
+}
+
+impl Default for Indexes {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Verifies the word data for the given language, then decompresses it and
+/// builds the selected indexes, so later calls don't pay for it. Corrupt
+/// data is reported before the words are loaded, which would panic on it.
+pub(crate) fn preload(lang: Lang, indexes: Indexes) -> Result<(), Error> {
+    try_verify(lang)?;
+    words::get(lang);
+    build(lang, indexes);
+    Ok(())
+}
+
+/// Builds the selected indexes of a language whose words are loaded.
+fn build(lang: Lang, indexes: Indexes) {
+    if indexes.len {
+        for &len in lang.lengths() {
+            words::get_len(len, lang);
+        }
+    }
+    if indexes.starts_with {
+        lang.initials();
+    }
+    if indexes.ends_with {
+        words::ends_with_index(lang);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::Lang;
+use ahash::AHashSet;
+
+/// Returns the curated profanity of the given language, one word per line.
+/// `static_word!` filters with a copy of these lists in
+/// macros/src/profanity, which must match.
+fn list(lang: Lang) -> &'static str {
+    match lang {
+        #[cfg(feature = "de")]
+        Lang::De => include_str!("profanity/de.txt"),
+        #[cfg(feature = "en")]
+        Lang::En => include_str!("profanity/en.txt"),
+        #[cfg(feature = "es")]
+        Lang::Es => include_str!("profanity/es.txt"),
+        #[cfg(feature = "fr")]
+        Lang::Fr => include_str!("profanity/fr.txt"),
+        #[cfg(feature = "ja")]
+        Lang::Ja => include_str!("profanity/ja.txt"),
+        #[cfg(feature = "ru")]
+        Lang::Ru => include_str!("profanity/ru.txt"),
+        #[cfg(feature = "zh")]
+        Lang::Zh => include_str!("profanity/zh.txt"),
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => include_str!("profanity/en.txt"),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => include_str!("profanity/en.txt"),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => include_str!("profanity/en.txt"),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => include_str!("profanity/en.txt"),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => include_str!("profanity/en.txt"),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => include_str!("profanity/en.txt"),
+    }
+}
+
+/// Returns the banned words of the given language, in lowercase.
+pub(crate) fn banned(lang: Lang) -> impl Iterator<Item = &'static str> {
+    list(lang).lines().filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// The banned words of a language, for filtering its lists.
+pub(crate) struct Profanity(AHashSet<&'static str>);
+
+impl Profanity {
+    pub(crate) fn of(lang: Lang) -> Self {
+        Self(banned(lang).collect())
+    }
+
+    /// Returns whether `entry` has a banned word, matched case-insensitively
+    /// against each of its space-separated parts, so that phrases, Japanese
+    /// readings in brackets and both forms of a Chinese entry are checked.
+    pub(crate) fn matches(&self, entry: &str) -> bool {
+        entry.split(' ').any(|part| {
+            let part = part.trim_matches(['[', ']']);
+            if part.chars().any(char::is_uppercase) {
+                self.0.contains(part.to_lowercase().as_str())
+            } else {
+                self.0.contains(part)
+            }
+        })
+    }
+}
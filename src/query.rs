@@ -0,0 +1,580 @@
+use crate::{
+    Blocklist, CaseSensitivity, CharClass, JaScript, Lang, LengthUnit, Tier, WordSource, all_common, all_ends_with_str,
+    all_hsk, all_ja_script, all_lemmas, all_starts_with_case, all_starts_with_str, containing, fits_pattern, hsk_level,
+    is_lemma, len_buckets_in, pool_entropy, transcription, words,
+};
+use rand::{Rng, prelude::IndexedRandom};
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+/// The widest length range looked up one length at a time in a source
+/// without an embedded language.
+const MAX_LEN_LOOKUPS: usize = 64;
+
+/// A combination of filters over the words of one language, or of any other
+/// [`WordSource`] such as a [`Dictionary`](crate::Dictionary).
+///
+/// Constraints are combined with a logical AND. The narrowest available
+/// index is used to produce candidates, which are then checked against the
+/// remaining constraints.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, WordQuery};
+/// let words = WordQuery::new(Lang::En)
+///     .len(4..=8)
+///     .starts_with('c')
+///     .ends_with('t')
+///     .all();
+/// assert!(words.contains(&"carrot"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordQuery {
+    source: Arc<dyn WordSource>,
+    len: Option<RangeInclusive<usize>>,
+    len_unit: LengthUnit,
+    max_bytes: Option<usize>,
+    starts_with: Option<char>,
+    prefix: Option<String>,
+    ends_with: Option<char>,
+    suffix: Option<String>,
+    case: CaseSensitivity,
+    containing: Option<String>,
+    pattern: Option<String>,
+    excluding: String,
+    char_class: Option<CharClass>,
+    ja_script: Option<JaScript>,
+    commonness: Option<Tier>,
+    blocklists: Vec<Blocklist>,
+    transcription_safe: bool,
+    lemmas_only: bool,
+    hsk: Option<RangeInclusive<u8>>,
+    #[cfg(feature = "regex")]
+    regex: Option<regex::Regex>,
+}
+
+impl WordQuery {
+    /// Creates a query matching every word of the given language or other
+    /// source.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Dictionary, WordQuery};
+    /// let dictionary = Dictionary::new(["kiwi", "lime", "melon", "peach"]);
+    /// let words = WordQuery::new(dictionary).len(4..=4).ends_with('e').all();
+    /// assert_eq!(words, ["lime"]);
+    /// ```
+    #[inline(always)]
+    pub fn new(source: impl WordSource + 'static) -> Self {
+        Self {
+            source: Arc::new(source),
+            len: None,
+            len_unit: LengthUnit::Chars,
+            max_bytes: None,
+            starts_with: None,
+            prefix: None,
+            ends_with: None,
+            suffix: None,
+            case: CaseSensitivity::Sensitive,
+            containing: None,
+            pattern: None,
+            excluding: String::new(),
+            char_class: None,
+            ja_script: None,
+            commonness: None,
+            blocklists: Vec::new(),
+            transcription_safe: false,
+            lemmas_only: false,
+            hsk: None,
+            #[cfg(feature = "regex")]
+            regex: None,
+        }
+    }
+
+    /// Restricts matches to words whose length is within `range`, measured
+    /// in characters unless changed with [`len_unit`](Self::len_unit).
+    #[inline(always)]
+    pub fn len(mut self, range: RangeInclusive<usize>) -> Self {
+        self.len = Some(range);
+        self
+    }
+
+    /// Sets the unit in which [`len`](Self::len) measures words.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, LengthUnit, WordQuery};
+    /// let words = WordQuery::new(Lang::En)
+    ///     .len(4..=4)
+    ///     .len_unit(LengthUnit::Bytes)
+    ///     .all();
+    /// assert!(words.iter().all(|w| w.len() == 4));
+    /// ```
+    #[inline(always)]
+    pub fn len_unit(mut self, unit: LengthUnit) -> Self {
+        self.len_unit = unit;
+        self
+    }
+
+    /// Restricts matches to words whose UTF-8 encoding is at most `max` bytes.
+    #[inline(always)]
+    pub fn max_bytes(mut self, max: usize) -> Self {
+        self.max_bytes = Some(max);
+        self
+    }
+
+    /// Restricts matches to words starting with the given character.
+    #[inline(always)]
+    pub fn starts_with(mut self, char: char) -> Self {
+        self.starts_with = Some(char);
+        self
+    }
+
+    /// Restricts matches to words starting with the given prefix. The prefix
+    /// is always matched case-sensitively.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).prefix("pre").len(6..=6).all();
+    /// assert!(words.contains(&"preach"));
+    /// ```
+    #[inline(always)]
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_owned());
+        self
+    }
+
+    /// Restricts matches to words ending with the given character.
+    #[inline(always)]
+    pub fn ends_with(mut self, char: char) -> Self {
+        self.ends_with = Some(char);
+        self
+    }
+
+    /// Restricts matches to words ending with the given suffix. The suffix
+    /// is always matched case-sensitively.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).suffix("ness").len(8..=8).all();
+    /// assert!(words.contains(&"goodness"));
+    /// ```
+    #[inline(always)]
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_owned());
+        self
+    }
+
+    /// Sets whether [`starts_with`](Self::starts_with) and
+    /// [`ends_with`](Self::ends_with) distinguish upper and lower case.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{CaseSensitivity, Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En)
+    ///     .starts_with('Q')
+    ///     .case(CaseSensitivity::Insensitive)
+    ///     .all();
+    /// assert!(!words.is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn case(mut self, case: CaseSensitivity) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Restricts matches to words containing the given substring.
+    #[inline(always)]
+    pub fn containing(mut self, pattern: &str) -> Self {
+        self.containing = Some(pattern.to_owned());
+        self
+    }
+
+    /// Restricts matches to words fitting `pattern`, where `_` matches any
+    /// single character.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).pattern("h_r_e").all();
+    /// assert!(words.contains(&"horse"));
+    /// ```
+    #[inline(always)]
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(pattern.to_owned());
+        self
+    }
+
+    /// Excludes words containing any character of `chars`. Repeated calls
+    /// accumulate.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En)
+    ///     .len(5..=5)
+    ///     .excluding(random_word::AMBIGUOUS_CHARS)
+    ///     .all();
+    /// assert!(words.iter().all(|w| !w.contains('l')));
+    /// ```
+    #[inline(always)]
+    pub fn excluding(mut self, chars: &str) -> Self {
+        self.excluding.push_str(chars);
+        self
+    }
+
+    /// Restricts matches to words made only of characters in `class`.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{CharClass, Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).char_class(CharClass::Ascii).len(3..=3).all();
+    /// assert!(words.iter().all(|w| w.is_ascii()));
+    /// ```
+    #[inline(always)]
+    pub fn char_class(mut self, class: CharClass) -> Self {
+        self.char_class = Some(class);
+        self
+    }
+
+    /// Restricts matches to Japanese words whose written form is in
+    /// `script`; see [`JaScript::matches`]. Words of other sources, such as
+    /// a [`Dictionary`](crate::Dictionary) of Japanese words, are checked
+    /// character by character the same way.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{JaScript, Lang, WordQuery};
+    /// if let Ok(lang) = "ja".parse::<Lang>() {
+    ///     let words = WordQuery::new(lang).ja_script(JaScript::Katakana).len(3..=3).all();
+    ///     assert!(words.contains(&"テレビ"));
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn ja_script(mut self, script: JaScript) -> Self {
+        self.ja_script = Some(script);
+        self
+    }
+
+    /// Restricts matches to the most common words of the language. Languages
+    /// without frequency data, and sources other than a [`Lang`], have no
+    /// common words.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, Tier, WordQuery};
+    /// let words = WordQuery::new(Lang::En).commonness(Tier::Top5k).len(5..=5).all();
+    /// assert!(words.contains(&"house"));
+    /// assert!(!words.contains(&"zymic"));
+    /// ```
+    #[inline(always)]
+    pub fn commonness(mut self, tier: Tier) -> Self {
+        self.commonness = Some(tier);
+        self
+    }
+
+    /// Excludes words in `blocklist`. Repeated calls accumulate.
+    #[inline(always)]
+    pub fn blocklist(mut self, blocklist: &Blocklist) -> Self {
+        self.blocklists.push(blocklist.clone());
+        self
+    }
+
+    /// Excludes words easily confused with another word when dictated over
+    /// the phone or handwritten: curated homophones such as "their" and
+    /// "there", and words one look-alike letter sequence from another, such
+    /// as "modern" and "modem". See [`is_transcription_safe`](crate::is_transcription_safe).
+    /// Sources other than a [`Lang`] have no homophone data, so only
+    /// look-alikes are excluded.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).len(4..=5).transcription_safe().all();
+    /// assert!(words.contains(&"zebra"));
+    /// assert!(!words.contains(&"wear") && !words.contains(&"where"));
+    /// ```
+    #[inline(always)]
+    pub fn transcription_safe(mut self) -> Self {
+        self.transcription_safe = true;
+        self
+    }
+
+    /// Restricts matches to base forms, without plurals and conjugations;
+    /// see [`all_lemmas`](crate::all_lemmas). Languages without lemma data,
+    /// and sources other than a [`Lang`], have no base forms.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).prefix("walk").lemmas_only().all();
+    /// assert!(words.contains(&"walk"));
+    /// assert!(!words.contains(&"walked") && !words.contains(&"walks"));
+    /// ```
+    #[inline(always)]
+    pub fn lemmas_only(mut self) -> Self {
+        self.lemmas_only = true;
+        self
+    }
+
+    /// Restricts matches to words whose HSK level is within `levels`; see
+    /// [`hsk_level`](crate::hsk_level). Only Chinese ships HSK data, so
+    /// other languages and sources other than a [`Lang`] have no matches.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, LengthUnit, WordQuery};
+    /// if let Ok(lang) = "zh".parse::<Lang>() {
+    ///     let words = WordQuery::new(lang).hsk(1..=2).len(1..=1).len_unit(LengthUnit::Headword).all();
+    ///     assert!(words.contains(&"愛 爱"));
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn hsk(mut self, levels: RangeInclusive<u8>) -> Self {
+        self.hsk = Some(levels);
+        self
+    }
+
+    /// Restricts matches to words matching the given regular expression
+    /// (feature = "regex").
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// use regex::Regex;
+    /// let words = WordQuery::new(Lang::En)
+    ///     .starts_with('c')
+    ///     .matching(Regex::new("^c[aeiou]t$").unwrap())
+    ///     .all();
+    /// assert!(words.contains(&"cut"));
+    /// ```
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    pub fn matching(mut self, regex: regex::Regex) -> Self {
+        self.regex = Some(regex);
+        self
+    }
+
+    /// Returns the language this query searches, or `None` if its source
+    /// isn't an embedded language.
+    #[inline(always)]
+    pub fn lang(&self) -> Option<Lang> {
+        self.source.lang()
+    }
+
+    /// Returns the source of the words this query searches.
+    #[inline(always)]
+    pub fn source(&self) -> &dyn WordSource {
+        &*self.source
+    }
+
+    /// Returns every matching word, once each.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let words = WordQuery::new(Lang::En).len(3..=3).starts_with('z').all();
+    /// assert!(words.iter().all(|w| w.len() == 3 && w.starts_with('z')));
+    /// ```
+    pub fn all(&self) -> Vec<&'static str> {
+        // Trigram lookups are far more selective than any single-character
+        // or length bucket, so they win whenever they apply.
+        if let Some(pattern) = &self.containing
+            && pattern.chars().count() >= 3
+            && let Some(lang) = self.source.lang()
+        {
+            let mut words: Vec<&'static str> =
+                containing(pattern, lang).into_iter().filter(|word| self.matches(word)).collect();
+            words.dedup();
+            return words;
+        }
+        let mut words: Vec<&'static str> = self
+            .buckets()
+            .iter()
+            .flat_map(|bucket| bucket.iter().copied())
+            .filter(|word| self.matches(word))
+            .collect();
+        // Some lists repeat entries. Buckets keep the order of the list and
+        // no word is in two of them, so repeats are adjacent.
+        words.dedup();
+        words
+    }
+
+    /// Returns a random matching word using the supplied RNG.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let word = WordQuery::new(Lang::En)
+    ///     .len(5..=5)
+    ///     .starts_with('c')
+    ///     .one(&mut rand::rng());
+    /// assert!(word.is_some());
+    /// ```
+    pub fn one(&self, rng: &mut impl Rng) -> Option<&'static str> {
+        self.all().choose(rng).copied()
+    }
+
+    /// Returns the entropy in bits of one word drawn uniformly from the
+    /// matching words, as by [`one`](Self::one). Every constraint shrinks
+    /// the pool and so lowers the entropy.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Lang, WordQuery};
+    /// let query = WordQuery::new(Lang::En).len(4..=6);
+    /// assert!(query.entropy_bits() < random_word::entropy_bits(Lang::En));
+    /// assert_eq!(query.entropy_bits(), (query.all().len() as f64).log2());
+    /// ```
+    pub fn entropy_bits(&self) -> f64 {
+        pool_entropy(self.all().len())
+    }
+
+    /// Returns whether `word` satisfies every constraint of this query.
+    ///
+    /// This does not check that `word` belongs to the query's source.
+    pub fn matches(&self, word: &str) -> bool {
+        self.len.as_ref().is_none_or(|range| range.contains(&self.len_unit.measure(word)))
+            && self.max_bytes.is_none_or(|max| word.len() <= max)
+            && self.starts_with.is_none_or(|first| {
+                word.chars().next().is_some_and(|c| self.case.eq(c, first))
+            })
+            && self.prefix.as_ref().is_none_or(|prefix| word.starts_with(prefix.as_str()))
+            && self.ends_with.is_none_or(|last| {
+                word.chars().next_back().is_some_and(|c| self.case.eq(c, last))
+            })
+            && self.suffix.as_ref().is_none_or(|suffix| word.ends_with(suffix.as_str()))
+            && self.containing.as_ref().is_none_or(|pattern| word.contains(pattern.as_str()))
+            && self.pattern.as_ref().is_none_or(|pattern| fits_pattern(word, pattern))
+            && !word.contains(|c| self.excluding.contains(c))
+            && self.char_class.is_none_or(|class| class.matches(word))
+            && self.ja_script.is_none_or(|script| script.matches(word))
+            && self.commonness.is_none_or(|tier| {
+                self.source.lang().is_some_and(|lang| tier.contains(word, lang))
+            })
+            && !self.blocklists.iter().any(|blocklist| blocklist.contains(word))
+            && !(self.transcription_safe && self.is_confusable(word))
+            && (!self.lemmas_only || self.is_lemma(word))
+            && self.hsk.as_ref().is_none_or(|levels| {
+                self.source.lang().and_then(|lang| hsk_level(word, lang)).is_some_and(|level| levels.contains(&level))
+            })
+            && self.matches_regex(word)
+    }
+
+    fn is_confusable(&self, word: &str) -> bool {
+        match self.source.lang() {
+            Some(lang) => words::confusable(lang).contains(word),
+            None => transcription::lookalikes(word, self.source.words()).next().is_some(),
+        }
+    }
+
+    fn is_lemma(&self, word: &str) -> bool {
+        self.source.lang().and_then(|lang| is_lemma(word, lang)).unwrap_or(false)
+    }
+
+    #[cfg(feature = "regex")]
+    #[inline(always)]
+    fn matches_regex(&self, word: &str) -> bool {
+        self.regex.as_ref().is_none_or(|regex| regex.is_match(word))
+    }
+
+    #[cfg(not(feature = "regex"))]
+    #[inline(always)]
+    fn matches_regex(&self, _word: &str) -> bool {
+        true
+    }
+
+    /// Returns the smallest set of candidate buckets produced by an index.
+    fn buckets(&self) -> Vec<&[&'static str]> {
+        let Some(lang) = self.source.lang() else {
+            return self.source_buckets();
+        };
+        let mut best: Vec<&[&'static str]> = vec![words::get(lang)];
+        let mut best_len = best[0].len();
+        let mut consider = |buckets: Vec<&'static [&'static str]>| {
+            let len = buckets.iter().map(|bucket| bucket.len()).sum();
+            if len < best_len {
+                best_len = len;
+                best = buckets;
+            }
+        };
+
+        if let Some(first) = self.starts_with {
+            consider(all_starts_with_case(first, self.case, lang).into_iter().collect());
+        }
+        if let Some(prefix) = &self.prefix {
+            consider(all_starts_with_str(prefix, lang).into_iter().collect());
+        }
+        // There is no case-folded suffix index, so only exact suffixes narrow
+        // the candidates.
+        if let Some(last) = self.ends_with
+            && self.case == CaseSensitivity::Sensitive
+        {
+            consider(words::get_ends_with(last, lang).map(|b| &**b).into_iter().collect());
+        }
+        if let Some(suffix) = &self.suffix {
+            consider(all_ends_with_str(suffix, lang).into_iter().collect());
+        }
+        if let Some(range) = &self.len {
+            consider(len_buckets_in(range, self.len_unit, lang));
+        }
+        if let Some(pattern) = &self.pattern {
+            consider(words::get_len(pattern.chars().count(), lang).map(|b| &**b).into_iter().collect());
+        }
+        if let Some(max) = self.max_bytes {
+            consider(len_buckets_in(&(0..=max), LengthUnit::Bytes, lang));
+        }
+        if let Some(tier) = self.commonness {
+            consider(vec![all_common(tier, lang).unwrap_or_default()]);
+        }
+        if self.lemmas_only {
+            consider(vec![all_lemmas(lang).unwrap_or_default()]);
+        }
+        if let Some(script) = self.ja_script
+            && let Some(words) = all_ja_script(script, lang)
+        {
+            consider(vec![words]);
+        }
+        if let Some(levels) = &self.hsk {
+            consider(levels.clone().filter_map(|level| all_hsk(level, lang)).collect());
+        }
+        best
+    }
+    /// Returns the smallest set of candidate buckets produced by the
+    /// length and first-character indexes every source has.
+    fn source_buckets<'a>(&'a self) -> Vec<&'a [&'static str]> {
+        let source = &*self.source;
+        let mut best = vec![source.words()];
+        let mut best_len = best[0].len();
+        let mut consider = |buckets: Vec<&'a [&'static str]>| {
+            let len = buckets.iter().map(|bucket| bucket.len()).sum();
+            if len < best_len {
+                best_len = len;
+                best = buckets;
+            }
+        };
+
+        if let Some(first) = self.starts_with
+            && self.case == CaseSensitivity::Sensitive
+        {
+            consider(source.all_starts_with(first).into_iter().collect());
+        }
+        if let Some(prefix) = &self.prefix {
+            consider(vec![words::prefix_range(source.words(), prefix)]);
+        }
+        // Without a list of the lengths present, only short ranges are worth
+        // looking up one length at a time.
+        if let Some(range) = &self.len
+            && self.len_unit == LengthUnit::Chars
+            && range.end().saturating_sub(*range.start()) < MAX_LEN_LOOKUPS
+        {
+            consider(range.clone().filter_map(|len| source.all_len(len)).collect());
+        }
+        if let Some(pattern) = &self.pattern {
+            consider(source.all_len(pattern.chars().count()).into_iter().collect());
+        }
+        best
+    }
+}
@@ -0,0 +1,289 @@
+use crate::Lang;
+#[cfg(any(feature = "ja", feature = "ru", feature = "zh"))]
+use crate::words::Words;
+#[cfg(feature = "zh")]
+use ahash::AHashMap;
+#[cfg(any(feature = "ja", feature = "ru", feature = "zh"))]
+use std::sync::OnceLock;
+
+/// The script words are written in by [`romanize`](crate::romanize) and
+/// [`all_script`](crate::all_script).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Script {
+    /// The words as listed.
+    #[default]
+    Native,
+    /// The Latin alphabet with diacritics: Hepburn romaji with macrons for
+    /// the long vowels written "ー", Hanyu Pinyin with tone marks, and
+    /// ISO 9 for Russian, as in "kōhī", "zhōngwén" and "žurnal".
+    Latin,
+    /// Plain ASCII letters, as typed on any keyboard: romaji with long
+    /// vowels doubled, Pinyin without tone marks and with "v" for "ü", and
+    /// BGN/PCGN digraphs for Russian, as in "koohii", "zhongwen" and
+    /// "zhurnal". Apostrophes between syllables are dropped.
+    Ascii,
+}
+
+#[cfg(feature = "ja")]
+static JA_TEXT: [OnceLock<String>; 2] = [const { OnceLock::new() }; 2];
+#[cfg(feature = "ja")]
+static JA: [OnceLock<Words>; 2] = [const { OnceLock::new() }; 2];
+#[cfg(feature = "ru")]
+static RU_TEXT: [OnceLock<String>; 2] = [const { OnceLock::new() }; 2];
+#[cfg(feature = "ru")]
+static RU: [OnceLock<Words>; 2] = [const { OnceLock::new() }; 2];
+#[cfg(feature = "zh")]
+static ZH_TEXT: [OnceLock<String>; 2] = [const { OnceLock::new() }; 2];
+#[cfg(feature = "zh")]
+static ZH: [OnceLock<Words>; 2] = [const { OnceLock::new() }; 2];
+
+/// The reading of each character of the Chinese list, with tone marks.
+#[cfg(feature = "zh")]
+static PINYIN_RAW: &[u8] = include_compressed!("romanization/zh");
+#[cfg(feature = "zh")]
+static PINYIN_TEXT: OnceLock<String> = OnceLock::new();
+#[cfg(feature = "zh")]
+static PINYIN: OnceLock<AHashMap<char, &'static str>> = OnceLock::new();
+
+/// Returns the distinct romanizations of the given language's words in
+/// `script`, sorted byte-wise, or `None` if the language has none.
+///
+/// Words that can't be romanized, such as lone punctuation, are left out.
+/// So are repeats: homophones such as "橋 [はし]" and "箸 [はし]" share one
+/// entry, so that a word drawn from the list carries the entropy its length
+/// suggests.
+#[cfg_attr(not(any(feature = "ja", feature = "ru", feature = "zh")), allow(unused_variables))]
+pub(crate) fn all(script: Script, lang: Lang) -> Option<&'static [&'static str]> {
+    #[cfg(any(feature = "ja", feature = "ru", feature = "zh"))]
+    let build = |text: &'static [OnceLock<String>; 2], words: &'static [OnceLock<Words>; 2]| {
+        let i = (script == Script::Ascii) as usize;
+        let text = text[i].get_or_init(|| {
+            let mut romanized: Vec<String> =
+                crate::all(lang).iter().filter_map(|word| romanize(word, script, lang)).collect();
+            romanized.sort_unstable();
+            romanized.dedup();
+            romanized.join("\n")
+        });
+        Some(&**words[i].get_or_init(|| text.lines().collect()))
+    };
+    match (script, lang) {
+        (Script::Native, _) => Some(crate::all(lang)),
+        #[cfg(feature = "ja")]
+        (_, Lang::Ja) => build(&JA_TEXT, &JA),
+        #[cfg(feature = "ru")]
+        (_, Lang::Ru) => build(&RU_TEXT, &RU),
+        #[cfg(feature = "zh")]
+        (_, Lang::Zh) => build(&ZH_TEXT, &ZH),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Returns `word` of the given language written in `script`, or `None` if
+/// the language has no romanization or `word` has a character it can't
+/// write.
+#[cfg_attr(not(any(feature = "ja", feature = "ru", feature = "zh")), allow(unused_variables))]
+pub(crate) fn romanize(word: &str, script: Script, lang: Lang) -> Option<String> {
+    let ascii = match script {
+        Script::Native => return Some(word.to_owned()),
+        Script::Latin => false,
+        Script::Ascii => true,
+    };
+    match lang {
+        #[cfg(feature = "ja")]
+        Lang::Ja => japanese(word, ascii),
+        #[cfg(feature = "ru")]
+        Lang::Ru => russian(word, ascii),
+        #[cfg(feature = "zh")]
+        Lang::Zh => chinese(word, ascii),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Returns the Hepburn romaji of the reading of a Japanese entry, such as
+/// "hirou" for "拾う [ひろう]".
+///
+/// Long vowels written "ー" take a macron, or are doubled with `ascii`;
+/// those spelled out in kana, as in "とうきょう", are romanized kana by
+/// kana, as "toukyou". A syllabic "n" before a vowel or "y" is followed by
+/// an apostrophe, which `ascii` drops.
+#[cfg(feature = "ja")]
+fn japanese(word: &str, ascii: bool) -> Option<String> {
+    let reading = match word.split_once(" [") {
+        Some((_, reading)) => reading.strip_suffix(']')?,
+        None => word,
+    };
+    let kana: Vec<char> = reading.chars().filter(|&c| c != '・').collect();
+    let mut romaji = String::new();
+    // Set after "っ", which doubles the next consonant.
+    let mut geminate = false;
+    let mut syllabic_n = false;
+    let mut i = 0;
+    while i < kana.len() {
+        let (mut syllable, mut used) = (kana_romaji(kana[i])?.to_owned(), 1);
+        // Small kana after a syllable replace its vowel: "きゃ", "ティ".
+        if let Some(small) = kana.get(i + 1).and_then(|&next| small_romaji(next))
+            && syllable.ends_with(['a', 'i', 'u', 'e', 'o'])
+        {
+            let stem = syllable.trim_end_matches(['a', 'i', 'u', 'e', 'o']);
+            let stem = match (stem, syllable.as_str()) {
+                ("", "u") => "w",
+                ("", "i") => "y",
+                _ => stem,
+            };
+            syllable = if small.starts_with('y') && !matches!(stem, "sh" | "ch" | "j") {
+                format!("{stem}{small}")
+            } else {
+                format!("{stem}{}", small.trim_start_matches('y'))
+            };
+            used = 2;
+        }
+        match syllable.as_str() {
+            "ー" => {
+                let vowel = romaji.pop().filter(|&c| "aiueo".contains(c))?;
+                if ascii {
+                    romaji.extend([vowel, vowel]);
+                } else {
+                    romaji.push(['ā', 'ī', 'ū', 'ē', 'ō']["aiueo".find(vowel)?]);
+                }
+            }
+            "っ" => geminate = true,
+            _ => {
+                if std::mem::take(&mut geminate) {
+                    match syllable.as_bytes()[0] {
+                        b'c' => romaji.push('t'),
+                        c if !b"aiueo".contains(&c) => romaji.push(c as char),
+                        _ => {}
+                    }
+                }
+                if syllabic_n && !ascii && syllable.starts_with(['a', 'i', 'u', 'e', 'o', 'y']) {
+                    romaji.push('\'');
+                }
+                romaji.push_str(&syllable);
+            }
+        }
+        syllabic_n = matches!(kana[i], 'ん' | 'ン');
+        i += used;
+    }
+    (!romaji.is_empty()).then_some(romaji)
+}
+
+/// Returns the romaji of a full-size kana, in hiragana or katakana, or
+/// "ー" and "っ" for the marks [`japanese`] handles itself.
+#[cfg(feature = "ja")]
+fn kana_romaji(c: char) -> Option<&'static str> {
+    const HIRAGANA: [&str; 86] = [
+        "a", "a", "i", "i", "u", "u", "e", "e", "o", "o", "ka", "ga", "ki", "gi", "ku", "gu", "ke", "ge", "ko", "go",
+        "sa", "za", "shi", "ji", "su", "zu", "se", "ze", "so", "zo", "ta", "da", "chi", "ji", "っ", "tsu", "zu", "te",
+        "de", "to", "do", "na", "ni", "nu", "ne", "no", "ha", "ba", "pa", "hi", "bi", "pi", "fu", "bu", "pu", "he",
+        "be", "pe", "ho", "bo", "po", "ma", "mi", "mu", "me", "mo", "ya", "ya", "yu", "yu", "yo", "yo", "ra", "ri",
+        "ru", "re", "ro", "wa", "wa", "i", "e", "o", "n", "vu", "ka", "ke",
+    ];
+    match c {
+        'ー' => Some("ー"),
+        'ヷ' => Some("va"),
+        'ヸ' => Some("vi"),
+        'ヹ' => Some("ve"),
+        'ヺ' => Some("vo"),
+        'ァ'..='ヶ' => kana_romaji(char::from_u32(c as u32 - 0x60)?),
+        'ぁ'..='ゖ' => Some(HIRAGANA[(c as u32 - 'ぁ' as u32) as usize]),
+        _ => None,
+    }
+}
+
+/// Returns the romaji of a small kana that joins the syllable before it.
+#[cfg(feature = "ja")]
+fn small_romaji(c: char) -> Option<&'static str> {
+    match c {
+        'ぁ' | 'ァ' => Some("a"),
+        'ぃ' | 'ィ' => Some("i"),
+        'ぅ' | 'ゥ' => Some("u"),
+        'ぇ' | 'ェ' => Some("e"),
+        'ぉ' | 'ォ' => Some("o"),
+        'ゃ' | 'ャ' => Some("ya"),
+        'ゅ' | 'ュ' => Some("yu"),
+        'ょ' | 'ョ' => Some("yo"),
+        'ゎ' | 'ヮ' => Some("wa"),
+        _ => None,
+    }
+}
+
+/// Returns the transliteration of a Russian word: ISO 9, or BGN/PCGN
+/// without its apostrophes with `ascii`, so "щи" is "ŝi" or "shchi".
+#[cfg(feature = "ru")]
+fn russian(word: &str, ascii: bool) -> Option<String> {
+    const LETTERS: &str = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя";
+    const ISO_9: [&str; 33] = [
+        "a", "b", "v", "g", "d", "e", "ë", "ž", "z", "i", "j", "k", "l", "m", "n", "o", "p", "r", "s", "t", "u", "f", "h",
+        "c", "č", "š", "ŝ", "ʺ", "y", "ʹ", "è", "û", "â",
+    ];
+    const BGN: [&str; 33] = [
+        "a", "b", "v", "g", "d", "e", "yo", "zh", "z", "i", "y", "k", "l", "m", "n", "o", "p", "r", "s", "t", "u", "f",
+        "kh", "ts", "ch", "sh", "shch", "", "y", "", "e", "yu", "ya",
+    ];
+    let table = if ascii { &BGN } else { &ISO_9 };
+    let mut latin = String::with_capacity(word.len());
+    for c in word.chars() {
+        match LETTERS.chars().position(|letter| letter == c) {
+            Some(i) => latin.push_str(table[i]),
+            None if c.is_ascii() => latin.push(c),
+            None => return None,
+        }
+    }
+    Some(latin)
+}
+
+/// Returns the Pinyin of a Chinese entry, read from its traditional form,
+/// such as "zhōngwén" for "中文 中文".
+///
+/// Each character takes its most common reading, so a word with a
+/// character read differently in it, such as the "háng" of "银行", comes
+/// out wrong. A syllable starting with "a", "e" or "o" is set off with an
+/// apostrophe, as in "xī'ān", which `ascii` drops along with the tone
+/// marks.
+#[cfg(feature = "zh")]
+fn chinese(word: &str, ascii: bool) -> Option<String> {
+    let pinyin = PINYIN.get_or_init(|| {
+        let text = PINYIN_TEXT.get_or_init(|| crate::words::decompress(PINYIN_RAW));
+        text.lines()
+            .filter_map(|line| line.split_once(' '))
+            .filter_map(|(c, reading)| Some((c.parse().ok()?, reading)))
+            .collect()
+    });
+    let traditional = word.split(' ').next()?;
+    let mut latin = String::new();
+    for c in traditional.chars().filter(|&c| c != '·') {
+        if c.is_ascii_alphanumeric() {
+            latin.push(c);
+            continue;
+        }
+        let reading = pinyin.get(&c)?;
+        let initial = reading.chars().next().map(toneless);
+        if !latin.is_empty() && !ascii && matches!(initial, Some('a' | 'e' | 'o')) {
+            latin.push('\'');
+        }
+        if ascii {
+            latin.extend(reading.chars().map(toneless));
+        } else {
+            latin.push_str(reading);
+        }
+    }
+    (!latin.is_empty()).then_some(latin)
+}
+
+/// Returns a Pinyin letter without its tone mark, and "ü" as "v".
+#[cfg(feature = "zh")]
+fn toneless(c: char) -> char {
+    match c {
+        'ā' | 'á' | 'ǎ' | 'à' => 'a',
+        'ē' | 'é' | 'ě' | 'è' | 'ê' | 'ế' | 'ề' => 'e',
+        'ī' | 'í' | 'ǐ' | 'ì' => 'i',
+        'ō' | 'ó' | 'ǒ' | 'ò' => 'o',
+        'ū' | 'ú' | 'ǔ' | 'ù' => 'u',
+        'ü' | 'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' => 'v',
+        'ń' | 'ň' | 'ǹ' => 'n',
+        'ḿ' => 'm',
+        _ => c,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{Lang, get_len_with_rng, get_starts_with_rng, get_with_rng, poem};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+/// A random word generator that produces a reproducible sequence.
+///
+/// Created with [`seeded`](crate::seeded). Two generators built from the
+/// same seed return the same words in the same order, across runs and
+/// platforms, for the same [`Lang::dataset_version`] and crate features:
+/// words are picked by position, so a new list, a change to its order or
+/// the `clean` feature changes them. Cloning a generator forks the sequence
+/// at its current position.
+#[derive(Clone, Debug)]
+pub struct Seeded {
+    rng: ChaCha12Rng,
+}
+
+impl Seeded {
+    #[inline(always)]
+    pub(crate) fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the next word with the given language.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Lang;
+    /// let mut a = random_word::seeded(42);
+    /// let mut b = random_word::seeded(42);
+    /// assert_eq!(a.get(Lang::En), b.get(Lang::En));
+    /// ```
+    #[inline(always)]
+    pub fn get(&mut self, lang: Lang) -> &'static str {
+        get_with_rng(lang, &mut self.rng)
+    }
+
+    /// Returns the next word with the given length and language.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Lang;
+    /// let mut a = random_word::seeded(42);
+    /// let mut b = random_word::seeded(42);
+    /// assert_eq!(a.get_len(4, Lang::En), b.get_len(4, Lang::En));
+    /// ```
+    #[inline(always)]
+    pub fn get_len(&mut self, len: usize, lang: Lang) -> Option<&'static str> {
+        get_len_with_rng(len, lang, &mut self.rng)
+    }
+
+    /// Returns the next word with the given starting character and language.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Lang;
+    /// let mut a = random_word::seeded(42);
+    /// let mut b = random_word::seeded(42);
+    /// assert_eq!(a.get_starts_with('c', Lang::En), b.get_starts_with('c', Lang::En));
+    /// ```
+    #[inline(always)]
+    pub fn get_starts_with(&mut self, char: char, lang: Lang) -> Option<&'static str> {
+        get_starts_with_rng(char, lang, &mut self.rng)
+    }
+
+    /// Returns the next haiku with the given language, as from
+    /// [`poem::haiku`].
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::Lang;
+    /// let mut a = random_word::seeded(42);
+    /// let mut b = random_word::seeded(42);
+    /// assert_eq!(a.haiku(Lang::En), b.haiku(Lang::En));
+    /// ```
+    #[inline(always)]
+    pub fn haiku(&mut self, lang: Lang) -> Option<[String; 3]> {
+        poem::haiku_rng(lang, &mut self.rng)
+    }
+}
@@ -0,0 +1,148 @@
+//! Simple grammatical sentences, such as "The quick horse staples
+//! batteries.", for test data, CAPTCHAs and memorable secrets.
+//!
+//! Sentences follow a small grammar of determiners, adjectives, nouns,
+//! verbs and adverbs filled from the part-of-speech lists. Plural nouns and
+//! third-person verbs are formed with regular English spelling rules and
+//! kept only if the inflected word is in the wordlist, so irregular forms
+//! like "mouses" never appear. Only English has part-of-speech data.
+//!
+//! # Example
+//! ```
+//! use random_word::{Lang, sentence};
+//!
+//! let sentence = sentence::generate(Lang::En).unwrap();
+//! assert!(sentence.starts_with(|c: char| c.is_ascii_uppercase()));
+//! assert!(sentence.ends_with('.'));
+//! ```
+
+use crate::{Lang, Pos, contains, default_rng, pos};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+/// A slot in a sentence pattern.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Token {
+    /// "the" or "a", agreeing with the next word.
+    Det,
+    Adj,
+    Noun,
+    /// A plural noun.
+    Nouns,
+    /// A verb agreeing with a plural subject.
+    Verb,
+    /// A verb agreeing with a singular subject.
+    Verbs,
+    Adverb,
+}
+
+use Token::*;
+
+/// The sentence patterns of the grammar, as subject, verb and object.
+const PATTERNS: &[&[Token]] = &[
+    &[Det, Adj, Noun, Verbs, Nouns],
+    &[Det, Noun, Verbs, Adj, Nouns],
+    &[Det, Adj, Noun, Verbs, Det, Noun],
+    &[Det, Noun, Adverb, Verbs, Det, Adj, Noun],
+    &[Det, Noun, Verbs, Nouns, Adverb],
+    &[Adj, Nouns, Verb, Det, Noun],
+    &[Nouns, Adverb, Verb, Adj, Nouns],
+];
+
+/// How many words are drawn looking for one with the right inflection
+/// before giving up.
+const DRAWS: usize = 100;
+
+/// Generates a sentence with the given language.
+///
+/// Returns `None` if the language has no part-of-speech data.
+#[inline(always)]
+pub fn generate(lang: Lang) -> Option<String> {
+    generate_rng(lang, &mut default_rng())
+}
+
+/// Generates a sentence with the given language, using the supplied RNG.
+///
+/// Returns `None` if the language has no part-of-speech data.
+pub fn generate_rng(lang: Lang, rng: &mut impl Rng) -> Option<String> {
+    let pattern = PATTERNS.choose(rng).expect("non-empty");
+    let mut words = Vec::with_capacity(pattern.len());
+    // Articles are filled in once the following word is known.
+    for &token in pattern.iter().rev() {
+        let word = match token {
+            Det => determiner(words.last().map_or("", String::as_str), rng).to_owned(),
+            Adj => pick(Pos::Adjective, lang, rng)?.to_owned(),
+            Noun => singular(lang, rng)?.to_owned(),
+            Nouns => inflected(Pos::Noun, lang, rng)?,
+            Verb => pick(Pos::Verb, lang, rng)?.to_owned(),
+            Verbs => inflected(Pos::Verb, lang, rng)?,
+            Adverb => pick(Pos::Adverb, lang, rng)?.to_owned(),
+        };
+        words.push(word);
+    }
+    words.reverse();
+    let mut sentence = words.join(" ");
+    if let Some(first) = sentence.chars().next() {
+        sentence.replace_range(..first.len_utf8(), &first.to_uppercase().to_string());
+    }
+    sentence.push('.');
+    Some(sentence)
+}
+
+fn pick(part: Pos, lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    pos::get(part, lang)?.choose(rng).copied()
+}
+
+/// Returns a random noun that isn't the plural of another noun, since the
+/// noun list has entries like "books".
+fn singular(lang: Lang, rng: &mut impl Rng) -> Option<&'static str> {
+    (0..DRAWS).find_map(|_| {
+        let noun = pick(Pos::Noun, lang, rng)?;
+        let stems = [
+            noun.strip_suffix('s').map(str::to_owned),
+            noun.strip_suffix("es").map(str::to_owned),
+            noun.strip_suffix("ies").map(|stem| format!("{stem}y")),
+        ];
+        let plural = stems
+            .into_iter()
+            .flatten()
+            .any(|stem| add_s(&stem) == noun && Pos::Noun.contains(&stem, lang));
+        (!plural).then_some(noun)
+    })
+}
+
+/// Returns a random word with the "-s" ending of plural nouns and
+/// third-person verbs, if its inflection is in the wordlist.
+fn inflected(part: Pos, lang: Lang, rng: &mut impl Rng) -> Option<String> {
+    (0..DRAWS).find_map(|_| {
+        let word = add_s(pick(part, lang, rng)?);
+        contains(&word, lang).then_some(word)
+    })
+}
+
+/// Adds "-s" or "-es" with regular English spelling: "horses", "boxes",
+/// "batteries", "days".
+fn add_s(word: &str) -> String {
+    let consonant_y = word
+        .strip_suffix('y')
+        .and_then(|stem| stem.chars().next_back())
+        .is_some_and(|c| !"aeiou".contains(c));
+    if consonant_y {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"].iter().any(|ending| word.ends_with(ending)) {
+        format!("{word}es")
+    } else {
+        format!("{word}s")
+    }
+}
+
+/// Returns "the" or an indefinite article for `next`.
+fn determiner(next: &str, rng: &mut impl Rng) -> &'static str {
+    if rng.random() {
+        "the"
+    } else if next.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
@@ -0,0 +1,74 @@
+//! The S/KEY dictionary of RFC 2289, for one-time passwords as six words.
+//!
+//! A 64-bit value and a 2-bit checksum are spelled with six words of at
+//! most four letters each, as used by S/KEY and OPIE one-time password
+//! systems. Enabled by the `skey` feature.
+//!
+//! # Example
+//! ```
+//! use random_word::skey;
+//! let words = skey::encode(0x9E87_6134_D904_99DD);
+//! assert_eq!(words, ["INCH", "SEA", "ANNE", "LONG", "AHEM", "TOUR"]);
+//! assert_eq!(skey::decode(&words).unwrap(), 0x9E87_6134_D904_99DD);
+//! ```
+
+use crate::Error;
+use ahash::AHashMap;
+use std::sync::OnceLock;
+
+static WORDS: OnceLock<Box<[&'static str]>> = OnceLock::new();
+static INDEX: OnceLock<AHashMap<&'static str, u16>> = OnceLock::new();
+
+/// Returns the 2048 dictionary words in standard order.
+pub fn words() -> &'static [&'static str] {
+    WORDS.get_or_init(|| include_str!("skey/words.txt").lines().collect())
+}
+
+fn index() -> &'static AHashMap<&'static str, u16> {
+    INDEX.get_or_init(|| words().iter().enumerate().map(|(i, &word)| (word, i as u16)).collect())
+}
+
+/// Returns the 2-bit checksum of RFC 2289: the sum of the value's 2-bit
+/// pairs, modulo 4.
+fn checksum(value: u64) -> u64 {
+    (0..32).map(|i| value >> (i * 2) & 3).sum::<u64>() & 3
+}
+
+/// Spells `value` as six words.
+pub fn encode(value: u64) -> [&'static str; 6] {
+    let bits = u128::from(value) << 2 | u128::from(checksum(value));
+    std::array::from_fn(|i| words()[(bits >> ((5 - i) * 11) & 0x7ff) as usize])
+}
+
+/// Reads a value back from six words, ignoring case, and checks its
+/// checksum.
+///
+/// Returns [`Error::InvalidWordCount`] unless there are six words,
+/// [`Error::UnknownWord`] with the position of a word not in the
+/// dictionary, and [`Error::InvalidChecksum`] if the checksum doesn't
+/// match.
+///
+/// # Example
+/// ```
+/// use random_word::{Error, skey};
+/// assert_eq!(skey::decode(&["milt", "vary", "mast", "ok", "sees", "went"]), Ok(0xBB9E_6AE1_979D_8FF4));
+/// assert_eq!(skey::decode(&["mimi", "vary", "mast", "ok", "sees", "went"]), Err(Error::InvalidChecksum));
+/// ```
+pub fn decode<S: AsRef<str>>(words: &[S]) -> Result<u64, Error> {
+    if words.len() != 6 {
+        return Err(Error::InvalidWordCount(words.len()));
+    }
+    let index = index();
+    let mut bits = 0u128;
+    for (i, word) in words.iter().enumerate() {
+        let word = word.as_ref().to_uppercase();
+        let &n = index.get(word.as_str()).ok_or(Error::UnknownWord(i))?;
+        bits = bits << 11 | u128::from(n);
+    }
+    let value = (bits >> 2) as u64;
+    if bits as u64 & 3 == checksum(value) {
+        Ok(value)
+    } else {
+        Err(Error::InvalidChecksum)
+    }
+}
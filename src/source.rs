@@ -0,0 +1,93 @@
+use crate::{Dictionary, Lang, words};
+use std::fmt;
+
+/// A sorted list of words with length and first-character indexes, such as
+/// an embedded [`Lang`] or a [`Dictionary`] loaded at runtime.
+///
+/// [`WordQuery`](crate::WordQuery), and through it
+/// [`PassphraseOptions::filter`](crate::PassphraseOptions::filter) and
+/// [`WordDist`](crate::WordDist), accept any source.
+///
+/// # Example
+/// ```
+/// use random_word::{Dictionary, Lang, WordSource};
+///
+/// fn longest(source: &impl WordSource) -> Option<&'static str> {
+///     source.words().iter().copied().max_by_key(|word| word.chars().count())
+/// }
+///
+/// assert!(longest(&Lang::En).is_some());
+/// assert_eq!(longest(&Dictionary::new(["fig", "plum", "mango"])), Some("mango"));
+/// ```
+pub trait WordSource: fmt::Debug + Send + Sync {
+    /// Returns all words, in byte-wise order.
+    fn words(&self) -> &[&'static str];
+
+    /// Returns all words with the given length in `char`s.
+    fn all_len(&self, len: usize) -> Option<&[&'static str]>;
+
+    /// Returns all words with the given starting character.
+    fn all_starts_with(&self, char: char) -> Option<&[&'static str]>;
+
+    /// Returns whether `word` is one of the words.
+    #[inline(always)]
+    fn contains(&self, word: &str) -> bool {
+        self.words().binary_search(&word).is_ok()
+    }
+
+    /// Returns the embedded language of the source, whose frequency and
+    /// homophone data then apply, or `None` for other sources.
+    #[inline(always)]
+    fn lang(&self) -> Option<Lang> {
+        None
+    }
+}
+
+impl WordSource for Lang {
+    #[inline(always)]
+    fn words(&self) -> &[&'static str] {
+        words::get(*self)
+    }
+
+    #[inline(always)]
+    fn all_len(&self, len: usize) -> Option<&[&'static str]> {
+        crate::all_len(len, *self)
+    }
+
+    #[inline(always)]
+    fn all_starts_with(&self, char: char) -> Option<&[&'static str]> {
+        crate::all_starts_with(char, *self)
+    }
+
+    #[inline(always)]
+    fn contains(&self, word: &str) -> bool {
+        crate::contains(word, *self)
+    }
+
+    #[inline(always)]
+    fn lang(&self) -> Option<Lang> {
+        Some(*self)
+    }
+}
+
+impl WordSource for Dictionary {
+    #[inline(always)]
+    fn words(&self) -> &[&'static str] {
+        self.all()
+    }
+
+    #[inline(always)]
+    fn all_len(&self, len: usize) -> Option<&[&'static str]> {
+        Dictionary::all_len(self, len)
+    }
+
+    #[inline(always)]
+    fn all_starts_with(&self, char: char) -> Option<&[&'static str]> {
+        Dictionary::all_starts_with(self, char)
+    }
+
+    #[inline(always)]
+    fn contains(&self, word: &str) -> bool {
+        Dictionary::contains(self, word)
+    }
+}
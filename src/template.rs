@@ -0,0 +1,190 @@
+use crate::transform::{DIGITS, SYMBOLS};
+use crate::{Error, Lang, Pos, all, all_len_between, default_rng, pos};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+
+/// Lowercase ASCII letters drawn by `{letter}` placeholders.
+const LETTERS: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+    'x', 'y', 'z',
+];
+
+/// What a placeholder is replaced with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Class {
+    Word,
+    Pos(Pos),
+    Chars(&'static [char]),
+}
+
+impl Class {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "word" => Class::Word,
+            "noun" => Class::Pos(Pos::Noun),
+            "verb" => Class::Pos(Pos::Verb),
+            "adj" | "adjective" => Class::Pos(Pos::Adjective),
+            "adv" | "adverb" => Class::Pos(Pos::Adverb),
+            "digit" | "digits" => Class::Chars(DIGITS),
+            "symbol" | "symbols" => Class::Chars(SYMBOLS),
+            "letter" | "letters" => Class::Chars(LETTERS),
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Segment {
+    Literal(String),
+    Placeholder {
+        class: Class,
+        size: Option<RangeInclusive<usize>>,
+    },
+}
+
+/// A parsed pattern of literal text and placeholders, such as
+/// `"{adj}-{noun}-{4:digits}"`.
+///
+/// Each placeholder is a class in braces, optionally preceded by a size and
+/// a colon:
+///
+/// | Placeholder | Replaced with |
+/// |-------------|---------------|
+/// | `{word}` | any word |
+/// | `{noun}`, `{verb}`, `{adj}`, `{adverb}` | a word with that part of speech |
+/// | `{digits}`, `{symbols}`, `{letters}` | one digit, symbol or lowercase ASCII letter |
+///
+/// For words the size is the length in characters, and for the others the
+/// number of characters: `{5:word}` is a five-letter word, `{4:digits}`
+/// four digits. A size may be a range, as in `{3-6:noun}`. `{{` and `}}`
+/// stand for literal braces.
+///
+/// # Example
+/// ```
+/// use random_word::{Lang, Template};
+/// let template = Template::parse("{adj}-{noun}-{4:digits}").unwrap();
+/// let name = template.generate(Lang::En).unwrap();
+/// assert_eq!(name.split('-').count(), 3);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    /// Parses a template.
+    ///
+    /// Returns [`Error::InvalidTemplate`] with the byte offset of an
+    /// unclosed or unknown placeholder, a malformed size or a stray `}`.
+    ///
+    /// # Example
+    /// ```
+    /// use random_word::{Error, Template};
+    /// assert!(Template::parse("{{{word}}}").is_ok());
+    /// assert_eq!(Template::parse("{word}-{name}"), Err(Error::InvalidTemplate(7)));
+    /// ```
+    pub fn parse(template: &str) -> Result<Self, Error> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(i) = rest.find(['{', '}']) {
+            let offset = template.len() - rest.len() + i;
+            literal.push_str(&rest[..i]);
+            let (brace, after) = rest[i..].split_at(1);
+            if after.starts_with(brace) {
+                literal.push_str(brace);
+                rest = &after[1..];
+                continue;
+            }
+            if brace == "}" {
+                return Err(Error::InvalidTemplate(offset));
+            }
+            let end = after.find('}').ok_or(Error::InvalidTemplate(offset))?;
+            let placeholder = parse_placeholder(&after[..end]).ok_or(Error::InvalidTemplate(offset))?;
+            if !literal.is_empty() {
+                segments.push(Segment::Literal(std::mem::take(&mut literal)));
+            }
+            segments.push(placeholder);
+            rest = &after[end + 1..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Fills the template's placeholders with words of the given language.
+    ///
+    /// Returns [`Error::NoWordsForFilter`] if a placeholder matches no words,
+    /// as for parts of speech in a language without part-of-speech data.
+    #[inline(always)]
+    pub fn generate(&self, lang: Lang) -> Result<String, Error> {
+        self.generate_rng(lang, &mut default_rng())
+    }
+
+    /// Fills the template's placeholders with words of the given language,
+    /// using the supplied RNG.
+    pub fn generate_rng(&self, lang: Lang, rng: &mut impl Rng) -> Result<String, Error> {
+        let mut output = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(text) => output.push_str(text),
+                Segment::Placeholder {
+                    class: Class::Chars(chars),
+                    size,
+                } => {
+                    let count = size.clone().map_or(1, |size| rng.random_range(size));
+                    output.extend((0..count).map(|_| *chars.choose(rng).expect("non-empty")));
+                }
+                Segment::Placeholder { class, size } => {
+                    let words: Cow<'static, [&'static str]> = match (class, size) {
+                        (Class::Pos(part), size) => {
+                            let words = pos::get(*part, lang).ok_or(Error::NoWordsForFilter)?;
+                            match size {
+                                Some(size) => Cow::Owned(
+                                    words
+                                        .iter()
+                                        .copied()
+                                        .filter(|word| size.contains(&word.chars().count()))
+                                        .collect(),
+                                ),
+                                None => Cow::Borrowed(words),
+                            }
+                        }
+                        (_, Some(size)) => Cow::Owned(all_len_between(size.clone(), lang).unwrap_or_default()),
+                        (_, None) => Cow::Borrowed(all(lang)),
+                    };
+                    output.push_str(words.choose(rng).ok_or(Error::NoWordsForFilter)?);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Parses the inside of a placeholder, such as `4:digits` or `3-6:noun`.
+fn parse_placeholder(inner: &str) -> Option<Segment> {
+    let (size, class) = match inner.split_once(':') {
+        Some((size, class)) => {
+            let size = match size.split_once('-') {
+                Some((min, max)) => min.trim().parse().ok()?..=max.trim().parse().ok()?,
+                None => {
+                    let len = size.trim().parse().ok()?;
+                    len..=len
+                }
+            };
+            if size.is_empty() {
+                return None;
+            }
+            (Some(size), class)
+        }
+        None => (None, inner),
+    };
+    Some(Segment::Placeholder {
+        class: Class::parse(class.trim())?,
+        size,
+    })
+}
@@ -47,11 +47,1365 @@ mod tests {
         test_language_randomness(Lang::Zh);
     }
 
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_with_rng_is_reproducible() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let mut a = StdRng::seed_from_u64(7);
+        let mut b = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            assert_eq!(get_with_rng(Lang::En, &mut a), get_with_rng(Lang::En, &mut b));
+            assert_eq!(get_len_with_rng(5, Lang::En, &mut a), get_len_with_rng(5, Lang::En, &mut b));
+            assert_eq!(get_starts_with_rng('q', Lang::En, &mut a), get_starts_with_rng('q', Lang::En, &mut b));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_len_between_matches_len_buckets() {
+        let words = all_len_between(4..=6, Lang::En).unwrap();
+        let expected: usize = (4..=6).filter_map(|len| all_len(len, Lang::En)).map(|w| w.len()).sum();
+        assert_eq!(words.len(), expected);
+        assert!(words.iter().all(|w| (4..=6).contains(&w.chars().count())));
+
+        for _ in 0..100 {
+            let word = get_len_between(4..=6, Lang::En).unwrap();
+            assert!((4..=6).contains(&word.chars().count()));
+        }
+        assert!(all_len_between(500..=600, Lang::En).is_none());
+        assert!(get_len_between(500..=600, Lang::En).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_ends_with() {
+        let words = all_ends_with('x', Lang::En).unwrap();
+        assert!(words.iter().all(|w| w.ends_with('x')));
+        assert_eq!(words.len(), all(Lang::En).iter().filter(|w| w.ends_with('x')).count());
+        assert!(get_ends_with('x', Lang::En).unwrap().ends_with('x'));
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_containing_matches_scan() {
+        for pattern in ["ight", "qu", "zzz", "tion", "abcdefg", "e"] {
+            let expected: Vec<&str> = all(Lang::En).iter().copied().filter(|w| w.contains(pattern)).collect();
+            let found = all_containing(pattern, Lang::En).unwrap_or_default();
+            assert_eq!(found, expected, "mismatch for {:?}", pattern);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_word_query_matches_scan() {
+        let query = WordQuery::new(Lang::En).len(4..=8).starts_with('c').ends_with('t');
+        let expected: Vec<&str> = all(Lang::En).iter().copied().filter(|w| query.matches(w)).collect();
+        assert!(!expected.is_empty());
+        assert_eq!(query.all(), expected);
+
+        let query = WordQuery::new(Lang::En).containing("ough").len(5..=6);
+        let expected: Vec<&str> = all(Lang::En).iter().copied().filter(|w| query.matches(w)).collect();
+        assert_eq!(query.all(), expected);
+        assert!(query.matches(query.one(&mut rand::rng()).unwrap()));
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_starts_with_str_matches_scan() {
+        for prefix in ["pre", "qu", "z", "xylo", "", "nonexistentprefix"] {
+            let mut expected: Vec<&str> = all(Lang::En).iter().copied().filter(|w| w.starts_with(prefix)).collect();
+            expected.sort_unstable();
+            let found = all_starts_with_str(prefix, Lang::En).unwrap_or_default();
+            assert_eq!(found, expected, "mismatch for {:?}", prefix);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_next_chars_matches_scan() {
+        for prefix in ["", "q", "xylo", "th", "nonexistentprefix"] {
+            let mut expected: Vec<char> = all(Lang::En)
+                .iter()
+                .filter_map(|w| w.strip_prefix(prefix)?.chars().next())
+                .collect();
+            expected.dedup();
+            assert_eq!(next_chars(prefix, Lang::En), expected, "mismatch for {:?}", prefix);
+            assert!(prefix_iter(prefix, Lang::En).eq(all_starts_with_str(prefix, Lang::En).unwrap_or_default().iter().copied()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_ends_with_str_matches_scan() {
+        for suffix in ["ing", "x", "ology", "", "nonexistentsuffix"] {
+            let mut expected: Vec<&str> = all(Lang::En).iter().copied().filter(|w| w.ends_with(suffix)).collect();
+            let mut found = all_ends_with_str(suffix, Lang::En).unwrap_or_default().to_vec();
+            expected.sort_unstable();
+            found.sort_unstable();
+            assert_eq!(found, expected, "mismatch for {:?}", suffix);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_index_round_trip() {
+        let len = all(Lang::En).len();
+        for index in [0, 1, len / 2, len - 1] {
+            let word = word_at(index, Lang::En).unwrap();
+            assert_eq!(index_of(word, Lang::En), Some(index));
+        }
+        assert_eq!(word_at(len, Lang::En), None);
+    }
+
+    #[test]
+    fn test_letter_constraints_feedback() {
+        // Secret "eerie", guessed "geese": g(grey) e(green) e(yellow) s(grey) e(green).
+        let constraints = LetterConstraints::new(5).feedback("geese", "bgybg");
+        assert!(constraints.matches("eerie"));
+        assert!(!constraints.matches("geese"));
+        assert!(!constraints.matches("there"));
+
+        // A grey duplicate caps the count at the number of coloured copies.
+        let constraints = LetterConstraints::new(5).feedback("speed", "bbgbb");
+        assert!(constraints.matches("cheat"));
+        assert!(!constraints.matches("there"));
+        assert!(!constraints.matches("sheet"));
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_common_tiers() {
+        let top = all_common(Tier::Top10k, Lang::En).unwrap();
+        assert_eq!(top.len(), Tier::Top10k.size());
+        assert!(top.iter().all(|w| contains(w, Lang::En)));
+        for (rank, word) in top.iter().enumerate() {
+            assert_eq!(frequency_rank(word, Lang::En), Some(rank));
+        }
+        assert!(all_common(Tier::Top5k, Lang::En).unwrap().starts_with(&top[..1000]));
+        assert_eq!(top_n(1_234, Lang::En).unwrap(), &top[..1_234]);
+        assert_eq!(top_n(0, Lang::En), Some(&[][..]));
+        assert_eq!(top_n(usize::MAX, Lang::En), frequency::ranked(Lang::En));
+        assert_eq!(top_n_entropy_bits(4_096, Lang::En), Some(12.0));
+
+        let query = WordQuery::new(Lang::En).commonness(Tier::Top5k).starts_with('b');
+        let mut expected: Vec<&str> = all(Lang::En).iter().copied().filter(|w| query.matches(w)).collect();
+        let mut found = query.all();
+        expected.sort_unstable();
+        found.sort_unstable();
+        assert!(!found.is_empty());
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_pos_lists() {
+        for pos in [Pos::Noun, Pos::Verb, Pos::Adjective, Pos::Adverb] {
+            let words = all_pos(pos, Lang::En).unwrap();
+            assert!(!words.is_empty(), "no words for {:?}", pos);
+            assert!(words.is_sorted(), "{:?} is not sorted", pos);
+            assert!(words.iter().all(|w| contains(w, Lang::En)), "{:?} has unknown words", pos);
+            assert!(pos.contains(get_pos(pos, Lang::En).unwrap(), Lang::En));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_english_syllables() {
+        for (word, expected) in [
+            ("the", 1),
+            ("make", 1),
+            ("played", 1),
+            ("horse", 1),
+            ("table", 2),
+            ("wanted", 2),
+            ("boxes", 2),
+            ("station", 2),
+            ("battery", 3),
+            ("hmm", 1),
+        ] {
+            assert_eq!(count_syllables(word, Lang::En), Some(expected), "wrong count for {:?}", word);
+        }
+        let words = all_syllables(4, Lang::En).unwrap();
+        assert!(words.iter().all(|w| count_syllables(w, Lang::En) == Some(4)));
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_metaphone_keys() {
+        use crate::phonetics::phonetic_key;
+
+        for (word, expected) in [
+            ("night", "NT"),
+            ("knight", "NT"),
+            ("phone", "FN"),
+            ("thumb", "0M"),
+            ("school", "SKL"),
+            ("science", "SNS"),
+            ("edge", "EJ"),
+            ("nation", "NXN"),
+            ("wright", "RT"),
+            ("xylophone", "SLFN"),
+        ] {
+            assert_eq!(phonetic_key(word, Lang::En).as_deref(), Some(expected), "wrong key for {:?}", word);
+        }
+        assert_eq!(phonetic_key("123", Lang::En), None);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_suggest_matches_scan() {
+        for (word, max) in [("recieve", 2), ("xylophon", 1), ("qzqzqz", 1)] {
+            let mut expected: Vec<&str> = all(Lang::En)
+                .iter()
+                .copied()
+                .filter(|w| strsim_levenshtein(word, w) <= max)
+                .collect();
+            expected.sort_unstable_by_key(|w| (strsim_levenshtein(word, w), *w));
+            assert_eq!(suggest(word, Lang::En, max).unwrap_or_default(), expected, "mismatch for {:?}", word);
+        }
+    }
+
+    fn strsim_levenshtein(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, ca) in a.chars().enumerate() {
+            let mut prev = row[0];
+            row[0] = i + 1;
+            for j in 0..b.len() {
+                let next = (prev + (ca != b[j]) as usize).min(row[j] + 1).min(row[j + 1] + 1);
+                prev = row[j + 1];
+                row[j + 1] = next;
+            }
+        }
+        row[b.len()]
+    }
+
+    #[test]
+    fn test_alliterative_words_are_distinct() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let mut rng = StdRng::seed_from_u64(3);
+        for &lang in Lang::enabled() {
+            let distinct = words::distinct(lang);
+            let initial = |word: &&str| word.chars().next();
+            let largest = distinct.chunk_by(|a, b| initial(a) == initial(b)).map(<[_]>::len).max().unwrap();
+            let mut words = get_alliterative_rng(largest, lang, &mut rng).unwrap();
+            words.sort_unstable();
+            words.dedup();
+            assert_eq!(words.len(), largest, "{:?}", lang);
+            assert!(get_alliterative_rng(largest + 1, lang, &mut rng).is_none(), "{:?}", lang);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_n_distinct() {
+        for _ in 0..20 {
+            let words = get_n_distinct(8, Lang::En, 5).unwrap();
+            for (i, a) in words.iter().enumerate() {
+                for b in &words[i + 1..] {
+                    assert!(strsim_levenshtein(a, b) >= 5, "{:?} and {:?} are too close", a, b);
+                }
+            }
+        }
+        assert!(get_n_distinct(2, Lang::En, 100).is_none());
+        let mut words = get_n_distinct(500, Lang::En, 0).unwrap();
+        words.sort_unstable();
+        words.dedup();
+        assert_eq!(words.len(), 500);
+        assert!(get_n_distinct(usize::MAX, Lang::En, 0).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_weighted_follows_zipf() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let ranked = all_common(Tier::Top10k, Lang::En).unwrap();
+        let harmonic: f64 = (1..=frequency::ranked(Lang::En).unwrap().len()).map(|r| 1.0 / r as f64).sum();
+        let draws = 20_000;
+        let top = (0..draws)
+            .filter(|_| get_weighted_rng(WeightModel::Zipf, Lang::En, &mut rng) == Some(ranked[0]))
+            .count();
+        let expected = draws as f64 / harmonic;
+        assert!((top as f64 - expected).abs() < expected * 0.15, "{} draws of the top word, expected {:.0}", top, expected);
+        assert!(WeightModel::Corpus.entropy_bits(Lang::En).unwrap() > WeightModel::Zipf.entropy_bits(Lang::En).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_try_apis_match_option_apis() {
+        assert_eq!(try_verify(Lang::En), Ok(()));
+        assert_eq!(try_all_len(5, Lang::En), all_len(5, Lang::En).ok_or(Error::NoWordsForFilter));
+        assert_eq!(try_all_len(0, Lang::En), Err(Error::NoWordsForFilter));
+        assert_eq!(try_get_ends_with('1', Lang::En), Err(Error::NoWordsForFilter));
+        assert!(Error::CorruptDictionary(Lang::En).to_string().contains("En"));
+
+        // The check `try_load` runs before the words are first loaded.
+        let (raw, index) = (words::raw(Lang::En), words::raw_index(Lang::En));
+        assert_eq!(check_data(Lang::En, raw, index), Ok(()));
+        assert_eq!(check_data(Lang::En, &raw[..raw.len() / 2], index), Err(Error::CorruptDictionary(Lang::En)));
+        assert_eq!(check_data(Lang::En, raw, &index[..index.len() / 2]), Err(Error::CorruptDictionary(Lang::En)));
+        assert_eq!(try_load(Lang::En), Ok(()));
+        assert!(contains(try_get(Lang::En).unwrap(), Lang::En));
+    }
+
+    #[test]
+    fn test_verify_corrupt_data() {
+        for &lang in Lang::ALL {
+            let (raw, index, hash) = (words::raw(lang), words::raw_index(lang), sha256(lang));
+            assert!(words::check(raw, index, hash).unwrap(), "{}", lang);
+            assert!(!words::check(&raw[..raw.len() / 2], index, hash).unwrap_or(false), "{}", lang);
+            assert!(!words::check(raw, &index[..index.len() / 2], hash).unwrap_or(false), "{}", lang);
+            let mut flipped = raw.to_vec();
+            flipped[raw.len() / 3] ^= 0x55;
+            assert!(!words::check(&flipped, index, hash).unwrap_or(false), "{}", lang);
+            assert!(words::check(raw, &[], hash).is_err(), "{}", lang);
+        }
+    }
+
+    #[test]
+    fn test_lang_parse_round_trip() {
+        for &lang in Lang::ALL {
+            assert_eq!(lang.to_string().parse(), Ok(lang));
+            assert_eq!(lang.name().to_uppercase().parse(), Ok(lang));
+            assert_eq!(Lang::try_from(lang.code()), Ok(lang));
+        }
+        assert_eq!("".parse::<Lang>(), Err(Error::UnknownLang));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_lang_serde_round_trip() {
+        for &lang in Lang::ALL {
+            let json = serde_json::to_string(&lang).unwrap();
+            assert_eq!(json, format!("\"{}\"", lang.code()));
+            assert_eq!(serde_json::from_str::<Lang>(&json).unwrap(), lang);
+        }
+        assert!(serde_json::from_str::<Lang>("\"xx\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_word_dist_samples_its_words() {
+        let dist = WordDist::ends_with('q', Lang::En).unwrap();
+        let mut rng = rand::rng();
+        for _ in 0..1000 {
+            let word = rng.sample(&dist);
+            assert!(all_ends_with('q', Lang::En).unwrap().contains(&word));
+        }
+        assert!(WordDist::len(0, Lang::En).is_none());
+        assert!(WordDist::from_query(&WordQuery::new(Lang::En).len(500..=500)).is_none());
+    }
+
+    #[test]
+    fn test_lazy_buckets_match_scan() {
+        for &lang in Lang::ALL {
+            let words = all(lang);
+            // Languages written in Han characters have thousands of initials.
+            for &char in lang.initials().iter().take(100) {
+                let scan: Vec<&str> = words.iter().copied().filter(|w| w.starts_with(char)).collect();
+                assert_eq!(all_starts_with(char, lang).unwrap(), scan);
+            }
+            for len in 0..=lang.lengths().last().copied().unwrap_or(0) + 1 {
+                let scan: Vec<&str> = words.iter().copied().filter(|w| w.chars().count() == len).collect();
+                assert_eq!(all_len(len, lang).unwrap_or_default(), scan);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_passphrase_transform_entropy() {
+        let generate = |transforms| {
+            Passphrase::generate(PassphraseOptions {
+                words: 4,
+                lang: Lang::En,
+                transforms,
+                ..Default::default()
+            })
+        };
+        let base = distinct_entropy(all(Lang::En).len(), 4);
+        let first = generate(vec![Transform::Capitalize(Capitalize::First)]);
+        assert_eq!(first.entropy_bits(), base);
+        assert!(first.to_string().split('-').all(|w| w.starts_with(|c: char| !c.is_lowercase())));
+        let digit = generate(vec![Transform::InsertDigit]);
+        assert!((digit.entropy_bits() - base - 40f64.log2()).abs() < 1e-9);
+        assert_eq!(digit.to_string().chars().filter(char::is_ascii_digit).count(), 1);
+        let separators = generate(vec![Transform::RandomSeparator]);
+        assert!((separators.entropy_bits() - base - 3.0 * 24f64.log2()).abs() < 1e-9);
+        let words_len: usize = separators.words().iter().map(|w| w.len()).sum();
+        assert_eq!(separators.to_string().len(), words_len + 3);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_policy_is_satisfied() {
+        let policy = Policy::new()
+            .min_len(30)
+            .max_len(60)
+            .require(RequiredClass::Uppercase)
+            .require(RequiredClass::Digit)
+            .require(RequiredClass::Symbol)
+            .ban("the");
+        for _ in 0..20 {
+            let passphrase = Passphrase::generate_for(PassphraseOptions::default(), &policy).unwrap();
+            assert!(policy.allows(&passphrase.to_string()));
+        }
+        let impossible = Policy::new().min_len(10).max_len(5);
+        assert_eq!(
+            Passphrase::generate_for(PassphraseOptions::default(), &impossible),
+            Err(Error::PolicyUnsatisfiable)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_estimate_patterns() {
+        let kinds = |input| estimate(input).matches.iter().map(|m| m.kind).collect::<Vec<_>>();
+        assert_eq!(kinds("aaaa"), [MatchKind::Repeat]);
+        assert_eq!(kinds("abcdef"), [MatchKind::Sequence]);
+        assert_eq!(kinds("1987"), [MatchKind::Year]);
+        assert_eq!(kinds("Staple"), [MatchKind::Dictionary(Lang::En)]);
+        assert_eq!(estimate("").bits, 0.0);
+        let passphrase = estimate("battery.staple.battery");
+        assert_eq!(passphrase.matches[3].kind, MatchKind::Separator);
+        assert!(estimate("battery").bits < estimate("Battery").bits);
+        assert!(estimate("battery").bits < estimate("bAttery").bits);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_passphrase_without_repeats() {
+        let options = PassphraseOptions {
+            words: 6,
+            filter: Some(WordQuery::new(Lang::En).len(2..=2)),
+            ..Default::default()
+        };
+        let pool = options.filter.as_ref().unwrap().all().len();
+        assert!(pool >= 6);
+        for _ in 0..100 {
+            let passphrase = Passphrase::generate(options.clone());
+            let mut words = passphrase.words().to_vec();
+            words.sort_unstable();
+            words.dedup();
+            assert_eq!(words.len(), 6);
+            let falling: f64 = (0..6).map(|i| ((pool - i) as f64).log2()).sum();
+            assert!((passphrase.entropy_bits() - falling).abs() < 1e-9);
+        }
+        let repeats = Passphrase::generate(PassphraseOptions { allow_repeats: true, ..options.clone() });
+        assert_eq!(repeats.entropy_bits(), 6.0 * (pool as f64).log2());
+        let too_strong = Passphrase::with_min_entropy_options(1000.0, options.clone());
+        assert_eq!(too_strong, Err(Error::NoWordsForFilter));
+        let repeats = PassphraseOptions { allow_repeats: true, ..options.clone() };
+        let long = Passphrase::with_min_entropy_options(1000.0, repeats.clone()).unwrap();
+        assert_eq!(long.words().len(), (1000.0 / (pool as f64).log2()).ceil() as usize);
+        for bits in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            assert_eq!(Passphrase::with_min_entropy_options(bits, repeats.clone()), Err(Error::InvalidEntropyTarget));
+            assert_eq!(Passphrase::with_min_entropy(bits, Lang::En), Err(Error::InvalidEntropyTarget));
+        }
+        assert_eq!(Passphrase::with_min_entropy(-1.0, Lang::En).unwrap().words().len(), 1);
+        // Near the most the pool allows, without recomputing the sum per word.
+        let all_words = distinct_entropy(words::distinct(Lang::En).len(), words::distinct(Lang::En).len());
+        assert_eq!(Passphrase::with_min_entropy(all_words * 2.0, Lang::En), Err(Error::NoWordsForFilter));
+    }
+
+    #[test]
+    fn test_pools_skip_repeated_entries() {
+        for &lang in Lang::ALL {
+            let distinct = words::distinct(lang).len();
+            assert_eq!(WordQuery::new(lang).all().len(), distinct, "{}", lang);
+            let mut short = WordQuery::new(lang).len(2..=3).all();
+            let len = short.len();
+            short.sort_unstable();
+            short.dedup();
+            assert_eq!(short.len(), len, "{}", lang);
+            let passphrase = Passphrase::generate(PassphraseOptions { words: 3, lang, ..Default::default() });
+            assert_eq!(passphrase.entropy_bits(), distinct_entropy(distinct, 3), "{}", lang);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_passphrase_blocklist() {
+        let blocked = Blocklist::new(all_len(2, Lang::En).unwrap().iter().skip(4));
+        assert!(blocked.contains("Ye") && !Blocklist::new(["Été"]).contains("ete") && Blocklist::new(["ÉTÉ"]).contains("été"));
+        let options = PassphraseOptions {
+            words: 4,
+            filter: Some(WordQuery::new(Lang::En).len(2..=2)),
+            blocklist: blocked.clone(),
+            ..Default::default()
+        };
+        let mut words = Passphrase::generate(options.clone()).words().to_vec();
+        words.sort_unstable();
+        assert_eq!(words, all_len(2, Lang::En).unwrap()[..4]);
+        assert_eq!(Passphrase::generate(options).entropy_bits(), 24f64.log2());
+
+        let options = PassphraseOptions { words: 1, blocklist: blocked, ..Default::default() };
+        for passphrase in Passphrase::generate_batch(100, options.clone()) {
+            assert!(!options.blocklist.contains(passphrase.words()[0]));
+        }
+        for _ in 0..5 {
+            let passphrase = Passphrase::generate_within_len(2..=2, options.clone()).unwrap();
+            assert!(all_len(2, Lang::En).unwrap()[..4].contains(&passphrase.words()[0]));
+        }
+
+        let allowed = options.blocklist.apply(&Lang::En);
+        assert_eq!(allowed.all_len(2), Some(&all_len(2, Lang::En).unwrap()[..4]));
+        assert_eq!(allowed.len() + all_len(2, Lang::En).unwrap().len() - 4, words::distinct(Lang::En).len());
+        for _ in 0..100 {
+            assert!(!options.blocklist.contains(allowed.get().unwrap()));
+            assert!(!options.blocklist.contains(allowed.get_starts_with('y').unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_dice_rolls_cover_the_list() {
+        for &lang in Lang::ALL {
+            let dice = dice_per_word(lang);
+            let lowest = vec![1; dice];
+            let highest = vec![6; dice];
+            assert_eq!(from_dice_rolls(&lowest, lang).unwrap(), [all(lang)[0]]);
+            let last = from_dice_rolls(&highest, lang).unwrap()[0];
+            assert!(index_of(last, lang).unwrap() >= all(lang).len() * 5 / 6);
+            assert!(6usize.pow(dice as u32) <= words::distinct(lang).len());
+            // Every outcome picks a different word.
+            let outcomes = 6usize.pow(dice as u32);
+            let rolls: Vec<u8> = (0..outcomes)
+                .flat_map(|i| (0..dice as u32).rev().map(move |digit| (i / 6usize.pow(digit) % 6) as u8 + 1))
+                .collect();
+            let mut words = from_dice_rolls(&rolls, lang).unwrap();
+            words.sort_unstable();
+            words.dedup();
+            assert_eq!(words.len(), outcomes, "{}", lang);
+            assert_eq!(from_dice_rolls(&[0], lang), Err(Error::DiceRollOutOfRange(0)));
+            assert_eq!(
+                from_dice_rolls(&lowest[1..], lang),
+                Err(Error::DiceRollCount { per_word: dice, rolls: dice - 1 })
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "eff-long")]
+    fn test_eff_long_matches_dice_table() {
+        assert_eq!(Lang::EffLong.word_count(), 7776);
+        assert_eq!(dice_per_word(Lang::EffLong), 5);
+        assert_eq!(from_dice_rolls(&[1, 1, 1, 1, 1], Lang::EffLong).unwrap(), ["abacus"]);
+        assert_eq!(from_dice_rolls(&[6, 6, 6, 6, 6], Lang::EffLong).unwrap(), ["zoom"]);
+    }
+
+    #[test]
+    #[cfg(feature = "eff-short1")]
+    fn test_eff_short1_matches_dice_table() {
+        assert_eq!(Lang::EffShort1.word_count(), 1296);
+        assert_eq!(from_dice_rolls(&[1, 1, 1, 1], Lang::EffShort1).unwrap(), ["acid"]);
+    }
+
+    #[test]
+    #[cfg(feature = "eff-short2")]
+    fn test_eff_short2_has_unique_prefixes() {
+        let words = all(Lang::EffShort2);
+        assert_eq!(words.len(), 1296);
+        let prefixes: AHashSet<&str> = words.iter().map(|w| &w[..3]).collect();
+        assert_eq!(prefixes.len(), words.len());
+    }
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "en-10k", not(feature = "clean")))]
+    fn test_en_10k_is_most_common_english() {
+        let common = top_n(10_000, Lang::En).unwrap();
+        assert_eq!(top_n(usize::MAX, Lang::En10k).unwrap(), common);
+        let mut sorted = common.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(all(Lang::En10k), sorted);
+        assert_eq!(Lang::En10k.word_count(), 10_000);
+        assert_eq!(frequency_rank("the", Lang::En10k), Some(0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "en-gb", feature = "en-us"))]
+    fn test_en_spelling_variants() {
+        for (british, american) in [("colour", "color"), ("centre", "center"), ("analyse", "analyze"), ("travelled", "traveled")] {
+            assert!(contains(british, Lang::EnGb) && !contains(american, Lang::EnGb), "{british}");
+            assert!(contains(american, Lang::EnUs) && !contains(british, Lang::EnUs), "{american}");
+            assert!(contains(british, Lang::En) && contains(american, Lang::En));
+        }
+        for shared in ["program", "license", "meter", "apple"] {
+            assert!(contains(shared, Lang::EnGb) && contains(shared, Lang::EnUs), "{shared}");
+        }
+        assert!(all(Lang::EnGb).len() < all(Lang::En).len() && all(Lang::EnUs).len() < all(Lang::En).len());
+        assert_eq!("en-GB".parse::<Lang>(), Ok(Lang::EnGb));
+    }
+
+    #[test]
+    #[cfg(feature = "ja")]
+    fn test_entropy_counts_distinct_words() {
+        let options = PassphraseOptions { words: 4, lang: Lang::Ja, allow_repeats: true, ..Default::default() };
+        let bits = Passphrase::generate(options).entropy_bits();
+        assert!((passphrase_entropy(4, Lang::Ja) - bits).abs() < 1e-9);
+        assert!(entropy_bits(Lang::Ja) < (all(Lang::Ja).len() as f64).log2());
+        assert_eq!(words_for_entropy(bits, Lang::Ja), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "ja")]
+    fn test_ja_script() {
+        for (word, hiragana, katakana, kana, kanji) in [
+            ("ありがと", true, false, true, false),
+            ("テレビ", false, true, true, false),
+            ("アテになる", false, false, true, false),
+            ("拾う [ひろう]", false, false, false, true),
+            ("ヽ", false, true, true, false),
+        ] {
+            assert_eq!(JaScript::Hiragana.matches(word), hiragana, "{word}");
+            assert_eq!(JaScript::Katakana.matches(word), katakana, "{word}");
+            assert_eq!(JaScript::Kana.matches(word), kana, "{word}");
+            assert_eq!(JaScript::Kanji.matches(word), kanji, "{word}");
+        }
+        let kana = all_ja_script(JaScript::Kana, Lang::Ja).unwrap();
+        let (hiragana, katakana) =
+            (all_ja_script(JaScript::Hiragana, Lang::Ja).unwrap(), all_ja_script(JaScript::Katakana, Lang::Ja).unwrap());
+        assert!(hiragana.len() + katakana.len() <= kana.len());
+        assert!(kana.iter().all(|word| !JaScript::Kanji.matches(word)));
+        assert!(all_ja_script(JaScript::Kanji, Lang::Ja).unwrap().contains(&"拾う [ひろう]"));
+        assert_eq!(all_ja_script(JaScript::Kana, Lang::Zh), None);
+
+        let words = WordQuery::new(Lang::Ja).ja_script(JaScript::Katakana).len(3..=3).all();
+        assert!(words.contains(&"テレビ") && words.iter().all(|word| JaScript::Katakana.matches(word)));
+    }
+
+    #[test]
+    #[cfg(feature = "zh")]
+    fn test_hsk() {
+        assert_eq!(hsk_level("愛 爱", Lang::Zh), Some(1));
+        assert_eq!(hsk_level("一絲不苟 一丝不苟", Lang::Zh), Some(6));
+        assert_eq!(hsk_level("遠征軍 远征军", Lang::Zh), None);
+        assert!(all_hsk(1, Lang::Zh).unwrap().len() < all_hsk(6, Lang::Zh).unwrap().len());
+        assert_eq!(all_hsk(0, Lang::Zh), None);
+        assert_eq!(all_hsk(7, Lang::Zh), None);
+        for level in 1..=6 {
+            assert!(all_hsk(level, Lang::Zh).unwrap().iter().all(|word| hsk_level(word, Lang::Zh) == Some(level)));
+        }
+
+        assert_eq!(LengthUnit::Chars.measure("遠征軍 远征军"), 7);
+        assert_eq!(LengthUnit::Headword.measure("遠征軍 远征军"), 3);
+        let words = WordQuery::new(Lang::Zh).hsk(1..=1).len(2..=2).len_unit(LengthUnit::Headword).all();
+        assert!(words.contains(&"朋友 朋友"));
+        assert!(words.iter().all(|word| word.split(' ').all(|form| form.chars().count() == 2)));
+        let words = all_len_in(2, LengthUnit::Headword, Lang::Zh).unwrap();
+        assert!(words.contains(&"中文 中文") && !words.contains(&"愛 爱"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "romanization", feature = "ja", feature = "ru", feature = "zh"))]
+    fn test_romanize() {
+        use crate::{Script, all_script, romanize};
+        for (word, lang, latin, ascii) in [
+            ("コーヒー", Lang::Ja, "kōhī", "koohii"),
+            ("抹茶 [まっちゃ]", Lang::Ja, "matcha", "matcha"),
+            ("店員 [てんいん]", Lang::Ja, "ten'in", "tenin"),
+            ("西安 西安", Lang::Zh, "xī'ān", "xian"),
+            ("綠色 绿色", Lang::Zh, "lǜsè", "lvse"),
+            ("журнал", Lang::Ru, "žurnal", "zhurnal"),
+        ] {
+            assert_eq!(romanize(word, Script::Latin, lang).as_deref(), Some(latin));
+            assert_eq!(romanize(word, Script::Ascii, lang).as_deref(), Some(ascii));
+            assert_eq!(romanize(word, Script::Native, lang).as_deref(), Some(word));
+        }
+        assert_eq!(romanize("ヽ", Script::Ascii, Lang::Ja), None);
+        assert_eq!(all_script(Script::Ascii, Lang::En), None);
+        for lang in [Lang::Ja, Lang::Zh, Lang::Ru] {
+            let words = all_script(Script::Ascii, lang).unwrap();
+            assert!(words.iter().all(|word| word.is_ascii()), "{lang:?}");
+            assert!(words.windows(2).all(|pair| pair[0] < pair[1]), "{lang:?}");
+        }
+    }
+
+    #[cfg(feature = "bip39-en")]
+    #[test]
+    fn test_mnemonic_vectors() {
+        use crate::mnemonic::{self, Wordlist};
+        let vectors: [(&[u8], &str); 3] = [
+            (&[0; 16], "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"),
+            (&[0x7f; 16], "legal winner thank year wave sausage worth useful legal winner thank yellow"),
+            (&[0xff; 32], "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote"),
+        ];
+        for (entropy, phrase) in vectors {
+            let words = mnemonic::encode(entropy, Wordlist::English).unwrap();
+            assert_eq!(words.join(" "), phrase);
+            assert_eq!(mnemonic::decode(&words, Wordlist::English).unwrap(), entropy);
+        }
+        assert_eq!(mnemonic::encode(&[0; 17], Wordlist::English), Err(Error::InvalidEntropyLength(17)));
+        assert_eq!(mnemonic::decode(&["abandon"; 11], Wordlist::English), Err(Error::InvalidWordCount(11)));
+        let mut words = vec!["abandon"; 12];
+        words[3] = "abandonn";
+        assert_eq!(mnemonic::decode(&words, Wordlist::English), Err(Error::UnknownWord(3)));
+    }
+
+    #[cfg(feature = "pgp")]
+    #[test]
+    fn test_pgp_words_round_trip() {
+        let bytes: Vec<u8> = (0..=255).chain((0..=255).rev()).collect();
+        let words = pgp::fingerprint_to_words(&bytes);
+        assert_eq!(pgp::words_to_fingerprint(&words), Ok(bytes));
+        let even: AHashSet<_> = pgp::even_words().iter().collect();
+        assert_eq!(even.len(), 256);
+        assert!(pgp::odd_words().iter().all(|word| !even.contains(word)));
+        let swapped = [words[1], words[0]];
+        assert_eq!(pgp::words_to_fingerprint(&swapped), Err(Error::WordOutOfOrder(0)));
+    }
+
+    #[cfg(feature = "skey")]
+    #[test]
+    fn test_skey_round_trip() {
+        // RFC 2289 appendix C, MD4 output.
+        assert_eq!(skey::encode(0xD185_4218_EBBB_0B51).join(" "), "ROME MUG FRED SCAN LIVE LACE");
+        let mut rng = rand::rng();
+        for _ in 0..1000 {
+            let value: u64 = rng.random();
+            assert_eq!(skey::decode(&skey::encode(value)), Ok(value));
+        }
+        assert_eq!(skey::decode(&["A"; 5]), Err(Error::InvalidWordCount(5)));
+        assert_eq!(skey::decode(&["A", "B", "C", "D", "E", "ZZZZ"]), Err(Error::UnknownWord(1)));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut rng = rand::rng();
+        for lang in Lang::ALL.iter().copied() {
+            for len in 0..40 {
+                let bytes: Vec<u8> = (0..len).map(|_| rng.random()).collect();
+                let words = encode_bytes(&bytes, lang);
+                assert_eq!(decode_words(&words, lang), Ok(bytes), "{lang} with {len} bytes");
+            }
+            // A dropped word goes unnoticed when it carries whole bytes.
+            if !bits_per_word(lang).is_multiple_of(8) {
+                let words = encode_bytes(&[0xff; 8], lang);
+                assert_eq!(decode_words(&words[1..], lang), Err(Error::InvalidPadding));
+            }
+        }
+        assert_eq!(decode_words::<&str>(&[], Lang::En), Err(Error::InvalidWordCount(0)));
+    }
+
+    #[test]
+    fn test_names_follow_style() {
+        use crate::name::{Generator, Style};
+        for style in [Style::AdjNoun, Style::AdjAdjNoun, Style::AdverbAdjNoun, Style::VerbNoun] {
+            let generator = Generator::new(style, Lang::En).unwrap().separator("_").suffix_digits(3);
+            let name = generator.generate();
+            let parts: Vec<&str> = name.split('_').collect();
+            assert_eq!(parts.len(), style.parts().len() + 1);
+            for (word, &pos) in parts.iter().zip(style.parts()) {
+                assert!(pos.contains(word, Lang::En), "{word} is not a {pos:?}");
+            }
+            assert_eq!(parts.last().unwrap().len(), 3);
+        }
+        let generator = Generator::new(Style::AdjNoun, Lang::En).unwrap();
+        assert_eq!(generator.collision_probability(1), 0.0);
+        assert!(generator.collision_probability(1_000_000) > 0.99);
+    }
+
+    #[test]
+    fn test_template_placeholders() {
+        let template = Template::parse("{{{3-4:noun}}}:{5:word}.{2-3:digits}{letter}").unwrap();
+        for _ in 0..100 {
+            let output = template.generate(Lang::En).unwrap();
+            let (noun, rest) = output.strip_prefix('{').unwrap().split_once("}:").unwrap();
+            assert!(Pos::Noun.contains(noun, Lang::En) && (3..=4).contains(&noun.len()));
+            let (word, rest) = rest.split_once('.').unwrap();
+            assert!(contains(word, Lang::En) && word.chars().count() == 5);
+            let (digits, letter) = rest.split_at(rest.len() - 1);
+            assert!((2..=3).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit()));
+            assert!(letter.chars().all(|c| c.is_ascii_lowercase()));
+        }
+        for (template, offset) in [("{word", 0), ("a}", 1), ("{4:}", 0), ("x{5-3:word}", 1), ("{x:word}", 0)] {
+            assert_eq!(Template::parse(template), Err(Error::InvalidTemplate(offset)), "{template}");
+        }
+        assert_eq!(generate_template("{40:noun}", Lang::En), Err(Error::NoWordsForFilter));
+    }
+
+    #[test]
+    fn test_major_system() {
+        for (word, digits) in [("meteor", "314"), ("tulip", "159"), ("cheese", "60"), ("action", "762"), ("butter", "914")] {
+            assert_eq!(major_digits(word, Lang::En).as_deref(), Some(digits), "{word}");
+        }
+        for number in ["314159", "2718281828", "0", "1234567890"] {
+            let sequences = mnemonic_for_number(number, Lang::En).unwrap();
+            assert!(!sequences.is_empty());
+            let fewest = sequences[0].len();
+            for words in sequences {
+                assert_eq!(words.len(), fewest);
+                let digits: String = words.iter().map(|word| major_digits(word, Lang::En).unwrap()).collect();
+                assert_eq!(digits, number);
+            }
+        }
+        assert_eq!(mnemonic_for_number("", Lang::En), None);
+        assert_eq!(mnemonic_for_number("3.14", Lang::En), None);
+    }
+
+    #[test]
+    fn test_leet_transform_entropy() {
+        let options = PassphraseOptions {
+            transforms: vec![Transform::Leet {
+                level: LeetLevel::Light,
+                random: true,
+            }],
+            ..Default::default()
+        };
+        let base = distinct_entropy(all(Lang::En).len(), options.words);
+        for _ in 0..20 {
+            let passphrase = Passphrase::generate(options.clone());
+            let replaceable = passphrase.words().concat().chars().filter(|c| "aeiost".contains(*c)).count();
+            assert!((passphrase.entropy_bits() - base - replaceable as f64).abs() < 1e-9);
+            let text = passphrase.to_string();
+            let unleeted: String = text.chars().map(|c| match c { '4' => 'a', '3' => 'e', '1' => 'i', '0' => 'o', '5' => 's', '7' => 't', c => c }).collect();
+            assert_eq!(unleeted, passphrase.words().join("-"));
+        }
+        let fixed = Transform::Leet {
+            level: LeetLevel::Heavy,
+            random: false,
+        };
+        let passphrase = Passphrase::generate(PassphraseOptions {
+            transforms: vec![fixed],
+            ..Default::default()
+        });
+        assert!((passphrase.entropy_bits() - base).abs() < 1e-9);
+        let leeted = estimate(&leet("sunshine", LeetLevel::Light));
+        assert!(matches!(leeted.matches[..], [Match { kind: MatchKind::Dictionary(Lang::En), .. }]));
+        assert!(leeted.bits < estimate("sunshine").bits + 8.0);
+    }
+
+    #[cfg(feature = "breach")]
+    #[test]
+    fn test_breach_filter() {
+        use crate::breach::{self, BloomFilter};
+        for password in ["123456", "password", "qwerty", "letmein", "trustno1"] {
+            assert!(breach::is_breached(password), "{password}");
+        }
+        let passphrase = Passphrase::generate(PassphraseOptions::default()).to_string();
+        assert!(!breach::is_breached(&passphrase));
+
+        let mut filter = BloomFilter::new(100, 1e-3);
+        filter.insert("hunter2");
+        let sha1 = [
+            0xf3, 0xbb, 0xbd, 0x66, 0xa6, 0x3d, 0x4b, 0xf1, 0x74, 0x79, 0x40, 0x57, 0x8e, 0xc3, 0xd0, 0x10, 0x35, 0x30,
+            0xe2, 0x1d,
+        ];
+        assert!(filter.contains_sha1(sha1));
+        assert_eq!(BloomFilter::from_bytes(&filter.to_bytes()), Ok(filter));
+        assert_eq!(BloomFilter::from_bytes(b"RWBLOOM1"), Err(Error::InvalidFilter));
+    }
+
+    #[test]
+    fn test_gibberish_syllables() {
+        use crate::gibberish::Generator;
+        for lang in Lang::ALL.iter().copied() {
+            // Every syllable must end in its only vowel for the entropy to be exact.
+            let vowels = crate::phonetics::vowels(lang).unwrap_or("aeiou");
+            let generator = Generator::new(lang).length(9);
+            for syllable in generator.syllables() {
+                let last = syllable.chars().last().unwrap();
+                assert!(vowels.contains(last), "{syllable}");
+                assert_eq!(syllable.chars().filter(|&c| vowels.contains(c)).count(), 1, "{syllable}");
+            }
+            for _ in 0..20 {
+                assert_eq!(generator.generate().chars().count(), 9);
+            }
+        }
+        // Count the distinct spellings of every sequence of four chars.
+        let generator = Generator::new(Lang::En).length(4);
+        let mut words = AHashSet::new();
+        let mut partial = vec![String::new()];
+        while let Some(word) = partial.pop() {
+            for syllable in generator.syllables() {
+                let next = format!("{word}{syllable}");
+                match next.chars().count() {
+                    4 => _ = words.insert(next),
+                    len if len < 4 => partial.push(next),
+                    _ => {}
+                }
+            }
+        }
+        assert_eq!(words.len() as f64, generator.combinations());
+        assert_eq!(Generator::new(Lang::En).length(0).generate(), "");
+        assert_eq!(Generator::new(Lang::En).length(0).entropy_bits(), 0.0);
+    }
+
+    #[test]
+    fn test_poem_meter() {
+        let syllables = |line: &str| -> usize { line.split(' ').map(|word| count_syllables(word, Lang::En).unwrap()).sum() };
+        for _ in 0..20 {
+            let haiku = poem::haiku(Lang::En).unwrap();
+            assert_eq!(haiku.each_ref().map(|line| syllables(line)), [5, 7, 5], "{haiku:?}");
+        }
+        for count in 1..=12 {
+            assert_eq!(syllables(&poem::line(count, Lang::En).unwrap()), count);
+        }
+        assert_eq!(poem::line(0, Lang::En), None);
+        assert_eq!(seeded(3).haiku(Lang::En), seeded(3).haiku(Lang::En));
+    }
+
+    #[test]
+    fn test_sentence_grammar() {
+        use rand::{SeedableRng, rngs::StdRng};
+        for _ in 0..50 {
+            let sentence = sentence::generate(Lang::En).unwrap();
+            let body = sentence.strip_suffix('.').unwrap().to_lowercase();
+            let words: Vec<&str> = body.split(' ').collect();
+            assert!((5..=7).contains(&words.len()), "{sentence}");
+            for (i, word) in words.iter().enumerate() {
+                match *word {
+                    "the" => {}
+                    "a" | "an" => assert_eq!(*word == "an", words[i + 1].starts_with(['a', 'e', 'i', 'o', 'u'])),
+                    word => assert!(contains(word, Lang::En), "{word} in {sentence}"),
+                }
+            }
+        }
+        assert_eq!(
+            sentence::generate_rng(Lang::En, &mut StdRng::seed_from_u64(5)),
+            sentence::generate_rng(Lang::En, &mut StdRng::seed_from_u64(5))
+        );
+    }
+
+    #[test]
+    fn test_passphrase_batches() {
+        let options = PassphraseOptions {
+            words: 1,
+            filter: Some(WordQuery::new(Lang::En).len(3..=3)),
+            ..Default::default()
+        };
+        let pool = all_len(3, Lang::En).unwrap();
+        let batch = Passphrase::generate_batch(50, options.clone());
+        assert_eq!(batch.len(), 50);
+        assert!(batch.iter().all(|p| pool.contains(&p.words()[0])));
+        // Half the pool can be drawn without repeats; more than all of it can't.
+        let unique = Passphrase::generate_unique_batch(pool.len() / 2, options.clone()).unwrap();
+        let texts: AHashSet<String> = unique.iter().map(|p| p.to_string()).collect();
+        assert_eq!(texts.len(), pool.len() / 2);
+        assert_eq!(
+            Passphrase::generate_unique_batch(pool.len() + 1, options),
+            Err(Error::TooFewPassphrases)
+        );
+        assert!(Passphrase::generate_unique_batch(0, PassphraseOptions::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_passphrase_within_len() {
+        let options = PassphraseOptions {
+            words: 2,
+            filter: Some(WordQuery::new(Lang::En).len(2..=3)),
+            ..Default::default()
+        };
+        let (two, three) = (all_len(2, Lang::En).unwrap().len() as f64, all_len(3, Lang::En).unwrap().len() as f64);
+        // Six chars fit a two- and a three-letter word in either order; seven
+        // fit two distinct three-letter words.
+        let passphrase = Passphrase::generate_within_len(6..=7, options.clone()).unwrap();
+        let expected = 2.0 * two * three + three * (three - 1.0);
+        assert!((passphrase.entropy_bits() - expected.log2()).abs() < 1e-9);
+        assert_eq!(Passphrase::generate_within_len(8..=20, options), Err(Error::NoWordsForFilter));
+
+        let options = PassphraseOptions {
+            words: 4,
+            transforms: vec![Transform::InsertDigit, Transform::RandomSeparator],
+            ..Default::default()
+        };
+        for _ in 0..50 {
+            let passphrase = Passphrase::generate_within_len(18..=20, options.clone()).unwrap();
+            assert!((18..=20).contains(&passphrase.to_string().chars().count()), "{passphrase}");
+            assert_eq!(passphrase.words().len(), 4);
+        }
+        assert_eq!(Passphrase::generate_within_len(0..=3, options), Err(Error::NoWordsForFilter));
+    }
+
+    #[test]
+    fn test_transcription_safe() {
+        for group in [["their", "there"], ["ware", "wear"], ["modern", "modem"], ["clear", "dear"]] {
+            for word in group {
+                assert!(contains(word, Lang::En) && !is_transcription_safe(word, Lang::En), "{word}");
+            }
+        }
+        for word in ["zebra", "battery", "staple", "correct"] {
+            assert!(is_transcription_safe(word, Lang::En), "{word}");
+        }
+        let safe = WordQuery::new(Lang::En).len(3..=4).transcription_safe().all();
+        let all = WordQuery::new(Lang::En).len(3..=4).all();
+        assert!(safe.len() < all.len() && safe.len() > all.len() * 9 / 10);
+        assert!(safe.iter().all(|word| is_transcription_safe(word, Lang::En)));
+    }
+
+    #[test]
+    fn test_custom_dictionary() {
+        let path = std::env::temp_dir().join(format!("random_word_dictionary_{}.txt", std::process::id()));
+        std::fs::write(&path, "# approved words\nzebra\n  apple \n\nzebra\nécole\n").unwrap();
+        let dictionary = Dictionary::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(dictionary.all(), ["apple", "zebra", "école"]);
+        assert_eq!(Dictionary::from_bytes(b"zebra\napple\n\xc3\xa9cole").unwrap().all(), dictionary.all());
+        assert!(Dictionary::from_bytes(b"\xff").is_err());
+        assert_eq!(dictionary.all_len(5), Some(&["apple", "zebra", "école"][..]));
+        assert_eq!(dictionary.all_len(4), None);
+        assert_eq!(dictionary.get_starts_with('é'), Some("école"));
+        assert_eq!(dictionary.all_starts_with('b'), None);
+        assert!(!dictionary.contains("École"));
+
+        let empty: Dictionary = Vec::<String>::new().into_iter().collect();
+        assert!(empty.is_empty() && empty.get().is_none());
+        let words = Dictionary::new(["a\nb", "", "c"]);
+        assert_eq!(words.all(), ["a\nb", "c"]);
+    }
+
+    #[test]
+    fn test_word_source() {
+        let dictionary = Dictionary::new(["modem", "modern", "mango", "melon", "lime", "plum"]);
+        let query = WordQuery::new(dictionary.clone()).starts_with('m').len(5..=5);
+        assert_eq!(query.all(), ["mango", "melon", "modem"]);
+        assert_eq!(query.lang(), None);
+        assert!(query.source().contains("plum"));
+        assert_eq!(WordQuery::new(dictionary.clone()).pattern("l__e").all(), ["lime"]);
+        assert!(WordQuery::new(dictionary.clone()).commonness(Tier::Top5k).all().is_empty());
+        assert_eq!(
+            WordQuery::new(dictionary.clone()).starts_with('m').transcription_safe().all(),
+            ["mango", "melon"]
+        );
+
+        let query = WordQuery::new(Lang::En).len(5..=5);
+        assert_eq!(query.lang(), Some(Lang::En));
+        assert_eq!(query.source().all_len(5), all_len(5, Lang::En));
+
+        let options = PassphraseOptions {
+            words: 3,
+            filter: Some(WordQuery::new(dictionary.clone())),
+            ..PassphraseOptions::default()
+        };
+        let passphrase = Passphrase::generate(options);
+        assert!(passphrase.words().iter().all(|word| dictionary.contains(word)));
+    }
+
+    #[test]
+    fn test_dictionary_set_operations() {
+        let english = Lang::En.to_dictionary();
+        assert_eq!(english.all(), all(Lang::En));
+        let extra = Dictionary::new(["zebra", "xyzzy", "plugh"]);
+        let union = english.union(&extra);
+        assert_eq!(union.len(), english.len() + 2);
+        assert!(union.all().is_sorted() && union.contains("xyzzy"));
+        assert_eq!(extra.intersection(&Lang::En).all(), ["zebra"]);
+        assert_eq!(extra.difference(&english).all(), ["plugh", "xyzzy"]);
+        let allowed = english.difference(&extra);
+        assert_eq!(allowed.len(), english.len() - 1);
+        assert!(!allowed.contains("zebra"));
+        assert_eq!(allowed.union(&extra).all(), union.all());
+        assert!(extra.intersection(&Dictionary::default()).is_empty());
+    }
+
+    #[test]
+    fn test_dictionary_from_path() {
+        let dir = std::env::temp_dir().join(format!("random_word_load_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let load = |name: &str, bytes: &[u8]| {
+            let path = dir.join(name);
+            std::fs::write(&path, bytes).unwrap();
+            Dictionary::from_path(&path)
+        };
+        let words = ["apple", "banana", "cherry"];
+
+        let plain = load("plain.txt", "\u{feff}# fruit\n banana\napple\n\ncherry\napple\n".as_bytes()).unwrap();
+        assert_eq!(plain.all(), words);
+        let diceware = load("diceware.txt", b"11111\tbanana\n11112 apple\n11113   cherry\n").unwrap();
+        assert_eq!(diceware.all(), words);
+        let csv = load("list.csv", b"id,Word,note\n1,banana,\"yellow, long\"\n2,\"apple\",\n3,cherry,red\n").unwrap();
+        assert_eq!(csv.all(), words);
+        let headless = load("headless.txt", b"1,banana\n2,apple\n3,cherry\n").unwrap();
+        assert_eq!(headless.all(), words);
+
+        #[cfg(feature = "brotli")]
+        {
+            let mut compressed = Vec::new();
+            {
+                let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 9, 22);
+                std::io::Write::write_all(&mut writer, b"11111\tbanana\n11112\tapple\n11113\tcherry\n").unwrap();
+            }
+            assert_eq!(load("list.txt.br", &compressed).unwrap().all(), words);
+            assert_eq!(load("list.bin", &compressed).unwrap().all(), words);
+        }
+        #[cfg(feature = "zstd")]
+        {
+            let compressed = zstd::stream::encode_all(&b"id,word\n1,banana\n2,apple\n3,cherry\n"[..], 3).unwrap();
+            assert_eq!(load("list.csv.zst", &compressed).unwrap().all(), words);
+            assert_eq!(load("list.bin", &compressed).unwrap().all(), words);
+            assert!(matches!(load("bad.zst", b"apple\n"), Err(LoadError::Decompress(_))));
+        }
+
+        assert!(matches!(load("bad.txt", b"apple\nban\xffana\n"), Err(LoadError::InvalidUtf8(2))));
+        assert!(matches!(load("bad.br", b"apple\n"), Err(LoadError::Decompress(_))));
+        assert!(matches!(load("bad.csv", b"word\napple\n\"banana\n"), Err(LoadError::MalformedLine(3))));
+        assert!(matches!(load("bad.csv", b"id,word\n1,apple\n2\n"), Err(LoadError::MalformedLine(3))));
+        assert!(matches!(load("numbers.csv", b"1,2\n3,4\n"), Err(LoadError::NoWordColumn)));
+        assert!(matches!(load("dice.txt", b"11111 apple\nbanana\n"), Err(LoadError::MalformedLine(2))));
+        assert!(matches!(load("control.txt", b"apple\nban\x07ana\n"), Err(LoadError::MalformedLine(2))));
+        assert!(matches!(load("empty.txt", b"# nothing\n\n"), Err(LoadError::Empty)));
+        assert!(matches!(Dictionary::from_path(dir.join("missing.txt")), Err(LoadError::Io(_))));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn test_dictionary_from_url() {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            for (status, body) in [("200 OK", "banana\napple\n"); 4].into_iter().chain([("404 Not Found", "")]) {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).unwrap();
+                let response = format!("HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let dictionary = Dictionary::from_url(&format!("{url}/list.txt"), &FetchOptions::default()).unwrap();
+        assert_eq!(dictionary.all(), ["apple", "banana"]);
+        let checked = FetchOptions {
+            sha256: Some("9F5C0FBE46E1A4D8C3A2FF86B8C1EDDB4DC0F7A1F45FB83B8B5DA5D7A1EC2AC1".into()),
+            ..FetchOptions::default()
+        };
+        assert!(matches!(Dictionary::from_url(&url, &checked), Err(LoadError::ChecksumMismatch)));
+        let hex: String = sha2::Sha256::digest(b"banana\napple\n").iter().map(|b| format!("{:02X}", b)).collect();
+        let checked = FetchOptions { sha256: Some(hex), ..checked };
+        assert_eq!(Dictionary::from_url(&url, &checked).unwrap().all(), dictionary.all());
+        let small = FetchOptions { max_bytes: 8, ..FetchOptions::default() };
+        assert!(matches!(Dictionary::from_url(&url, &small), Err(LoadError::TooLarge(8))));
+        assert!(matches!(Dictionary::from_url(&url, &FetchOptions::default()), Err(LoadError::Http(_))));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_enabled_langs() {
+        assert_eq!(Lang::enabled(), Lang::ALL);
+        for &lang in Lang::enabled() {
+            assert!(Lang::is_enabled(lang.code()));
+            assert!(Lang::is_enabled(&lang.code().to_uppercase()));
+        }
+        assert!(!Lang::is_enabled("xx") && !Lang::is_enabled("") && !Lang::is_enabled("English"));
+        for code in ["de", "en", "es", "fr", "ja", "ru", "zh", "eff-long", "eff-short1", "eff-short2", "en-10k", "en-gb", "en-us"] {
+            assert_eq!(Lang::is_enabled(code), code.parse::<Lang>().is_ok(), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_build_index_matches_words() {
+        for &lang in Lang::enabled() {
+            let words = all(lang);
+            assert!(words.is_sorted(), "{:?}", lang);
+            for &len in lang.lengths() {
+                let expected: Vec<_> = words.iter().copied().filter(|w| w.chars().count() == len).collect();
+                assert_eq!(all_len(len, lang).unwrap(), expected.as_slice(), "{:?} {}", lang, len);
+            }
+            let lengths: usize = lang.lengths().iter().map(|&len| all_len(len, lang).unwrap().len()).sum();
+            assert_eq!(lengths, words.len(), "{:?}", lang);
+            assert_eq!(lang.word_count(), words.len(), "{:?}", lang);
+            let mut initials: Vec<char> = words.iter().filter_map(|w| w.chars().next()).collect();
+            initials.dedup();
+            assert_eq!(lang.initials(), initials.as_slice(), "{:?}", lang);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn test_embed_wordlist() {
+        static WORDS: EmbeddedList = crate::embed_wordlist!("data/eff-long/eff_long.txt");
+        let loaded = Dictionary::from_path("data/eff-long/eff_long.txt").unwrap();
+        assert_eq!(WORDS.all(), loaded.all());
+        assert_eq!(WORDS.all_len(5), loaded.all_len(5));
+        assert_eq!(WORDS.all_starts_with('z'), loaded.all_starts_with('z'));
+        assert!(WORDS.contains(WORDS.get().unwrap()));
+        assert_eq!(WordQuery::new(WORDS.dictionary().clone()).len(5..=5).all().len(), loaded.all_len(5).unwrap().len());
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "en"))]
+    fn test_static_word() {
+        const WORD: &str = crate::static_word!("en", seed = 42);
+        assert_eq!(WORD, seeded(42).get(Lang::En));
+        assert_ne!(crate::static_word!("en", seed = 7), WORD);
+        assert!(contains(crate::static_word!("EN"), Lang::En));
+    }
+
+    #[test]
+    fn test_macros_profanity_matches() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        for code in ["de", "en", "es", "fr", "ja", "ru", "zh"] {
+            let file = format!("{}.txt", code);
+            let ours = std::fs::read_to_string(dir.join("src/profanity").join(&file)).unwrap();
+            let theirs = std::fs::read_to_string(dir.join("macros/src/profanity").join(&file)).unwrap();
+            assert!(ours == theirs, "macros/src/profanity/{} differs", file);
+        }
+    }
+
+    #[test]
+    fn test_expand_front_coded() {
+        // The last word shares only the first byte of a two-byte char.
+        // Hands out one byte per read, so entries span chunk boundaries.
+        struct Trickle<'a>(&'a [u8]);
+        impl std::io::Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = self.0.len().min(buf.len()).min(1);
+                buf[..len].copy_from_slice(&self.0[..len]);
+                self.0 = &self.0[len..];
+                Ok(len)
+            }
+        }
+
+        // The last word shares only the first byte of a two-byte char.
+        let coded: &[u8] = b"\x00car\n\x03t\n\x02t\n\x00\xc3\xa9t\xc3\xa9\n\x03t\xc3\xa9\n\x01\xaa\n";
+        let (text, words) = words::expand(coded, 0, 0);
+        assert_eq!(&*words, ["car", "cart", "cat", "\u{e9}t\u{e9}", "\u{e9}tt\u{e9}", "\u{ea}"]);
+        assert_eq!(text, "car\ncart\ncat\n\u{e9}t\u{e9}\n\u{e9}tt\u{e9}\n\u{ea}\n");
+        assert_eq!(words::expand(Trickle(coded), text.len(), words.len()), (text, words));
+        assert_eq!(&*words::expand(&coded[..coded.len() - 1], 0, 0).1, ["car", "cart", "cat", "\u{e9}t\u{e9}", "\u{e9}tt\u{e9}", "\u{ea}"]);
+        let long = [&[0][..], &[b'x'; 40_000], b"\n"].concat();
+        assert_eq!(words::expand(long.as_slice(), 0, 0).1[0].len(), 40_000);
+        assert!(words::expand(&b""[..], 0, 0).1.is_empty());
+    }
+
+    #[test]
+    fn test_release_indexes() {
+        for &lang in Lang::enabled() {
+            let suggestions = suggest("helo", lang, 2);
+            let rhymes = rhymes_with("station", lang);
+            release_indexes(lang);
+            assert_eq!(suggest("helo", lang, 2), suggestions, "{:?}", lang);
+            assert_eq!(rhymes_with("station", lang), rhymes, "{:?}", lang);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn test_pack() {
+        let path = std::env::temp_dir().join("random_word_test_pack.pack");
+        let custom = Dictionary::new(["zebra", "äpfel", "apple"]);
+        let lang = Lang::enabled()[0];
+        let words = lang.to_dictionary();
+        Pack::write(std::fs::File::create(&path).unwrap(), [(lang.code(), &words), ("custom", &custom)]).unwrap();
+
+        let pack = unsafe { Pack::open(&path) }.unwrap();
+        assert_eq!(pack.codes().collect::<Vec<_>>(), [lang.code(), "custom"]);
+        assert_eq!(pack.dictionary(lang.code()).unwrap().all(), all(lang));
+        assert_eq!(pack.dictionary("custom").unwrap().all(), custom.all());
+        assert!(pack.dictionary("missing").is_none());
+
+        let truncated = std::env::temp_dir().join("random_word_test_pack_truncated.pack");
+        std::fs::write(&truncated, b"RWPACK\0\x01\x01\0\0\0\x02en").unwrap();
+        assert!(matches!(unsafe { Pack::open(&truncated) }, Err(LoadError::InvalidPack)));
+        let mut not_utf8 = b"RWPACK\0\x01\x01\0\0\0\x02en".to_vec();
+        not_utf8.extend(31u64.to_le_bytes());
+        not_utf8.extend(1u64.to_le_bytes());
+        not_utf8.push(0xff);
+        std::fs::write(&truncated, not_utf8).unwrap();
+        assert!(matches!(unsafe { Pack::open(&truncated) }, Err(LoadError::InvalidPack)));
+        std::fs::remove_file(&truncated).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "clean")]
+    fn test_clean_drops_profanity() {
+        for &lang in Lang::enabled() {
+            let profanity = Blocklist::profanity(lang);
+            assert!(!profanity.is_empty(), "{:?}", lang);
+            let words = all(lang);
+            assert!(!words.iter().any(|word| profanity.contains(word)), "{:?}", lang);
+            assert_eq!(lang.word_count(), words.len(), "{:?}", lang);
+            let lengths: usize = lang.lengths().iter().map(|&len| all_len(len, lang).unwrap().len()).sum();
+            assert_eq!(lengths, words.len(), "{:?}", lang);
+            let mut initials: Vec<char> = words.iter().filter_map(|w| w.chars().next()).collect();
+            initials.dedup();
+            assert_eq!(lang.initials(), initials.as_slice(), "{:?}", lang);
+        }
+        #[cfg(feature = "en")]
+        {
+            assert!(!contains("shit", Lang::En) && contains("shiitake", Lang::En));
+            assert!(!all_pos(Pos::Verb, Lang::En).unwrap().contains(&"rape"));
+        }
+    }
+
+    #[test]
+    fn test_dataset_version() {
+        for &lang in Lang::ALL {
+            let version = lang.dataset_version();
+            assert_eq!(version.sha256, sha256(lang));
+            assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+            assert!(!version.source.is_empty());
+            assert_eq!(version.clean, cfg!(feature = "clean"));
+            assert!(version.to_string().contains(version.sha256));
+        }
+        assert!(Lang::En.dataset_version().source.contains("ENABLE"));
+    }
+
+    #[test]
+    #[cfg(feature = "validate")]
+    fn test_validate() {
+        use crate::dictionary::{Fixes, IssueKind, normalize, validate};
+
+        let words = ["apple", "apple", " pear", "Kiwi", "cafe\u{301}", "b2b", "x-ray", "日本 [にほん]"];
+        let issues: Vec<(usize, IssueKind)> = validate(&words).iter().map(|issue| (issue.index, issue.kind)).collect();
+        assert_eq!(
+            issues,
+            [
+                (1, IssueKind::Duplicate),
+                (2, IssueKind::Whitespace),
+                (3, IssueKind::MixedCase),
+                (4, IssueKind::NotNfc),
+                (5, IssueKind::Digit),
+                (6, IssueKind::Punctuation),
+                (7, IssueKind::Punctuation),
+            ]
+        );
+
+        assert_eq!(normalize(&words, Fixes::NONE).len(), words.len() - 1);
+        assert_eq!(
+            normalize(&words, Fixes::default()),
+            ["apple", "pear", "Kiwi", "caf\u{e9}", "b2b", "x-ray", "日本 [にほん]"]
+        );
+        let fixed = normalize(&words, Fixes::ALL);
+        assert_eq!(fixed, ["apple", "pear", "kiwi", "caf\u{e9}"]);
+        assert!(validate(&fixed).is_empty());
+        // Fixes that make words equal leave one of them.
+        assert_eq!(normalize(&["Rose", "rose"], Fixes { lowercase: true, ..Fixes::NONE }), ["rose"]);
+
+        assert!(validate(all(Lang::En)).is_empty());
+    }
+
+    #[test]
+    fn test_lemmas() {
+        let lemmas = all_lemmas(Lang::En).unwrap();
+        assert!(lemmas.is_sorted());
+        assert!(lemmas.len() < all(Lang::En).len());
+        for word in ["run", "city", "bake", "stop", "box", "die", "news", "during", "walker"] {
+            assert_eq!(is_lemma(word, Lang::En), Some(true), "{}", word);
+        }
+        for word in ["runs", "running", "cities", "carried", "baked", "baking", "stopped", "boxes", "dying"] {
+            assert_eq!(is_lemma(word, Lang::En), Some(false), "{}", word);
+        }
+        // Short stems don't count, so "need" isn't "ne" inflected.
+        assert_eq!(is_lemma("need", Lang::En), Some(true));
+
+        let query = WordQuery::new(Lang::En).len(6..=6).lemmas_only();
+        assert!(query.all().iter().all(|word| is_lemma(word, Lang::En) == Some(true)));
+        assert!(query.all().len() < WordQuery::new(Lang::En).len(6..=6).all().len());
+        let dictionary = Dictionary::new(["run", "runs"]);
+        assert!(WordQuery::new(dictionary).lemmas_only().all().is_empty());
+    }
+
     fn test_language_randomness(lang: Lang) {
         let words = all(lang);
         let total_words = words.len();
 
         assert!(total_words >= 100000, "Less than 100,000 words available for {:?}", lang);
+        assert!(words.is_sorted(), "Words are not sorted for {:?}", lang);
 
         let mut seen: AHashSet<&'static str> = AHashSet::new();
         let mut consecutive_duplicates = 0;
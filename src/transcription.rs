@@ -0,0 +1,105 @@
+use crate::Lang;
+use ahash::AHashSet;
+
+/// Letter sequences that look alike in handwriting or small print, as in
+/// "modern" and "modem", or Russian "все" and "всё" when the dots are left
+/// off.
+const LOOKALIKES: &[(&str, &str)] = &[("rn", "m"), ("cl", "d"), ("vv", "w"), ("е", "ё"), ("ш", "щ")];
+
+/// Returns the curated homophone groups of the given language, one
+/// whitespace-separated group per line.
+fn homophones(lang: Lang) -> &'static str {
+    match lang {
+        #[cfg(feature = "de")]
+        Lang::De => include_str!("transcription/de.txt"),
+        #[cfg(feature = "en")]
+        Lang::En => include_str!("transcription/en.txt"),
+        #[cfg(feature = "es")]
+        Lang::Es => include_str!("transcription/es.txt"),
+        #[cfg(feature = "fr")]
+        Lang::Fr => include_str!("transcription/fr.txt"),
+        // Japanese homophones are found from the kana readings instead.
+        #[cfg(feature = "ja")]
+        Lang::Ja => "",
+        #[cfg(feature = "ru")]
+        Lang::Ru => include_str!("transcription/ru.txt"),
+        // The Chinese list has no pinyin to compare.
+        #[cfg(feature = "zh")]
+        Lang::Zh => "",
+        #[cfg(feature = "eff-long")]
+        Lang::EffLong => include_str!("transcription/en.txt"),
+        #[cfg(feature = "eff-short1")]
+        Lang::EffShort1 => include_str!("transcription/en.txt"),
+        #[cfg(feature = "eff-short2")]
+        Lang::EffShort2 => include_str!("transcription/en.txt"),
+        #[cfg(feature = "en-10k")]
+        Lang::En10k => include_str!("transcription/en.txt"),
+        #[cfg(feature = "en-gb")]
+        Lang::EnGb => include_str!("transcription/en.txt"),
+        #[cfg(feature = "en-us")]
+        Lang::EnUs => include_str!("transcription/en.txt"),
+    }
+}
+
+/// Returns the words of the sorted list `words` that are easily confused
+/// with another word when dictated or handwritten: members of a curated
+/// homophone group, words one look-alike letter sequence away from another
+/// word, and Japanese words sharing a reading.
+pub(crate) fn confusable(words: &[&'static str], lang: Lang) -> AHashSet<&'static str> {
+    let find = |word: &str| words.binary_search(&word).ok().map(|i| words[i]);
+    let mut confusable = AHashSet::new();
+    // A homophone is unsafe even when the rest of its group isn't in the
+    // list, since the listener may write any of them.
+    for line in homophones(lang).lines().filter(|line| !line.starts_with('#')) {
+        confusable.extend(line.split_whitespace().filter_map(find));
+    }
+    for &word in words {
+        for other in lookalikes(word, words) {
+            confusable.extend([word, other]);
+        }
+    }
+    #[cfg(feature = "ja")]
+    if lang == Lang::Ja {
+        let mut readings = ahash::AHashMap::new();
+        for &word in words {
+            readings.entry(reading(word)).or_insert_with(Vec::new).push(word);
+        }
+        // The list repeats some entries, which are adjacent once sorted.
+        for mut group in readings.into_values() {
+            group.dedup();
+            if group.len() > 1 {
+                confusable.extend(group);
+            }
+        }
+    }
+    confusable
+}
+
+/// Returns the words of the sorted list `words` one look-alike letter
+/// sequence away from `word`.
+pub(crate) fn lookalikes<'a>(word: &'a str, words: &'a [&'static str]) -> impl Iterator<Item = &'static str> + 'a {
+    LOOKALIKES
+        .iter()
+        .flat_map(|&(a, b)| [(a, b), (b, a)])
+        .flat_map(move |(from, to)| {
+            word.match_indices(from)
+                .map(move |(i, _)| format!("{}{to}{}", &word[..i], &word[i + from.len()..]))
+        })
+        .filter_map(|variant| words.binary_search(&variant.as_str()).ok().map(|i| words[i]))
+}
+
+/// Returns the reading of a Japanese entry, such as "ひろう" for
+/// "拾う [ひろう]", in hiragana.
+#[cfg(feature = "ja")]
+fn reading(word: &str) -> String {
+    let kana = match word.split_once(" [") {
+        Some((_, reading)) => reading.trim_end_matches(']'),
+        None => word,
+    };
+    kana.chars()
+        .map(|c| match c {
+            'ァ'..='ヶ' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
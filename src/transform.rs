@@ -0,0 +1,207 @@
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+/// Digits drawn by [`Transform::InsertDigit`].
+pub(crate) const DIGITS: &[char] = &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Symbols drawn by [`Transform::InsertSymbol`], chosen to be accepted by
+/// most password forms and easy to type on common keyboard layouts.
+pub(crate) const SYMBOLS: &[char] = &['!', '@', '#', '$', '%', '^', '&', '*', '-', '_', '=', '+', '?', '.'];
+
+/// How [`Transform::Capitalize`] changes the case of words.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Capitalize {
+    /// Uppercases the first letter of every word.
+    First,
+    /// Uppercases the first letter of each word with probability 1/2.
+    Random,
+    /// Uppercases every letter of every word.
+    All,
+}
+
+/// How many letters [`leet`](crate::leet) and [`Transform::Leet`] replace.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LeetLevel {
+    /// Replaces a, e, i, o, s and t with 4, 3, 1, 0, 5 and 7.
+    Light,
+    /// Also replaces b, g, l and z with 8, 9, 1 and 2, and uses @, ! and $
+    /// for a, i and s.
+    Heavy,
+}
+
+impl LeetLevel {
+    /// Returns the replacement for `char` at this level, if any.
+    pub(crate) fn replace(self, char: char) -> Option<char> {
+        match (self, char.to_ascii_lowercase()) {
+            (LeetLevel::Heavy, 'a') => Some('@'),
+            (LeetLevel::Heavy, 'i') => Some('!'),
+            (LeetLevel::Heavy, 's') => Some('$'),
+            (LeetLevel::Heavy, 'b') => Some('8'),
+            (LeetLevel::Heavy, 'g') => Some('9'),
+            (LeetLevel::Heavy, 'l') => Some('1'),
+            (LeetLevel::Heavy, 'z') => Some('2'),
+            (_, 'a') => Some('4'),
+            (_, 'e') => Some('3'),
+            (_, 'i') => Some('1'),
+            (_, 'o') => Some('0'),
+            (_, 's') => Some('5'),
+            (_, 't') => Some('7'),
+            _ => None,
+        }
+    }
+}
+
+/// Returns `word` with every letter replaced that `level` replaces.
+pub(crate) fn leet(word: &str, level: LeetLevel) -> String {
+    word.chars().map(|c| level.replace(c).unwrap_or(c)).collect()
+}
+
+/// A change applied to a passphrase after its words are drawn.
+///
+/// Transforms run in order. Each one adds the entropy of its own random
+/// choices to [`Passphrase::entropy_bits`](crate::Passphrase::entropy_bits);
+/// deterministic transforms add none.
+///
+/// # Example
+/// ```
+/// use random_word::{Capitalize, Lang, Passphrase, PassphraseOptions, Transform};
+/// let passphrase = Passphrase::generate(PassphraseOptions {
+///     transforms: vec![Transform::Capitalize(Capitalize::First), Transform::InsertDigit],
+///     ..Default::default()
+/// });
+/// let text = passphrase.to_string();
+/// assert!(text.chars().any(|c| c.is_ascii_uppercase()));
+/// assert!(text.chars().any(|c| c.is_ascii_digit()));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Transform {
+    /// Changes the case of words.
+    Capitalize(Capitalize),
+    /// Appends a random digit to a randomly chosen word.
+    InsertDigit,
+    /// Appends a random symbol to a randomly chosen word.
+    InsertSymbol,
+    /// Replaces every separator with a random digit or symbol, drawn
+    /// independently for each gap.
+    RandomSeparator,
+    /// Replaces letters with look-alike digits and symbols. Without
+    /// `random` every replaceable letter is replaced, which adds no
+    /// entropy; with it each is replaced with probability 1/2, adding a bit
+    /// per replaceable letter.
+    Leet {
+        /// Which letters are replaceable.
+        level: LeetLevel,
+        /// Whether each replacement is a coin flip.
+        random: bool,
+    },
+}
+
+impl Transform {
+    /// Applies the transform to the rendered words and separators of a
+    /// passphrase, returning the entropy in bits it added.
+    pub(crate) fn apply(self, words: &mut [String], separators: &mut [String], rng: &mut impl Rng) -> f64 {
+        match self {
+            Transform::Capitalize(Capitalize::First) => {
+                for word in words {
+                    let capitalized = capitalize_first(word);
+                    replace(word, capitalized);
+                }
+                0.0
+            }
+            Transform::Capitalize(Capitalize::Random) => {
+                let mut bits = 0.0;
+                for word in words {
+                    let capitalized = capitalize_first(word);
+                    // Words without a cased first letter gain nothing.
+                    if capitalized != *word {
+                        bits += 1.0;
+                        if rng.random() {
+                            replace(word, capitalized);
+                            continue;
+                        }
+                    }
+                    discard(capitalized);
+                }
+                bits
+            }
+            Transform::Capitalize(Capitalize::All) => {
+                for word in words {
+                    let upper = word.to_uppercase();
+                    replace(word, upper);
+                }
+                0.0
+            }
+            Transform::InsertDigit => append_random(words, DIGITS, rng),
+            Transform::InsertSymbol => append_random(words, SYMBOLS, rng),
+            Transform::RandomSeparator => {
+                let choices = [DIGITS, SYMBOLS].concat();
+                for separator in separators.iter_mut() {
+                    replace(separator, choices.choose(rng).expect("non-empty").to_string());
+                }
+                separators.len() as f64 * (choices.len() as f64).log2()
+            }
+            Transform::Leet { level, random: false } => {
+                for word in words {
+                    let leeted = leet(word, level);
+                    replace(word, leeted);
+                }
+                0.0
+            }
+            Transform::Leet { level, random: true } => {
+                let mut bits = 0.0;
+                for word in words {
+                    let leeted = word
+                        .chars()
+                        .map(|c| match level.replace(c) {
+                            Some(replacement) => {
+                                bits += 1.0;
+                                if rng.random() { replacement } else { c }
+                            }
+                            None => c,
+                        })
+                        .collect();
+                    replace(word, leeted);
+                }
+                bits
+            }
+        }
+    }
+}
+
+/// Replaces `text` with `new`, wiping the old text with the `zeroize`
+/// feature.
+fn replace(text: &mut String, new: String) {
+    discard(std::mem::replace(text, new));
+}
+
+/// Drops `text`, wiping it first with the `zeroize` feature.
+#[cfg_attr(not(feature = "zeroize"), allow(unused_mut, unused_variables))]
+pub(crate) fn discard(mut text: String) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut text);
+}
+
+fn capitalize_first(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Appends a character from `choices` to a random word, returning the
+/// entropy of both choices.
+fn append_random(words: &mut [String], choices: &[char], rng: &mut impl Rng) -> f64 {
+    if words.is_empty() {
+        return 0.0;
+    }
+    let index = rng.random_range(0..words.len());
+    let char = *choices.choose(rng).expect("non-empty");
+    // Build the longer word in a new buffer rather than letting `push`
+    // reallocate, so no copy is freed without being wiped.
+    let mut appended = String::with_capacity(words[index].len() + char.len_utf8());
+    appended.push_str(&words[index]);
+    appended.push(char);
+    replace(&mut words[index], appended);
+    (words.len() as f64 * choices.len() as f64).log2()
+}
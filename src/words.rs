@@ -1,13 +1,396 @@
 macro_rules! generate_word_db {
-    ($($feat:literal => $file_stem:ident : $EnumVariant:ident : $name:expr),* $(,)?) => {
-        use ahash::AHashMap;
-        use brotli::Decompressor;
-        use std::io::{Cursor, Read};
-        use std::sync::OnceLock;
+    ($($feat:literal => $file_stem:ident : $EnumVariant:ident : $name:literal),* $(,)?) => {
+        use crate::bktree::BkTree;
+        use ahash::{AHashMap, AHashSet};
+        use std::sync::{Arc, OnceLock, PoisonError, RwLock};
 
         pub(crate) type Words = Box<[&'static str]>;
+        pub(crate) type Trigram = [char; 3];
 
-        #[doc = "ISO 639-1 language codes.\n\nEach variant corresponds to a set of words included in the binary.\n\nYou **MUST** enable the corresponding crate feature.\n"]
+        /// The brotli decoder's buffer size. Decoding the largest lists is
+        /// about 10% faster than with 4 KiB; larger buffers gain nothing.
+        #[cfg(feature = "brotli")]
+        pub(crate) const DECODE_BUFFER_SIZE: usize = 16 * 1024;
+
+        /// Returns a reader decompressing embedded data, compressed with
+        /// brotli or, under the `zstd` feature, with zstd.
+        pub(crate) fn try_decoder(raw: &[u8]) -> std::io::Result<impl std::io::Read + '_> {
+            #[cfg(feature = "zstd")]
+            return zstd::stream::read::Decoder::with_buffer(raw);
+            #[cfg(all(feature = "brotli", not(feature = "zstd")))]
+            return Ok(brotli::Decompressor::new(raw, DECODE_BUFFER_SIZE));
+            // Only the `compile_error!` in lib.rs is reported.
+            #[cfg(not(any(feature = "brotli", feature = "zstd")))]
+            Ok(raw)
+        }
+
+        /// Like [`try_decoder`], for data embedded by this crate, which is
+        /// checked when built.
+        pub(crate) fn decoder(raw: &[u8]) -> impl std::io::Read + '_ {
+            try_decoder(raw).expect("Decompression failed")
+        }
+
+        /// Decompresses embedded data into a buffer.
+        pub(crate) fn try_decompress_bytes(raw: &[u8]) -> std::io::Result<Vec<u8>> {
+            use std::io::Read;
+            let mut bytes = Vec::new();
+            try_decoder(raw)?.read_to_end(&mut bytes)?;
+            Ok(bytes)
+        }
+
+        /// Like [`try_decompress_bytes`], panicking on corrupt data.
+        pub(crate) fn decompress_bytes(raw: &[u8]) -> Vec<u8> {
+            try_decompress_bytes(raw).expect("Decompression failed")
+        }
+
+        /// Returns the error reported for data that decompresses but doesn't
+        /// parse.
+        fn invalid_data(message: &'static str) -> std::io::Error {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+        }
+
+        /// Decompresses embedded data into UTF-8 text.
+        #[cfg_attr(
+            not(any(feature = "en", feature = "en-10k", feature = "_bip39", feature = "zh")),
+            allow(dead_code)
+        )]
+        pub(crate) fn decompress(raw: &[u8]) -> String {
+            String::from_utf8(decompress_bytes(raw))
+                .expect("Decompression resulted in invalid UTF-8")
+        }
+
+        /// Folds a character to the key used by case-insensitive indexes.
+        #[inline(always)]
+        pub(crate) fn fold(ch: char) -> char {
+            ch.to_lowercase().next().unwrap_or(ch)
+        }
+
+        /// Orders words as if both were lowercased, without allocating.
+        #[inline(always)]
+        pub(crate) fn cmp_folded(a: &str, b: &str) -> std::cmp::Ordering {
+            a.chars()
+                .flat_map(char::to_lowercase)
+                .cmp(b.chars().flat_map(char::to_lowercase))
+        }
+
+        /// Orders words by their characters read from the end, so words
+        /// sharing a suffix are adjacent.
+        #[inline(always)]
+        pub(crate) fn cmp_reversed(a: &str, b: &str) -> std::cmp::Ordering {
+            a.chars().rev().cmp(b.chars().rev())
+        }
+
+        /// Returns a bitmask with one bit per distinct character of `word`,
+        /// hashed into 64 bits. Words whose signature has bits outside another
+        /// signature cannot be made from its characters.
+        #[inline(always)]
+        pub(crate) fn signature(word: &str) -> u64 {
+            word.chars().fold(0, |sig, ch| sig | 1 << (ch as u32 % 64))
+        }
+
+        /// Returns the characters of `word` in sorted order, which is shared by
+        /// all of its anagrams.
+        #[inline(always)]
+        pub(crate) fn anagram_key(word: &str) -> Box<str> {
+            let mut chars: Vec<char> = word.chars().collect();
+            chars.sort_unstable();
+            chars.into_iter().collect()
+        }
+
+        /// Returns the words of a byte-wise sorted list starting with `prefix`,
+        /// which are always contiguous.
+        pub(crate) fn prefix_range<'a>(words: &'a [&'static str], prefix: &str) -> &'a [&'static str] {
+            let start = words.partition_point(|word| *word < prefix);
+            let len = words[start..].partition_point(|word| word.starts_with(prefix));
+            &words[start..start + len]
+        }
+
+        /// Expands a front-coded word list, as written by build.rs and
+        /// `embed_wordlist!`, while reading it from `coded`: each word is a
+        /// byte counting the leading bytes it shares with the previous word,
+        /// then the rest of the word and a `\n`. Returns the words each
+        /// followed by a `\n`, and the words themselves, sliced from that
+        /// text by their offsets.
+        ///
+        /// `text_len` and `count` size the text and offsets up front when
+        /// known, so that neither grows; the decompressed list is never held
+        /// whole, only a chunk of it at a time.
+        pub(crate) fn expand(coded: impl std::io::Read, text_len: usize, count: usize) -> (&'static str, Words) {
+            let (text, ends) = try_expand(coded, text_len, count).expect("Decompression failed");
+            let text: &'static str = Box::leak(text.into_boxed_str());
+            (text, split_words(text, &ends).collect())
+        }
+
+        /// Like [`expand`], returning the text and the byte offset where
+        /// each word ends, or an error for data that doesn't decompress or
+        /// isn't UTF-8.
+        pub(crate) fn try_expand(
+            mut coded: impl std::io::Read,
+            text_len: usize,
+            count: usize,
+        ) -> std::io::Result<(String, Vec<u32>)> {
+            let mut text = Vec::with_capacity(text_len);
+            let mut ends: Vec<u32> = Vec::with_capacity(count);
+            let mut chunk = vec![0; 16 * 1024];
+            let mut filled = 0;
+            let mut previous = 0;
+            loop {
+                if filled == chunk.len() {
+                    // A single entry is longer than the chunk.
+                    chunk.resize(chunk.len() * 2, 0);
+                }
+                let read = match coded.read(&mut chunk[filled..]) {
+                    Ok(read) => read,
+                    Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(err),
+                };
+                filled += read;
+                let mut rest = &chunk[..filled];
+                while let Some((&shared, tail)) = rest.split_first() {
+                    // At the end of the data, the last entry may lack its `\n`.
+                    let Some(len) = tail.iter().position(|&b| b == b'\n').or((read == 0).then_some(tail.len())) else {
+                        break;
+                    };
+                    let start = text.len();
+                    let shared = previous + usize::from(shared);
+                    if shared > start {
+                        return Err(invalid_data("a word shares more bytes than the previous one has"));
+                    }
+                    text.extend_from_within(previous..shared);
+                    text.extend_from_slice(&tail[..len]);
+                    ends.push(u32::try_from(text.len()).map_err(|_| invalid_data("word lists are smaller than 4 GiB"))?);
+                    text.push(b'\n');
+                    previous = start;
+                    rest = tail.get(len + 1..).unwrap_or_default();
+                }
+                let consumed = filled - rest.len();
+                chunk.copy_within(consumed..filled, 0);
+                filled -= consumed;
+                if read == 0 {
+                    break;
+                }
+            }
+            let text = String::from_utf8(text).map_err(|_| invalid_data("Decompression resulted in invalid UTF-8"))?;
+            Ok((text, ends))
+        }
+
+        /// Slices the words out of text expanded by [`try_expand`], given
+        /// where each ends.
+        fn split_words<'a>(text: &'a str, ends: &[u32]) -> impl Iterator<Item = &'a str> {
+            ends.iter().scan(0, |start, &end| {
+                let end = end as usize;
+                Some(&text[std::mem::replace(start, end + 1)..end])
+            })
+        }
+
+        /// Decompresses and expands the word list `raw` afresh, apart from
+        /// the cached index, and returns whether its words hash to `sha256`
+        /// and agree with the precomputed index `raw_index`. Data that
+        /// doesn't decompress or parse is an error rather than a panic.
+        pub(crate) fn check(raw: &[u8], raw_index: &[u8], sha256: &str) -> std::io::Result<bool> {
+            use sha2::{Digest, Sha256};
+            let index = try_decompress_bytes(raw_index)?;
+            let (text_len, initials, char_lens) = split_index(&index)?;
+            let (text, ends) = try_expand(try_decoder(raw)?, text_len, char_lens.len())?;
+            let hex: String = Sha256::digest(text.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+            if hex != sha256 || text.len() != text_len || ends.len() != char_lens.len() {
+                return Ok(false);
+            }
+            let mut first: Vec<char> = split_words(&text, &ends).filter_map(|word| word.chars().next()).collect();
+            first.dedup();
+            Ok(*first == *initials
+                && split_words(&text, &ends).zip(char_lens).all(|(word, &len)| word.chars().count() == usize::from(len)))
+        }
+
+        /// Word indexes computed by the build script when the list was
+        /// compressed, so that only decompression is left for run time.
+        pub(crate) struct Index {
+            /// The words, in byte-wise order.
+            words: Words,
+            /// `by_len[n]` holds the words `n` chars long.
+            by_len: Box<[Words]>,
+        }
+
+        /// What the precomputed index tells about a list without its words.
+        pub(crate) struct Meta {
+            /// The number of words.
+            count: usize,
+            /// Every length with at least one word, in ascending order.
+            lengths: Box<[usize]>,
+            /// Every first character, in ascending order.
+            initials: Box<[char]>,
+        }
+
+        /// Reads a little-endian `u32` from the front of `bytes`.
+        fn take_u32(bytes: &mut &[u8]) -> std::io::Result<u32> {
+            let (value, rest) = bytes.split_first_chunk::<4>().ok_or_else(|| invalid_data("truncated word index"))?;
+            *bytes = rest;
+            Ok(u32::from_le_bytes(*value))
+        }
+
+        /// Splits a decompressed index, in the layout written by
+        /// `write_index` in build.rs, into the length in bytes of the
+        /// expanded words, the first characters and the length in chars of
+        /// each word.
+        fn split_index(mut bytes: &[u8]) -> std::io::Result<(usize, Box<[char]>, &[u8])> {
+            let text_len = take_u32(&mut bytes)? as usize;
+            let initials = (0..take_u32(&mut bytes)?)
+                .map(|_| char::from_u32(take_u32(&mut bytes)?).ok_or_else(|| invalid_data("invalid initial")))
+                .collect::<std::io::Result<_>>()?;
+            Ok((text_len, initials, bytes))
+        }
+
+        impl Index {
+            /// Reads the compressed word list `raw` and its compressed
+            /// index `raw_index`, keeping the words for which `keep` is true.
+            fn read(raw: &[u8], raw_index: &[u8], keep: impl Fn(&str) -> bool) -> Self {
+                let bytes = decompress_bytes(raw_index);
+                let (text_len, _, char_lens) = split_index(&bytes).expect("invalid word index");
+                let (_, words) = expand(decoder(raw), text_len, char_lens.len());
+
+                let mut by_len: Vec<Vec<&'static str>> = Vec::new();
+                let mut char_lens = char_lens.iter();
+                let mut words = words.into_vec();
+                // `retain` visits the words in order, in step with their lengths.
+                words.retain(|&word| {
+                    let len = usize::from(*char_lens.next().expect("index is shorter than the list"));
+                    if !keep(word) {
+                        return false;
+                    }
+                    if len >= by_len.len() {
+                        by_len.resize_with(len + 1, Vec::new);
+                    }
+                    by_len[len].push(word);
+                    true
+                });
+                Self {
+                    words: words.into_boxed_slice(),
+                    by_len: by_len.into_iter().map(Words::from).collect(),
+                }
+            }
+        }
+
+        impl Meta {
+            /// Reads the compressed index `raw_index` alone, without
+            /// decompressing the words.
+            #[cfg(not(feature = "clean"))]
+            fn read(raw_index: &[u8]) -> Self {
+                let bytes = decompress_bytes(raw_index);
+                let (_, initials, char_lens) = split_index(&bytes).expect("invalid word index");
+                let mut present = [false; 256];
+                for &len in char_lens {
+                    present[usize::from(len)] = true;
+                }
+                Self {
+                    count: char_lens.len(),
+                    lengths: (0..present.len()).filter(|&len| present[len]).collect(),
+                    initials,
+                }
+            }
+
+            /// Decompresses the word list `raw` to count the words for which
+            /// `keep` is true, then drops it again, so that the list is only
+            /// held if words are asked for. Under the `lean` feature.
+            #[cfg(all(feature = "clean", feature = "lean"))]
+            fn filtered(raw: &[u8], raw_index: &[u8], keep: impl Fn(&str) -> bool) -> Self {
+                let bytes = decompress_bytes(raw_index);
+                let (text_len, _, char_lens) = split_index(&bytes).expect("invalid word index");
+                let (text, ends) = try_expand(decoder(raw), text_len, char_lens.len()).expect("Decompression failed");
+                let (mut count, mut present, mut initials) = (0, [false; 256], Vec::new());
+                for (word, &len) in split_words(&text, &ends).zip(char_lens) {
+                    if !keep(word) {
+                        continue;
+                    }
+                    count += 1;
+                    present[usize::from(len)] = true;
+                    initials.extend(word.chars().next());
+                }
+                initials.dedup();
+                Self {
+                    count,
+                    lengths: (0..present.len()).filter(|&len| present[len]).collect(),
+                    initials: initials.into_boxed_slice(),
+                }
+            }
+
+            /// Reads the words kept in `index`, which the precomputed index
+            /// doesn't describe once profanity is dropped.
+            #[cfg(feature = "clean")]
+            fn of(index: &Index) -> Self {
+                let mut initials: Vec<char> = index.words.iter().filter_map(|word| word.chars().next()).collect();
+                initials.dedup();
+                Self {
+                    count: index.words.len(),
+                    lengths: (0..index.by_len.len()).filter(|&len| !index.by_len[len].is_empty()).collect(),
+                    initials: initials.into_boxed_slice(),
+                }
+            }
+        }
+
+        /// A lazily built index that [`release_indexes`](crate::release_indexes)
+        /// can drop again, for indexes that are never lent out as `&'static`
+        /// references. Callers hold an [`Arc`] while they use it.
+        pub(crate) struct Releasable<T>(RwLock<Option<Arc<T>>>);
+
+        impl<T> Releasable<T> {
+            pub(crate) const fn new() -> Self {
+                Self(RwLock::new(None))
+            }
+
+            /// Returns the index, building it with `init` if it isn't built.
+            pub(crate) fn get_or_init(&self, init: impl FnOnce() -> T) -> Arc<T> {
+                if let Some(index) = &*self.0.read().unwrap_or_else(PoisonError::into_inner) {
+                    return Arc::clone(index);
+                }
+                let mut slot = self.0.write().unwrap_or_else(PoisonError::into_inner);
+                Arc::clone(slot.get_or_insert_with(|| Arc::new(init())))
+            }
+
+            /// Drops the index, returning whether it was built. Callers
+            /// still holding it keep their copy until they are done.
+            pub(crate) fn release(&self) -> bool {
+                self.0.write().unwrap_or_else(PoisonError::into_inner).take().is_some()
+            }
+        }
+
+        fn len_index_by(words: &[&'static str], measure: fn(&str) -> usize) -> AHashMap<usize, Words> {
+            let mut map = AHashMap::new();
+            for &word in words {
+                map.entry(measure(word)).or_insert_with(Vec::new).push(word);
+            }
+            map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
+        }
+
+        /// Groups words by `key`, skipping words without one.
+        fn key_index(words: &[&'static str], key: fn(&str) -> Option<Box<str>>) -> AHashMap<Box<str>, Words> {
+            let mut map = AHashMap::new();
+            for &word in words {
+                if let Some(key) = key(word) {
+                    map.entry(key).or_insert_with(Vec::new).push(word);
+                }
+            }
+            map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
+        }
+
+        fn trigram_index(words: &[&'static str]) -> AHashMap<Trigram, Box<[u32]>> {
+            let mut map: AHashMap<Trigram, Vec<u32>> = AHashMap::new();
+            let mut chars = Vec::new();
+            for (i, word) in words.iter().enumerate() {
+                chars.clear();
+                chars.extend(word.chars());
+                for window in chars.windows(3) {
+                    let postings = map.entry([window[0], window[1], window[2]]).or_default();
+                    // Words are visited in order, so a repeated trigram in the
+                    // same word is always the last posting.
+                    if postings.last() != Some(&(i as u32)) {
+                        postings.push(i as u32);
+                    }
+                }
+            }
+            map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
+        }
+
+        #[doc = "ISO 639-1 language codes, and curated English word lists.\n\nEach variant corresponds to a set of words included in the binary.\n\nYou **MUST** enable the corresponding crate feature.\n"]
         #[doc = concat!(
             "# Variants\n\n",
             $(
@@ -18,93 +401,720 @@ macro_rules! generate_word_db {
         pub enum Lang {
             $(
                 #[cfg(feature = $feat)]
-                #[doc = concat!("ISO 639-1 language code for ", $name, " (feature = \"", $feat, "\")")]
+                #[doc = concat!($name, " words (feature = \"", $feat, "\")")]
                 $EnumVariant,
             )*
         }
 
+        impl Lang {
+            /// Every language enabled by crate features, in declaration order.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// assert!(Lang::ALL.contains(&Lang::En));
+            /// ```
+            pub const ALL: &'static [Lang] = &[
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant,
+                )*
+            ];
+
+            /// Returns every language compiled in, in declaration order, such
+            /// as to fill a language picker. The same as [`Lang::ALL`].
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// let codes: Vec<&str> = Lang::enabled().iter().map(|lang| lang.code()).collect();
+            /// assert!(codes.contains(&"en"));
+            /// ```
+            #[inline(always)]
+            pub const fn enabled() -> &'static [Lang] {
+                Self::ALL
+            }
+
+            /// Returns whether the language with the given feature name,
+            /// such as `"en"` or `"eff-long"`, is compiled in. Codes are
+            /// matched case-insensitively, and unknown codes are not enabled.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// assert!(Lang::is_enabled("en"));
+            /// assert!(!Lang::is_enabled("xx"));
+            /// ```
+            pub fn is_enabled(code: &str) -> bool {
+                Self::ALL.iter().any(|lang| lang.code().eq_ignore_ascii_case(code))
+            }
+
+            /// Returns the name of this language's crate feature, which is its
+            /// ISO 639-1 code for natural languages.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// assert_eq!(Lang::En.code(), "en");
+            /// ```
+            pub const fn code(self) -> &'static str {
+                match self {
+                    $(
+                        #[cfg(feature = $feat)]
+                        Lang::$EnumVariant => $feat,
+                    )*
+                }
+            }
+
+            /// Returns the English name of this language.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// assert_eq!(Lang::En.name(), "English");
+            /// ```
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(
+                        #[cfg(feature = $feat)]
+                        Lang::$EnumVariant => $name,
+                    )*
+                }
+            }
+
+            /// Returns the version, hash and source of this language's word
+            /// list, to detect or pin the exact words in use.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// let version = Lang::En.dataset_version();
+            /// assert!(version.source.contains("ENABLE"));
+            /// ```
+            pub fn dataset_version(self) -> crate::DatasetVersion {
+                match self {
+                    $(
+                        #[cfg(feature = $feat)]
+                        Lang::$EnumVariant => paste::paste! {
+                            crate::DatasetVersion {
+                                version: [<random_word_data_ $file_stem>]::VERSION,
+                                sha256: [<random_word_data_ $file_stem>]::SHA256,
+                                source: [<random_word_data_ $file_stem>]::SOURCE,
+                                clean: cfg!(feature = "clean"),
+                            }
+                        },
+                    )*
+                }
+            }
+
+            /// Returns the number of words in this language, read from the
+            /// precomputed index without decompressing the words.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// assert_eq!(Lang::En.word_count(), random_word::all(Lang::En).len());
+            /// ```
+            pub fn word_count(self) -> usize {
+                match self {
+                    $(
+                        #[cfg(feature = $feat)]
+                        Lang::$EnumVariant => paste::paste! {
+                            [<$file_stem _meta>]().count
+                        },
+                    )*
+                }
+            }
+
+            /// Returns every word length in characters that has at least one
+            /// word, in ascending order. Lengths not listed make
+            /// `random_word::get_len` return `None`.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// let lengths = Lang::En.lengths();
+            /// assert!(lengths.is_sorted());
+            /// assert!(lengths.iter().all(|&len| random_word::get_len(len, Lang::En).is_some()));
+            /// ```
+            pub fn lengths(self) -> &'static [usize] {
+                match self {
+                    $(
+                        #[cfg(feature = $feat)]
+                        Lang::$EnumVariant => paste::paste! {
+                            &[<$file_stem _meta>]().lengths
+                        },
+                    )*
+                }
+            }
+
+            /// Returns every character that starts at least one word, in
+            /// ascending order. Characters not listed make
+            /// `random_word::get_starts_with` return `None`.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::Lang;
+            /// let initials = Lang::En.initials();
+            /// assert!(initials.contains(&'q'));
+            /// assert!(initials.iter().all(|&c| random_word::get_starts_with(c, Lang::En).is_some()));
+            /// ```
+            pub fn initials(self) -> &'static [char] {
+                match self {
+                    $(
+                        #[cfg(feature = $feat)]
+                        Lang::$EnumVariant => paste::paste! {
+                            &[<$file_stem _meta>]().initials
+                        },
+                    )*
+                }
+            }
+
+            /// Returns the words of this language as a [`Dictionary`](crate::Dictionary),
+            /// to combine with other lists.
+            ///
+            /// # Example
+            /// ```
+            /// use random_word::{Dictionary, Lang};
+            /// let blocked = Dictionary::new(["horse", "battery"]);
+            /// let allowed = Lang::En.to_dictionary().difference(&blocked);
+            /// assert_eq!(allowed.len(), Lang::En.word_count() - 2);
+            /// ```
+            #[inline(always)]
+            pub fn to_dictionary(self) -> crate::Dictionary {
+                crate::Dictionary::from_words(get(self).iter().copied())
+            }
+        }
+
+        impl std::fmt::Display for Lang {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.code())
+            }
+        }
+
+        /// Parses an ISO 639-1 code such as `"en"` or an English name such as
+        /// `"English"`, ignoring ASCII case.
+        ///
+        /// # Example
+        /// ```
+        /// use random_word::{Error, Lang};
+        /// assert_eq!("en".parse(), Ok(Lang::En));
+        /// assert_eq!("English".parse(), Ok(Lang::En));
+        /// assert_eq!("xx".parse::<Lang>(), Err(Error::UnknownLang));
+        /// ```
+        impl std::str::FromStr for Lang {
+            type Err = crate::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case($feat) || s.eq_ignore_ascii_case($name) {
+                        #[cfg(feature = $feat)]
+                        return Ok(Lang::$EnumVariant);
+                        #[cfg(not(feature = $feat))]
+                        return Err(crate::Error::LangNotEnabled($feat));
+                    }
+                )*
+                Err(crate::Error::UnknownLang)
+            }
+        }
+
+        impl TryFrom<&str> for Lang {
+            type Error = crate::Error;
+
+            #[inline(always)]
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
         $(
             #[cfg(feature = $feat)]
             paste::paste! {
-                static [<$file_stem:upper _COMPRESSED>]: OnceLock<String> = OnceLock::new();
-                static [<$file_stem:upper>]: OnceLock<Words> = OnceLock::new();
-                static [<$file_stem:upper _LEN>]: OnceLock<AHashMap<usize, Words>> = OnceLock::new();
-                static [<$file_stem:upper _STARTS_WITH>]: OnceLock<AHashMap<char, Words>> = OnceLock::new();
+                static [<$file_stem:upper _RAW>]: &[u8] = [<random_word_data_ $file_stem>]::RAW;
+                static [<$file_stem:upper _INDEX>]: OnceLock<Index> = OnceLock::new();
+                static [<$file_stem:upper _META>]: OnceLock<Meta> = OnceLock::new();
+                static [<$file_stem:upper _SORTED_FOLDED>]: OnceLock<Words> = OnceLock::new();
+                static [<$file_stem:upper _BYTE_LEN>]: OnceLock<AHashMap<usize, Words>> = OnceLock::new();
+                #[cfg(feature = "graphemes")]
+                static [<$file_stem:upper _GRAPHEME_LEN>]: OnceLock<AHashMap<usize, Words>> = OnceLock::new();
+                static [<$file_stem:upper _HEADWORD_LEN>]: OnceLock<AHashMap<usize, Words>> = OnceLock::new();
+                static [<$file_stem:upper _STARTS_WITH_FOLDED>]: OnceLock<AHashMap<char, Words>> = OnceLock::new();
+                static [<$file_stem:upper _ENDS_WITH>]: OnceLock<AHashMap<char, Words>> = OnceLock::new();
+                static [<$file_stem:upper _SORTED_REVERSED>]: OnceLock<Words> = OnceLock::new();
+                static [<$file_stem:upper _DISTINCT>]: Releasable<Words> = Releasable::new();
+                static [<$file_stem:upper _ANAGRAMS>]: OnceLock<AHashMap<Box<str>, Words>> = OnceLock::new();
+                static [<$file_stem:upper _SIGNATURES>]: Releasable<Box<[u64]>> = Releasable::new();
+                static [<$file_stem:upper _TRIGRAMS>]: Releasable<AHashMap<Trigram, Box<[u32]>>> = Releasable::new();
+                static [<$file_stem:upper _SYLLABLES>]: OnceLock<AHashMap<usize, Words>> = OnceLock::new();
+                static [<$file_stem:upper _RHYMES>]: Releasable<AHashMap<Box<str>, Words>> = Releasable::new();
+                static [<$file_stem:upper _SOUNDS>]: Releasable<AHashMap<Box<str>, Words>> = Releasable::new();
+                static [<$file_stem:upper _MAJOR>]: OnceLock<AHashMap<Box<str>, Words>> = OnceLock::new();
+                static [<$file_stem:upper _BK_TREE>]: Releasable<BkTree> = Releasable::new();
+                static [<$file_stem:upper _CONFUSABLE>]: Releasable<AHashSet<&'static str>> = Releasable::new();
+
+                /// Returns whether the `clean` feature keeps a word.
+                fn [<$file_stem _keep>]() -> impl Fn(&str) -> bool {
+                    #[cfg(feature = "clean")]
+                    return {
+                        let profanity = crate::profanity::Profanity::of(Lang::$EnumVariant);
+                        move |word: &str| !profanity.matches(word)
+                    };
+                    #[cfg(not(feature = "clean"))]
+                    |_: &str| true
+                }
+
+                fn [<init_ $file_stem _index>]() -> Index {
+                    Index::read([<$file_stem:upper _RAW>], [<random_word_data_ $file_stem>]::INDEX, [<$file_stem _keep>]())
+                }
 
-                fn [<init_ $file_stem _compressed>]() -> String {
-                    let compressed_bytes = include_bytes!(concat!("br/", stringify!($file_stem), ".br"));
-                    let cursor = Cursor::new(compressed_bytes);
-                    let mut decompressor = Decompressor::new(cursor, 4096);
+                fn [<$file_stem _index>]() -> &'static Index {
+                    [<$file_stem:upper _INDEX>].get_or_init([<init_ $file_stem _index>])
+                }
 
-                    let mut decompressed_bytes = Vec::new();
-                    decompressor.read_to_end(&mut decompressed_bytes).expect("Decompression failed");
+                fn [<$file_stem _meta>]() -> &'static Meta {
+                    #[cfg(all(feature = "clean", feature = "lean"))]
+                    return [<$file_stem:upper _META>].get_or_init(|| match [<$file_stem:upper _INDEX>].get() {
+                        Some(index) => Meta::of(index),
+                        None => Meta::filtered(
+                            [<$file_stem:upper _RAW>],
+                            [<random_word_data_ $file_stem>]::INDEX,
+                            [<$file_stem _keep>](),
+                        ),
+                    });
+                    #[cfg(all(feature = "clean", not(feature = "lean")))]
+                    return [<$file_stem:upper _META>].get_or_init(|| Meta::of([<$file_stem _index>]()));
+                    #[cfg(not(feature = "clean"))]
+                    [<$file_stem:upper _META>].get_or_init(|| Meta::read([<random_word_data_ $file_stem>]::INDEX))
+                }
 
-                    String::from_utf8(decompressed_bytes)
-                        .expect("Decompression resulted in invalid UTF-8")
+                fn [<init_ $file_stem _sorted_folded>]() -> Words {
+                    let mut words = [<$file_stem _index>]().words.clone();
+                    words.sort_unstable_by(|a, b| cmp_folded(a, b));
+                    words
                 }
 
-                fn [<init_ $file_stem>]() -> Words {
-                    [<$file_stem:upper _COMPRESSED>]
-                        .get_or_init([<init_ $file_stem _compressed>])
-                        .lines()
-                        .collect()
+                fn [<init_ $file_stem _sorted_reversed>]() -> Words {
+                    let mut words = [<$file_stem _index>]().words.clone();
+                    words.sort_unstable_by(|a, b| cmp_reversed(a, b));
+                    words
                 }
 
-                fn [<init_ $file_stem _len>]() -> AHashMap<usize, Words> {
+                fn [<init_ $file_stem _distinct>]() -> Words {
+                    let mut words = [<$file_stem _index>]().words.to_vec();
+                    words.dedup();
+                    words.into_boxed_slice()
+                }
+
+                fn [<init_ $file_stem _byte_len>]() -> AHashMap<usize, Words> {
+                    len_index_by(&[<$file_stem _index>]().words, str::len)
+                }
+
+                #[cfg(feature = "graphemes")]
+                fn [<init_ $file_stem _grapheme_len>]() -> AHashMap<usize, Words> {
+                    use unicode_segmentation::UnicodeSegmentation;
+                    len_index_by(&[<$file_stem _index>]().words, |word| {
+                        word.graphemes(true).count()
+                    })
+                }
+
+                fn [<init_ $file_stem _headword_len>]() -> AHashMap<usize, Words> {
+                    len_index_by(&[<$file_stem _index>]().words, |word| crate::LengthUnit::Headword.measure(word))
+                }
+
+                fn [<init_ $file_stem _syllables>]() -> AHashMap<usize, Words> {
+                    let mut map = len_index_by(&[<$file_stem _index>]().words, |word| {
+                        crate::phonetics::syllables(word, Lang::$EnumVariant).unwrap_or(0)
+                    });
+                    // Words without an estimate.
+                    map.remove(&0);
+                    map
+                }
+
+                fn [<init_ $file_stem _rhymes>]() -> AHashMap<Box<str>, Words> {
+                    key_index(&[<$file_stem _index>]().words, |word| {
+                        crate::phonetics::rhyme_key(word, Lang::$EnumVariant)
+                    })
+                }
+
+                fn [<init_ $file_stem _sounds>]() -> AHashMap<Box<str>, Words> {
+                    key_index(&[<$file_stem _index>]().words, |word| {
+                        crate::phonetics::phonetic_key(word, Lang::$EnumVariant)
+                    })
+                }
+
+                fn [<init_ $file_stem _major>]() -> AHashMap<Box<str>, Words> {
+                    key_index(&[<$file_stem _index>]().words, |word| {
+                        crate::phonetics::major_key(word, Lang::$EnumVariant)
+                    })
+                }
+
+                fn [<init_ $file_stem _confusable>]() -> AHashSet<&'static str> {
+                    crate::transcription::confusable(&[<$file_stem _index>]().words, Lang::$EnumVariant)
+                }
+
+                fn [<init_ $file_stem _bk_tree>]() -> BkTree {
+                    BkTree::new(&[<$file_stem _index>]().words)
+                }
+
+                fn [<init_ $file_stem _starts_with_folded>]() -> AHashMap<char, Words> {
                     let mut map = AHashMap::new();
-                    for &word in [<$file_stem:upper>].get_or_init([<init_ $file_stem>]).iter() {
-                        let len = word.chars().count();
-                        map.entry(len).or_insert_with(Vec::new).push(word);
+                    for &word in [<$file_stem _index>]().words.iter() {
+                        let first = word.chars().next().expect("empty word");
+                        map.entry(fold(first)).or_insert_with(Vec::new).push(word);
                     }
                     map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
                 }
 
-                fn [<init_ $file_stem _starts_with>]() -> AHashMap<char, Words> {
+                fn [<init_ $file_stem _anagrams>]() -> AHashMap<Box<str>, Words> {
                     let mut map = AHashMap::new();
-                    for &word in [<$file_stem:upper>].get_or_init([<init_ $file_stem>]).iter() {
-                        let first = word.chars().next().expect("empty word");
-                        map.entry(first).or_insert_with(Vec::new).push(word);
+                    for &word in [<$file_stem _index>]().words.iter() {
+                        map.entry(anagram_key(word)).or_insert_with(Vec::new).push(word);
+                    }
+                    map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
+                }
+
+                fn [<init_ $file_stem _signatures>]() -> Box<[u64]> {
+                    [<$file_stem _index>]()
+                        .words
+                        .iter()
+                        .map(|word| signature(word))
+                        .collect()
+                }
+
+                fn [<init_ $file_stem _trigrams>]() -> AHashMap<Trigram, Box<[u32]>> {
+                    trigram_index(&[<$file_stem _index>]().words)
+                }
+
+                fn [<init_ $file_stem _ends_with>]() -> AHashMap<char, Words> {
+                    let mut map = AHashMap::new();
+                    for &word in [<$file_stem _index>]().words.iter() {
+                        let last = word.chars().next_back().expect("empty word");
+                        map.entry(last).or_insert_with(Vec::new).push(word);
                     }
                     map.into_iter().map(|(k, v)| (k, v.into_boxed_slice())).collect()
                 }
             }
         )*
 
+        #[inline(always)]
+        pub(crate) fn sha256(lang: Lang) -> &'static str {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! { [<random_word_data_ $file_stem>]::SHA256 },
+                )*
+            }
+        }
+
+        /// Returns the compressed precomputed index of the language.
+        #[inline(always)]
+        pub(crate) fn raw_index(lang: Lang) -> &'static [u8] {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! { [<random_word_data_ $file_stem>]::INDEX },
+                )*
+            }
+        }
+
+        /// Returns whether the words of the language are loaded.
+        #[inline(always)]
+        pub(crate) fn is_loaded(lang: Lang) -> bool {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! { [<$file_stem:upper _INDEX>].get().is_some() },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn raw(lang: Lang) -> &'static [u8] {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! { [<$file_stem:upper _RAW>] },
+                )*
+            }
+        }
+
         #[inline(always)]
         pub(crate) fn get(lang: Lang) -> &'static Words {
             match lang {
                 $(
                     #[cfg(feature = $feat)]
                     Lang::$EnumVariant => paste::paste! {
-                        [<$file_stem:upper>].get_or_init([<init_ $file_stem>])
+                        &[<$file_stem _index>]().words
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn sorted_folded(lang: Lang) -> &'static Words {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _SORTED_FOLDED>].get_or_init([<init_ $file_stem _sorted_folded>])
+                    },
+                )*
+            }
+        }
+
+        /// Returns every word sorted by [`cmp_reversed`], the suffix
+        /// counterpart of [`get`].
+        #[inline(always)]
+        pub(crate) fn sorted_reversed(lang: Lang) -> &'static Words {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _SORTED_REVERSED>].get_or_init([<init_ $file_stem _sorted_reversed>])
+                    },
+                )*
+            }
+        }
+
+        /// Returns every word once, in the order of [`get`], which keeps
+        /// repeated entries of the source lists.
+        #[inline(always)]
+        pub(crate) fn distinct(lang: Lang) -> Arc<Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _DISTINCT>].get_or_init([<init_ $file_stem _distinct>])
                     },
                 )*
             }
         }
 
         #[inline(always)]
+        pub(crate) fn byte_len_index(lang: Lang) -> &'static AHashMap<usize, Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _BYTE_LEN>].get_or_init([<init_ $file_stem _byte_len>])
+                    },
+                )*
+            }
+        }
+
+        #[cfg(feature = "graphemes")]
+        #[inline(always)]
+        pub(crate) fn grapheme_len_index(lang: Lang) -> &'static AHashMap<usize, Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _GRAPHEME_LEN>].get_or_init([<init_ $file_stem _grapheme_len>])
+                    },
+                )*
+            }
+        }
+
+        /// Returns the words indexed by the length of their written form, as
+        /// measured by [`LengthUnit::Headword`](crate::LengthUnit::Headword).
+        #[inline(always)]
+        pub(crate) fn headword_len_index(lang: Lang) -> &'static AHashMap<usize, Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _HEADWORD_LEN>].get_or_init([<init_ $file_stem _headword_len>])
+                    },
+                )*
+            }
+        }
+
+        /// Returns the words with the given length in characters.
         pub(crate) fn get_len(len: usize, lang: Lang) -> Option<&'static Words> {
+            let index = match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! { [<$file_stem _index>]() },
+                )*
+            };
+            index.by_len.get(len).filter(|bucket| !bucket.is_empty())
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_starts_with(ch: char, lang: Lang) -> Option<&'static [&'static str]> {
+            let bucket = prefix_range(get(lang), ch.encode_utf8(&mut [0; 4]));
+            (!bucket.is_empty()).then_some(bucket)
+        }
+
+        #[inline(always)]
+        pub(crate) fn ends_with_index(lang: Lang) -> &'static AHashMap<char, Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _ENDS_WITH>].get_or_init([<init_ $file_stem _ends_with>])
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_ends_with(ch: char, lang: Lang) -> Option<&'static Words> {
+            ends_with_index(lang).get(&ch)
+        }
+
+        #[inline(always)]
+        pub(crate) fn trigrams(lang: Lang) -> Arc<AHashMap<Trigram, Box<[u32]>>> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _TRIGRAMS>].get_or_init([<init_ $file_stem _trigrams>])
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn starts_with_folded_index(lang: Lang) -> &'static AHashMap<char, Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _STARTS_WITH_FOLDED>]
+                            .get_or_init([<init_ $file_stem _starts_with_folded>])
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_starts_with_folded(ch: char, lang: Lang) -> Option<&'static Words> {
+            starts_with_folded_index(lang).get(&fold(ch))
+        }
+
+        /// Returns the [`signature`] of every word, aligned with [`get`].
+        #[inline(always)]
+        pub(crate) fn signatures(lang: Lang) -> Arc<Box<[u64]>> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _SIGNATURES>].get_or_init([<init_ $file_stem _signatures>])
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_anagrams(word: &str, lang: Lang) -> Option<&'static Words> {
             match lang {
                 $(
                     #[cfg(feature = $feat)]
                     Lang::$EnumVariant => paste::paste! {
-                        [<$file_stem:upper _LEN>]
-                            .get_or_init([<init_ $file_stem _len>])
-                            .get(&len)
+                        [<$file_stem:upper _ANAGRAMS>]
+                            .get_or_init([<init_ $file_stem _anagrams>])
+                            .get(&anagram_key(word))
                     },
                 )*
             }
         }
 
         #[inline(always)]
-        pub(crate) fn get_starts_with(ch: char, lang: Lang) -> Option<&'static Words> {
+        pub(crate) fn syllable_index(lang: Lang) -> &'static AHashMap<usize, Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _SYLLABLES>].get_or_init([<init_ $file_stem _syllables>])
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_rhymes(word: &str, lang: Lang) -> Option<Words> {
+            let key = crate::phonetics::rhyme_key(word, lang)?;
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _RHYMES>].get_or_init([<init_ $file_stem _rhymes>]).get(&key).cloned()
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn get_sounds_like(word: &str, lang: Lang) -> Option<Words> {
+            let key = crate::phonetics::phonetic_key(word, lang)?;
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _SOUNDS>].get_or_init([<init_ $file_stem _sounds>]).get(&key).cloned()
+                    },
+                )*
+            }
+        }
+
+        /// Returns the words whose consonant sounds encode `digits` in the
+        /// major system.
+        #[inline(always)]
+        pub(crate) fn get_major(digits: &str, lang: Lang) -> Option<&'static Words> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _MAJOR>].get_or_init([<init_ $file_stem _major>]).get(digits)
+                    },
+                )*
+            }
+        }
+
+        /// Returns the words easily confused with another word when
+        /// dictated or handwritten.
+        #[inline(always)]
+        pub(crate) fn confusable(lang: Lang) -> Arc<AHashSet<&'static str>> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _CONFUSABLE>].get_or_init([<init_ $file_stem _confusable>])
+                    },
+                )*
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) fn bk_tree(lang: Lang) -> Arc<BkTree> {
+            match lang {
+                $(
+                    #[cfg(feature = $feat)]
+                    Lang::$EnumVariant => paste::paste! {
+                        [<$file_stem:upper _BK_TREE>].get_or_init([<init_ $file_stem _bk_tree>])
+                    },
+                )*
+            }
+        }
+
+        /// Drops the [`Releasable`] indexes of the language, returning
+        /// whether any was built.
+        pub(crate) fn release(lang: Lang) -> bool {
             match lang {
                 $(
                     #[cfg(feature = $feat)]
                     Lang::$EnumVariant => paste::paste! {
-                        [<$file_stem:upper _STARTS_WITH>]
-                            .get_or_init([<init_ $file_stem _starts_with>])
-                            .get(&ch)
+                        [
+                            [<$file_stem:upper _DISTINCT>].release(),
+                            [<$file_stem:upper _SIGNATURES>].release(),
+                            [<$file_stem:upper _TRIGRAMS>].release(),
+                            [<$file_stem:upper _RHYMES>].release(),
+                            [<$file_stem:upper _SOUNDS>].release(),
+                            [<$file_stem:upper _BK_TREE>].release(),
+                            [<$file_stem:upper _CONFUSABLE>].release(),
+                        ]
+                        .contains(&true)
                     },
                 )*
             }
@@ -120,4 +1130,10 @@ generate_word_db! {
     "ja" => ja : Ja : "Japanese",
     "ru" => ru : Ru : "Russian",
     "zh" => zh : Zh : "Chinese",
+    "eff-long" => eff_long : EffLong : "EFF long",
+    "eff-short1" => eff_short1 : EffShort1 : "EFF short 1",
+    "eff-short2" => eff_short2 : EffShort2 : "EFF short 2",
+    "en-10k" => en_10k : En10k : "English 10k",
+    "en-gb" => en_gb : EnGb : "British English",
+    "en-us" => en_us : EnUs : "American English",
 }